@@ -0,0 +1,97 @@
+#include <tfhe.h>
+
+#include <assert.h>
+#include <inttypes.h>
+#include <stdio.h>
+
+int uint512_client_key(const ClientKey *client_key) {
+  int ok;
+  FheUint512 *lhs = NULL;
+  FheUint512 *rhs = NULL;
+  FheUint512 *result = NULL;
+  FheUint64 *cast_result = NULL;
+  U512 lhs_clear = {{1, 2, 3, 4, 5, 6, 7, 8}};
+  U512 rhs_clear = {{8, 7, 6, 5, 4, 3, 2, 1}};
+  U512 result_clear = {{0}};
+
+  ok = fhe_uint512_try_encrypt_with_client_key_u512(lhs_clear, client_key, &lhs);
+  assert(ok == 0);
+
+  ok = fhe_uint512_try_encrypt_with_client_key_u512(rhs_clear, client_key, &rhs);
+  assert(ok == 0);
+
+  ok = fhe_uint512_add(lhs, rhs, &result);
+  assert(ok == 0);
+
+  ok = fhe_uint512_decrypt(result, client_key, &result_clear);
+  assert(ok == 0);
+
+  for (int i = 0; i < 8; ++i) {
+    assert(result_clear.words[i] == 9);
+  }
+
+  // try some casting
+  ok = fhe_uint512_cast_into_fhe_uint64(result, &cast_result);
+  assert(ok == 0);
+  uint64_t u64_clear;
+  ok = fhe_uint64_decrypt(cast_result, client_key, &u64_clear);
+  assert(ok == 0);
+  assert(u64_clear == 9);
+
+  fhe_uint512_destroy(lhs);
+  fhe_uint512_destroy(rhs);
+  fhe_uint512_destroy(result);
+  fhe_uint64_destroy(cast_result);
+  return ok;
+}
+
+int uint2048_encrypt_trivial(const ClientKey *client_key) {
+  int ok;
+  FheUint2048 *value = NULL;
+  U2048 clear = {{0}};
+  U2048 result_clear = {{0}};
+  uint8_t bytes[256] = {0};
+
+  for (int i = 0; i < 256; ++i) {
+    bytes[i] = (uint8_t)i;
+  }
+  ok = u2048_from_little_endian_bytes(bytes, 256, &clear);
+  assert(ok == 0);
+
+  ok = fhe_uint2048_try_encrypt_trivial_u2048(clear, &value);
+  assert(ok == 0);
+
+  ok = fhe_uint2048_decrypt(value, client_key, &result_clear);
+  assert(ok == 0);
+
+  for (int i = 0; i < 32; ++i) {
+    assert(result_clear.words[i] == clear.words[i]);
+  }
+
+  fhe_uint2048_destroy(value);
+  return ok;
+}
+
+int main(void) {
+  int ok = 0;
+  ConfigBuilder *builder;
+  Config *config;
+
+  config_builder_all_disabled(&builder);
+  config_builder_enable_default_integers_small(&builder);
+  config_builder_build(builder, &config);
+
+  ClientKey *client_key = NULL;
+  ServerKey *server_key = NULL;
+
+  generate_keys(config, &client_key, &server_key);
+
+  set_server_key(server_key);
+
+  uint512_client_key(client_key);
+  uint2048_encrypt_trivial(client_key);
+
+  client_key_destroy(client_key);
+  server_key_destroy(server_key);
+  return ok;
+}
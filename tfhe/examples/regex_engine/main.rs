@@ -1,30 +1,45 @@
-#[macro_use]
-extern crate log;
-
-mod ciphertext;
-mod engine;
-mod execution;
-mod parser;
-
-use env_logger::Env;
 use std::env;
+use std::time::Instant;
 
-fn main() {
-    let env = Env::default().filter_or("RUST_LOG", "info");
-    env_logger::init_from_env(env);
+use tfhe::integer::gen_keys_radix;
+use tfhe::regex::RegexEngine;
+use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
 
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
     let content = &args[1];
-    let pattern = &args[2];
+    let patterns = &args[2..];
+
+    if !content.is_ascii() {
+        return Err("content contains non-ascii characters".into());
+    }
+
+    // 4 blocks of 2 bits are needed to encrypt an ASCII character
+    let (client_key, server_key) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+    let ct_content = content
+        .bytes()
+        .map(|byte| client_key.encrypt(byte as u64))
+        .collect::<Vec<_>>();
 
-    let (client_key, server_key) = ciphertext::gen_keys();
-    let ct_content = ciphertext::encrypt_str(&client_key, content).unwrap();
+    // The engine reuses the results computed for the previous patterns
+    let mut engine = RegexEngine::new(&server_key, &ct_content);
+    for pattern in patterns {
+        let start = Instant::now();
+        let (ct_found, ct_position) = engine.find(pattern)?;
+        let elapsed = start.elapsed();
 
-    let ct_res = engine::has_match(&server_key, &ct_content, pattern).unwrap();
-    let res: u64 = client_key.decrypt(&ct_res);
-    if res == 0 {
-        println!("no match");
-    } else {
-        println!("match");
+        let found: u64 = client_key.decrypt(&ct_found);
+        if found == 0 {
+            println!("{pattern}: no match ({elapsed:?})");
+        } else {
+            let position: u64 = client_key.decrypt(&ct_position);
+            println!("{pattern}: match at position {position} ({elapsed:?})");
+        }
     }
+    println!(
+        "{} ciphertext operations, {} cache hits",
+        engine.ct_operations_count(),
+        engine.cache_hits(),
+    );
+    Ok(())
 }
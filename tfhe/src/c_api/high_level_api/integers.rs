@@ -5,6 +5,7 @@ use std::ops::{
     Neg, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
 };
 
+use crate::c_api::high_level_api::static_big_uints::{U1024, U2048, U512};
 use crate::c_api::high_level_api::u128::U128;
 use crate::c_api::high_level_api::u256::U256;
 use crate::c_api::utils::*;
@@ -17,9 +18,11 @@ macro_rules! impl_operations_for_integer_type {
         name: $name:ident,
         clear_scalar_type: $clear_scalar_type:ty
     ) => {
-        impl_binary_fn_on_type!($name => add, sub, mul, bitand, bitor, bitxor, shl, shr, eq, ne, ge, gt, le, lt, min, max);
+        impl_binary_fn_on_type!($name => add, sub, mul, bitand, bitor, bitxor, shl, shr, min, max);
+        impl_comparison_fn_on_type!($name => eq, ne, ge, gt, le, lt);
         impl_binary_assign_fn_on_type!($name => add_assign, sub_assign, mul_assign, bitand_assign, bitor_assign, bitxor_assign, shl_assign, shr_assign);
-        impl_scalar_binary_fn_on_type!($name, $clear_scalar_type => add, sub, mul, shl, shr, eq, ne, ge, gt, le, lt, min, max);
+        impl_scalar_binary_fn_on_type!($name, $clear_scalar_type => add, sub, mul, shl, shr, min, max);
+        impl_scalar_comparison_fn_on_type!($name, $clear_scalar_type => eq, ne, ge, gt, le, lt);
         impl_scalar_binary_assign_fn_on_type!($name, $clear_scalar_type => add_assign, sub_assign, mul_assign, shl_assign, shr_assign);
 
         impl_unary_fn_on_type!($name => neg, not);
@@ -123,6 +126,9 @@ create_integer_wrapper_type!(name: FheUint32, clear_scalar_type: u32);
 create_integer_wrapper_type!(name: FheUint64, clear_scalar_type: u64);
 create_integer_wrapper_type!(name: FheUint128, clear_scalar_type: u64);
 create_integer_wrapper_type!(name: FheUint256, clear_scalar_type: u64);
+create_integer_wrapper_type!(name: FheUint512, clear_scalar_type: u64);
+create_integer_wrapper_type!(name: FheUint1024, clear_scalar_type: u64);
+create_integer_wrapper_type!(name: FheUint2048, clear_scalar_type: u64);
 
 impl_decrypt_on_type!(FheUint8, u8);
 impl_try_encrypt_trivial_on_type!(FheUint8{crate::high_level_api::FheUint8}, u8);
@@ -418,6 +424,136 @@ pub unsafe extern "C" fn fhe_uint256_decrypt(
     })
 }
 
+/// Implements the encryption and decryption functions of a FheUint type whose
+/// clear type is one of the C big uint types (which are converted to their Rust equivalent)
+macro_rules! impl_encrypt_decrypt_for_big_uint_type {
+    ($name:ident, $clear_type:ident) => {
+        ::paste::paste! {
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _try_encrypt_trivial_ $clear_type:lower>](
+                value: $clear_type,
+                result: *mut *mut $name,
+            ) -> c_int {
+                catch_panic(|| {
+                    let value = crate::integer::$clear_type::from(value);
+                    let inner = <crate::high_level_api::$name>::try_encrypt_trivial(value).unwrap();
+
+                    *result = Box::into_raw(Box::new($name(inner)));
+                })
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _try_encrypt_with_client_key_ $clear_type:lower>](
+                value: $clear_type,
+                client_key: *const ClientKey,
+                result: *mut *mut $name,
+            ) -> c_int {
+                catch_panic(|| {
+                    let client_key = get_ref_checked(client_key).unwrap();
+
+                    let value = crate::integer::$clear_type::from(value);
+                    let inner = <crate::high_level_api::$name>::try_encrypt(value, &client_key.0).unwrap();
+
+                    *result = Box::into_raw(Box::new($name(inner)));
+                })
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn [<compressed_ $name:snake _try_encrypt_with_client_key_ $clear_type:lower>](
+                value: $clear_type,
+                client_key: *const ClientKey,
+                result: *mut *mut [<Compressed $name>],
+            ) -> c_int {
+                catch_panic(|| {
+                    let client_key = get_ref_checked(client_key).unwrap();
+
+                    let value = crate::integer::$clear_type::from(value);
+                    let inner =
+                        <crate::high_level_api::[<Compressed $name>]>::try_encrypt(value, &client_key.0)
+                            .unwrap();
+
+                    *result = Box::into_raw(Box::new([<Compressed $name>](inner)));
+                })
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _try_encrypt_with_public_key_ $clear_type:lower>](
+                value: $clear_type,
+                public_key: *const PublicKey,
+                result: *mut *mut $name,
+            ) -> c_int {
+                catch_panic(|| {
+                    let public_key = get_ref_checked(public_key).unwrap();
+
+                    let value = crate::integer::$clear_type::from(value);
+                    let inner = <crate::high_level_api::$name>::try_encrypt(value, &public_key.0).unwrap();
+
+                    *result = Box::into_raw(Box::new($name(inner)));
+                })
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _try_encrypt_with_compact_public_key_ $clear_type:lower>](
+                value: $clear_type,
+                public_key: *const CompactPublicKey,
+                result: *mut *mut $name,
+            ) -> c_int {
+                catch_panic(|| {
+                    let public_key = get_ref_checked(public_key).unwrap();
+
+                    let value = crate::integer::$clear_type::from(value);
+                    let inner = <crate::high_level_api::$name>::try_encrypt(value, &public_key.0).unwrap();
+
+                    *result = Box::into_raw(Box::new($name(inner)));
+                })
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn [<compact_ $name:snake _list_try_encrypt_with_compact_public_key_ $clear_type:lower>](
+                input: *const $clear_type,
+                input_len: usize,
+                public_key: *const CompactPublicKey,
+                result: *mut *mut [<Compact $name List>],
+            ) -> c_int {
+                catch_panic(|| {
+                    let public_key = get_ref_checked(public_key).unwrap();
+
+                    let slc = ::std::slice::from_raw_parts(input, input_len);
+                    let values = slc
+                        .iter()
+                        .copied()
+                        .map(crate::integer::$clear_type::from)
+                        .collect::<Vec<_>>();
+                    let inner =
+                        <crate::high_level_api::[<Compact $name List>]>::try_encrypt(&values, &public_key.0)
+                            .unwrap();
+
+                    *result = Box::into_raw(Box::new([<Compact $name List>](inner)));
+                })
+            }
+
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:snake _decrypt>](
+                encrypted_value: *const $name,
+                client_key: *const ClientKey,
+                result: *mut $clear_type,
+            ) -> c_int {
+                catch_panic(|| {
+                    let client_key = get_ref_checked(client_key).unwrap();
+                    let encrypted_value = get_ref_checked(encrypted_value).unwrap();
+
+                    let inner: crate::integer::$clear_type = encrypted_value.0.decrypt(&client_key.0);
+                    *result = $clear_type::from(inner);
+                })
+            }
+        }
+    };
+}
+
+impl_encrypt_decrypt_for_big_uint_type!(FheUint512, U512);
+impl_encrypt_decrypt_for_big_uint_type!(FheUint1024, U1024);
+impl_encrypt_decrypt_for_big_uint_type!(FheUint2048, U2048);
+
 macro_rules! define_casting_operation(
     ($from:ty => $($to:ty),*) => {
         $(
@@ -439,12 +575,15 @@ macro_rules! define_casting_operation(
     }
 );
 
-define_casting_operation!(FheUint8 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint10 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint12 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint14 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint16 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint32 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint64 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint128 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
-define_casting_operation!(FheUint256 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256);
+define_casting_operation!(FheUint8 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint10 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint12 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint14 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint16 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint32 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint64 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint128 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint256 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint512 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint1024 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
+define_casting_operation!(FheUint2048 => FheUint8, FheUint10, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128, FheUint256, FheUint512, FheUint1024, FheUint2048);
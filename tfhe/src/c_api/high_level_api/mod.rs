@@ -7,6 +7,8 @@ pub mod config;
 pub mod integers;
 pub mod keys;
 #[cfg(feature = "integer")]
+pub mod static_big_uints;
+#[cfg(feature = "integer")]
 pub mod u128;
 #[cfg(feature = "integer")]
 pub mod u256;
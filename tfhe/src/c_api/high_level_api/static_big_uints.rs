@@ -0,0 +1,103 @@
+//! C representations of the [StaticUnsignedBigInt](crate::integer::StaticUnsignedBigInt)
+//! types wider than 256 bits.
+//!
+//! Unlike [U256](super::u256::U256) these store their words in an array,
+//! in little endian order (`words[0]` is the least significant word).
+use crate::c_api::utils::*;
+use std::os::raw::c_int;
+
+macro_rules! create_static_big_uint_c_type {
+    ($name:ident, $num_words:literal) => {
+        #[repr(C)]
+        #[derive(Copy, Clone)]
+        pub struct $name {
+            pub words: [u64; $num_words],
+        }
+
+        impl From<crate::integer::$name> for $name {
+            fn from(value: crate::integer::$name) -> Self {
+                Self { words: value.0 }
+            }
+        }
+
+        impl From<$name> for crate::integer::$name {
+            fn from(value: $name) -> Self {
+                crate::integer::StaticUnsignedBigInt(value.words)
+            }
+        }
+
+        ::paste::paste! {
+            #[doc = concat!("Creates a ", stringify!($name), " from little endian bytes")]
+            ///
+            #[doc = concat!("len must be ", stringify!($num_words), " * 8")]
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:lower _from_little_endian_bytes>](
+                input: *const u8,
+                len: usize,
+                result: *mut $name,
+            ) -> c_int {
+                catch_panic(|| {
+                    let mut inner = crate::integer::$name::default();
+
+                    let input = std::slice::from_raw_parts(input, len);
+                    inner.copy_from_le_byte_slice(input);
+
+                    *result = $name::from(inner)
+                })
+            }
+
+            #[doc = concat!("Creates a ", stringify!($name), " from big endian bytes")]
+            ///
+            #[doc = concat!("len must be ", stringify!($num_words), " * 8")]
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:lower _from_big_endian_bytes>](
+                input: *const u8,
+                len: usize,
+                result: *mut $name,
+            ) -> c_int {
+                catch_panic(|| {
+                    let mut inner = crate::integer::$name::default();
+
+                    let input = std::slice::from_raw_parts(input, len);
+                    inner.copy_from_be_byte_slice(input);
+
+                    *result = $name::from(inner)
+                })
+            }
+
+            #[doc = concat!("len must be ", stringify!($num_words), " * 8")]
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:lower _little_endian_bytes>](
+                input: $name,
+                result: *mut u8,
+                len: usize,
+            ) -> c_int {
+                catch_panic(|| {
+                    check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let bytes = std::slice::from_raw_parts_mut(result, len);
+                    crate::integer::$name::from(input).copy_to_le_byte_slice(bytes);
+                })
+            }
+
+            #[doc = concat!("len must be ", stringify!($num_words), " * 8")]
+            #[no_mangle]
+            pub unsafe extern "C" fn [<$name:lower _big_endian_bytes>](
+                input: $name,
+                result: *mut u8,
+                len: usize,
+            ) -> c_int {
+                catch_panic(|| {
+                    check_ptr_is_non_null_and_aligned(result).unwrap();
+
+                    let bytes = std::slice::from_raw_parts_mut(result, len);
+                    crate::integer::$name::from(input).copy_to_be_byte_slice(bytes);
+                })
+            }
+        }
+    };
+}
+
+create_static_big_uint_c_type!(U512, 8);
+create_static_big_uint_c_type!(U1024, 16);
+create_static_big_uint_c_type!(U2048, 32);
@@ -298,6 +298,57 @@ macro_rules! impl_scalar_binary_fn_on_type {
     };
 }
 
+// Comparisons return an encrypted boolean, which is converted back
+// to the integer type, as the C API does not expose the boolean type.
+#[cfg(feature = "integer")]
+macro_rules! impl_comparison_fn_on_type {
+    ($wrapper_type:ty => $($comparison_fn_name:ident),* $(,)?) => {
+        $(
+            ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<$wrapper_type:snake _ $comparison_fn_name>](
+                    lhs: *const $wrapper_type,
+                    rhs: *const $wrapper_type,
+                    result: *mut *mut $wrapper_type,
+                ) -> ::std::os::raw::c_int {
+                    $crate::c_api::utils::catch_panic(|| {
+                        let lhs = $crate::c_api::utils::get_ref_checked(lhs).unwrap();
+                        let rhs = $crate::c_api::utils::get_ref_checked(rhs).unwrap();
+
+                        let inner = (&lhs.0).$comparison_fn_name(&rhs.0);
+
+                        *result = Box::into_raw(Box::new($wrapper_type(inner.into())));
+                    })
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(feature = "integer")]
+macro_rules! impl_scalar_comparison_fn_on_type {
+    ($wrapper_type:ty, $scalar_type:ty => $($comparison_fn_name:ident),* $(,)?) => {
+        $(
+           ::paste::paste! {
+                #[no_mangle]
+                pub unsafe extern "C" fn [<$wrapper_type:snake _scalar_ $comparison_fn_name>](
+                    lhs: *const $wrapper_type,
+                    rhs: $scalar_type,
+                    result: *mut *mut $wrapper_type,
+                ) -> c_int {
+                    $crate::c_api::utils::catch_panic(|| {
+                        let lhs = $crate::c_api::utils::get_ref_checked(lhs).unwrap();
+
+                        let inner = (&lhs.0).$comparison_fn_name(rhs);
+
+                        *result = Box::into_raw(Box::new($wrapper_type(inner.into())));
+                    })
+                }
+            }
+        )*
+    };
+}
+
 #[cfg(feature = "integer")]
 macro_rules! impl_scalar_binary_assign_fn_on_type {
     ($wrapper_type:ty, $scalar_type:ty => $($binary_assign_fn_name:ident),* $(,)?) => {
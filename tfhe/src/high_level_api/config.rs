@@ -1,7 +1,9 @@
 #[cfg(feature = "boolean")]
 use crate::high_level_api::booleans::{BooleanConfig, FheBoolParameters};
 #[cfg(feature = "integer")]
-use crate::high_level_api::integers::IntegerConfig;
+use crate::high_level_api::errors::{Error, UnwrapResultExt};
+#[cfg(feature = "integer")]
+use crate::high_level_api::integers::{IntegerConfig, MAX_FUNCTION_EVALUATION_INPUT_BITS};
 #[cfg(feature = "shortint")]
 use crate::high_level_api::shortints::ShortIntConfig;
 
@@ -109,21 +111,90 @@ impl ConfigBuilder {
 
     #[cfg(feature = "integer")]
     pub fn enable_default_integers(mut self) -> Self {
-        self.config.integer_config = IntegerConfig::default_big();
+        self.set_integer_config(IntegerConfig::default_big());
         self
     }
 
     #[cfg(feature = "integer")]
     pub fn enable_default_integers_small(mut self) -> Self {
-        self.config.integer_config = IntegerConfig::default_small();
+        self.set_integer_config(IntegerConfig::default_small());
         self
     }
 
-    #[doc(hidden)]
+    /// Enables the evaluation of arbitrary functions on integers
+    ///
+    /// This allows to use [FheBootstrap::map](crate::prelude::FheBootstrap::map) and
+    /// `bivariate_map` on integer types, which evaluate any function given as a closure,
+    /// at the cost of generating additional (big) keys.
+    ///
+    /// Functions with up to 16 input bits are supported, e.g. `map` on
+    /// [FheUint16](crate::FheUint16) or `bivariate_map` on [FheUint8](crate::FheUint8).
+    ///
+    /// Integers must be enabled in the config, function evaluation uses the parameters of
+    /// their blocks, and stays enabled if these parameters are changed afterwards
+    /// (changing them to parameters that do not support function evaluation panics).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the integer parameters do not support function evaluation,
+    /// see [Self::try_enable_function_evaluation_integers] for a non panicking version.
     #[cfg(feature = "integer")]
-    pub fn enable_function_evaluation_integers(mut self) -> Self {
-        self.config.integer_config.enable_wopbs();
-        self
+    pub fn enable_function_evaluation_integers(self) -> Self {
+        self.try_enable_function_evaluation_integers(MAX_FUNCTION_EVALUATION_INPUT_BITS)
+            .unwrap_display()
+    }
+
+    /// Tries to enable the evaluation of functions with up to `max_input_bits` input bits
+    /// on integers
+    ///
+    /// The number of input bits of a function is the number of bits of its input
+    /// (e.g. 8 for `map` on a [FheUint8](crate::FheUint8)), or the sum of the number of bits
+    /// of its two inputs for a `bivariate_map`.
+    ///
+    /// Returns an error if:
+    ///
+    /// - integers are not enabled in the config;
+    /// - the integer parameters are not `KS_PBS` parameters, or their blocks are too wide;
+    /// - `max_input_bits` is greater than 16.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::{ConfigBuilder, Error, FunctionEvaluationError};
+    ///
+    /// // bivariate_map on FheUint16 needs functions with 32 input bits
+    /// let result = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .try_enable_function_evaluation_integers(32);
+    /// assert_eq!(
+    ///     result.err(),
+    ///     Some(Error::FunctionEvaluation(
+    ///         FunctionEvaluationError::InputTooWide {
+    ///             max_input_bits: 32,
+    ///             supported_input_bits: 16,
+    ///         }
+    ///     ))
+    /// );
+    ///
+    /// let result = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers_small()
+    ///     .try_enable_function_evaluation_integers(16);
+    /// assert_eq!(
+    ///     result.err(),
+    ///     Some(Error::FunctionEvaluation(
+    ///         FunctionEvaluationError::UnsupportedPBSOrder
+    ///     ))
+    /// );
+    /// ```
+    #[cfg(feature = "integer")]
+    pub fn try_enable_function_evaluation_integers(
+        mut self,
+        max_input_bits: u32,
+    ) -> Result<Self, Error> {
+        self.config
+            .integer_config
+            .try_enable_wopbs(max_input_bits)?;
+        Ok(self)
     }
 
     #[cfg(feature = "integer")]
@@ -135,8 +206,13 @@ impl ConfigBuilder {
     where
         P: Into<crate::shortint::PBSParameters>,
     {
-        self.config.integer_config =
+        let integer_config =
             IntegerConfig::new(Some(block_parameters.into()), wopbs_block_parameters);
+        if wopbs_block_parameters.is_some() {
+            self.config.integer_config = integer_config;
+        } else {
+            self.set_integer_config(integer_config);
+        }
         self
     }
 
@@ -146,6 +222,22 @@ impl ConfigBuilder {
         self
     }
 
+    /// Replaces the integer config, keeping function evaluation enabled if it was
+    #[cfg(feature = "integer")]
+    fn set_integer_config(&mut self, integer_config: IntegerConfig) {
+        let max_input_bits = self
+            .config
+            .integer_config
+            .function_evaluation_max_input_bits;
+        self.config.integer_config = integer_config;
+        if let Some(max_input_bits) = max_input_bits {
+            self.config
+                .integer_config
+                .try_enable_wopbs(max_input_bits)
+                .unwrap_display();
+        }
+    }
+
     pub fn build(self) -> Config {
         self.config
     }
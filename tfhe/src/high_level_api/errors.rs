@@ -53,6 +53,34 @@ pub enum Type {
     FheUint128,
     #[cfg(feature = "integer")]
     FheUint256,
+    #[cfg(feature = "integer")]
+    FheUint512,
+    #[cfg(feature = "integer")]
+    FheUint1024,
+    #[cfg(feature = "integer")]
+    FheUint2048,
+    #[cfg(feature = "integer")]
+    FheUint(usize),
+    #[cfg(feature = "integer")]
+    FheUFixed(usize, usize),
+    #[cfg(feature = "integer")]
+    FheIFixed(usize, usize),
+    #[cfg(feature = "integer")]
+    FheFloat(usize, usize),
+    #[cfg(feature = "integer")]
+    FheIntegerBool,
+    #[cfg(feature = "integer")]
+    FheInt8,
+    #[cfg(feature = "integer")]
+    FheInt16,
+    #[cfg(feature = "integer")]
+    FheInt32,
+    #[cfg(feature = "integer")]
+    FheInt64,
+    #[cfg(feature = "integer")]
+    FheInt128,
+    #[cfg(feature = "integer")]
+    FheInt256,
 }
 
 /// The server key of a given type was not initialized
@@ -140,6 +168,67 @@ impl Display for OutOfRangeError {
 
 impl std::error::Error for OutOfRangeError {}
 
+/// Error when the parameters of a config do not support function evaluation on integers
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum FunctionEvaluationError {
+    /// Function evaluation requires integers to be enabled in the config first
+    IntegersNotEnabled,
+    /// Function evaluation requires parameters where the keyswitch is done before the PBS
+    /// (`KS_PBS` parameters)
+    UnsupportedPBSOrder,
+    /// There are no WoPBS parameters for blocks this wide
+    UnsupportedBlockWidth {
+        message_modulus: usize,
+        carry_modulus: usize,
+    },
+    /// The functions to evaluate have more input bits than what is supported
+    InputTooWide {
+        max_input_bits: u32,
+        supported_input_bits: u32,
+    },
+}
+
+impl Display for FunctionEvaluationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::IntegersNotEnabled => {
+                write!(
+                    f,
+                    "Integers must be enabled in the config to enable function evaluation"
+                )
+            }
+            Self::UnsupportedPBSOrder => {
+                write!(
+                    f,
+                    "Function evaluation on integers only supports KS_PBS parameters"
+                )
+            }
+            Self::UnsupportedBlockWidth {
+                message_modulus,
+                carry_modulus,
+            } => {
+                write!(
+                    f,
+                    "Function evaluation on integers is not supported for blocks with \
+                    message modulus {message_modulus} and carry modulus {carry_modulus}"
+                )
+            }
+            Self::InputTooWide {
+                max_input_bits,
+                supported_input_bits,
+            } => {
+                write!(
+                    f,
+                    "Function evaluation on integers supports functions with at most \
+                    {supported_input_bits} input bits, {max_input_bits} were asked"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for FunctionEvaluationError {}
+
 #[non_exhaustive]
 #[derive(Debug, Eq, PartialEq)]
 pub enum Error {
@@ -147,6 +236,7 @@ pub enum Error {
     UninitializedClientKey(Type),
     UninitializedPublicKey(Type),
     UninitializedServerKey(Type),
+    FunctionEvaluation(FunctionEvaluationError),
 }
 
 impl From<OutOfRangeError> for Error {
@@ -173,6 +263,12 @@ impl From<UninitializedServerKey> for Error {
     }
 }
 
+impl From<FunctionEvaluationError> for Error {
+    fn from(value: FunctionEvaluationError) -> Self {
+        Self::FunctionEvaluation(value)
+    }
+}
+
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -188,6 +284,9 @@ impl Display for Error {
             Error::UninitializedServerKey(ty) => {
                 write!(f, "{}", UninitializedServerKey(*ty))
             }
+            Error::FunctionEvaluation(error) => {
+                write!(f, "{error}")
+            }
         }
     }
 }
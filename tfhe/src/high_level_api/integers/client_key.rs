@@ -1,5 +1,6 @@
+use crate::core_crypto::prelude::SignedInteger;
 use crate::high_level_api::internal_traits::DecryptionKey;
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
 
 impl<ClearType> DecryptionKey<RadixCiphertext, ClearType> for crate::integer::ClientKey
 where
@@ -9,3 +10,12 @@ where
         self.decrypt_radix(ciphertext)
     }
 }
+
+impl<ClearType> DecryptionKey<SignedRadixCiphertext, ClearType> for crate::integer::ClientKey
+where
+    ClearType: crate::integer::block_decomposition::RecomposableFrom<u64> + SignedInteger,
+{
+    fn decrypt(&self, ciphertext: &SignedRadixCiphertext) -> ClearType {
+        self.decrypt_signed_radix(ciphertext)
+    }
+}
@@ -3,15 +3,27 @@ use serde::{Deserialize, Serialize};
 
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
+use crate::high_level_api::errors::FunctionEvaluationError;
+use crate::high_level_api::integers::parameters::IntegerParameter;
 use crate::integer::ciphertext::{CompactCiphertextList, RadixCiphertext};
 use crate::integer::public_key::CompactPublicKey;
-use crate::integer::{CompressedCompactPublicKey, U256};
+use crate::integer::CompressedCompactPublicKey;
+use crate::shortint::parameters::parameters_wopbs_message_carry::ALL_PARAMETER_VEC_WOPBS;
 use crate::shortint::EncryptionKeyChoice;
 
+/// Maximum number of input bits of a function evaluated on integers.
+///
+/// The lookup table used to evaluate a function has one entry per possible input,
+/// so its size grows exponentially with the number of input bits.
+pub(in crate::high_level_api) const MAX_FUNCTION_EVALUATION_INPUT_BITS: u32 = 16;
+
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerConfig {
     pub(crate) block_parameters: Option<crate::shortint::PBSParameters>,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    /// Maximum number of input bits of the evaluated functions, set when function evaluation
+    /// is enabled
+    pub(crate) function_evaluation_max_input_bits: Option<u32>,
 }
 
 impl IntegerConfig {
@@ -22,6 +34,7 @@ impl IntegerConfig {
         Self {
             block_parameters,
             wopbs_block_parameters,
+            function_evaluation_max_input_bits: None,
         }
     }
 
@@ -39,6 +52,7 @@ impl IntegerConfig {
                 crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
             ),
             wopbs_block_parameters: None,
+            function_evaluation_max_input_bits: None,
         }
     }
 
@@ -48,20 +62,46 @@ impl IntegerConfig {
                 crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS.into(),
             ),
             wopbs_block_parameters: None,
+            function_evaluation_max_input_bits: None,
         }
     }
 
-    pub fn enable_wopbs(&mut self) {
-        let block_parameter = self
-            .block_parameters
-            .get_or_insert(crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into());
+    pub(in crate::high_level_api) fn try_enable_wopbs(
+        &mut self,
+        max_input_bits: u32,
+    ) -> Result<(), FunctionEvaluationError> {
+        if max_input_bits > MAX_FUNCTION_EVALUATION_INPUT_BITS {
+            return Err(FunctionEvaluationError::InputTooWide {
+                max_input_bits,
+                supported_input_bits: MAX_FUNCTION_EVALUATION_INPUT_BITS,
+            });
+        }
 
-        let wopbs_block_parameters = match block_parameter.encryption_key_choice() {
-            EncryptionKeyChoice::Big => crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
-            _ => panic!("WOPBS only support KS_PBS parameters")
+        let Some(block_parameters) = self.block_parameters.as_ref() else {
+            return Err(FunctionEvaluationError::IntegersNotEnabled);
         };
 
+        if block_parameters.encryption_key_choice() != EncryptionKeyChoice::Big {
+            return Err(FunctionEvaluationError::UnsupportedPBSOrder);
+        }
+
+        let message_modulus = block_parameters.message_modulus();
+        let carry_modulus = block_parameters.carry_modulus();
+        let wopbs_block_parameters = ALL_PARAMETER_VEC_WOPBS
+            .into_iter()
+            .find(|params| {
+                params.message_modulus == message_modulus
+                    && params.carry_modulus == carry_modulus
+                    && params.encryption_key_choice == EncryptionKeyChoice::Big
+            })
+            .ok_or(FunctionEvaluationError::UnsupportedBlockWidth {
+                message_modulus: message_modulus.0,
+                carry_modulus: carry_modulus.0,
+            })?;
+
         self.wopbs_block_parameters = Some(wopbs_block_parameters);
+        self.function_evaluation_max_input_bits = Some(max_input_bits);
+        Ok(())
     }
 }
 
@@ -69,6 +109,7 @@ impl IntegerConfig {
 pub(crate) struct IntegerClientKey {
     pub(crate) key: Option<crate::integer::ClientKey>,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) function_evaluation_max_input_bits: Option<u32>,
 }
 
 impl IntegerClientKey {
@@ -83,6 +124,7 @@ impl IntegerClientKey {
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            function_evaluation_max_input_bits: config.function_evaluation_max_input_bits,
         }
     }
 
@@ -104,6 +146,7 @@ impl From<IntegerConfig> for IntegerClientKey {
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            function_evaluation_max_input_bits: config.function_evaluation_max_input_bits,
         }
     }
 }
@@ -112,6 +155,7 @@ impl From<IntegerConfig> for IntegerClientKey {
 pub struct IntegerServerKey {
     pub(crate) key: Option<crate::integer::ServerKey>,
     pub(crate) wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+    pub(crate) function_evaluation_max_input_bits: Option<u32>,
 }
 
 impl IntegerServerKey {
@@ -129,6 +173,7 @@ impl IntegerServerKey {
         Self {
             key: Some(base_integer_key),
             wopbs_key,
+            function_evaluation_max_input_bits: client_key.function_evaluation_max_input_bits,
         }
     }
 
@@ -137,6 +182,12 @@ impl IntegerServerKey {
             .as_ref()
             .expect("Integer ServerKey is not initialized")
     }
+
+    /// Returns the maximum number of input bits of a function that can be evaluated
+    pub(in crate::high_level_api::integers) fn function_evaluation_max_input_bits(&self) -> u32 {
+        self.function_evaluation_max_input_bits
+            .unwrap_or(MAX_FUNCTION_EVALUATION_INPUT_BITS)
+    }
 }
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
@@ -169,6 +220,7 @@ impl IntegerCompressedServerKey {
         IntegerServerKey {
             key: self.key.map(crate::integer::ServerKey::from),
             wopbs_key: None,
+            function_evaluation_max_input_bits: None,
         }
     }
 }
@@ -195,33 +247,27 @@ impl IntegerCompactPublicKey {
         Some(Self { key: Some(key) })
     }
 
-    pub(in crate::high_level_api) fn try_encrypt<T>(
-        &self,
-        value: T,
-        num_blocks: usize,
-    ) -> Option<RadixCiphertext>
+    pub(in crate::high_level_api) fn try_encrypt<P, T>(&self, value: T) -> Option<RadixCiphertext>
     where
-        T: Into<U256>,
+        P: IntegerParameter,
+        T: crate::integer::block_decomposition::DecomposableInto<u64>,
     {
-        let Some(key) = self.key.as_ref() else {
-            return None;
-        };
-        let value = value.into();
+        let key = self.key.as_ref()?;
+        let num_blocks = P::num_blocks(key.key.parameters.message_modulus());
         let ct = key.encrypt_radix(value, num_blocks);
         Some(ct)
     }
 
-    pub(in crate::high_level_api::integers) fn try_encrypt_compact<T>(
+    pub(in crate::high_level_api::integers) fn try_encrypt_compact<P, T>(
         &self,
         values: &[T],
-        num_blocks: usize,
     ) -> Option<CompactCiphertextList>
     where
+        P: IntegerParameter,
         T: crate::integer::block_decomposition::DecomposableInto<u64>,
     {
-        let Some(key) = self.key.as_ref() else {
-            return None;
-        };
+        let key = self.key.as_ref()?;
+        let num_blocks = P::num_blocks(key.key.parameters.message_modulus());
         let ct = key.encrypt_slice_radix_compact(values, num_blocks);
         Some(ct)
     }
@@ -1,13 +1,19 @@
 expand_pub_use_fhe_type!(
     pub use types{
         FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
-        FheUint256
+        FheUint256, FheUint512, FheUint1024, FheUint2048
     };
 );
+pub use types::{
+    AsciiPattern, CompactFheUint, CompactFheUintList, CompressedFheUint, FheAsciiString, FheFloat,
+    FheFloat16, FheFloat32, FheIFixed, FheInt128, FheInt16, FheInt256, FheInt32, FheInt64, FheInt8,
+    FheIntegerBool, FheUFixed, FheUint, LazyInteger,
+};
 
 pub(in crate::high_level_api) use keys::{
     IntegerClientKey, IntegerCompactPublicKey, IntegerCompressedCompactPublicKey,
     IntegerCompressedServerKey, IntegerConfig, IntegerServerKey,
+    MAX_FUNCTION_EVALUATION_INPUT_BITS,
 };
 
 mod client_key;
@@ -2,11 +2,12 @@ use rand::Rng;
 
 use crate::high_level_api::prelude::*;
 use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
-use crate::integer::U256;
+use crate::integer::{U2048, U256, U512};
 use crate::{
     CompactFheUint32, CompactFheUint32List, CompactPublicKey, CompressedFheUint16,
-    CompressedFheUint256, CompressedPublicKey, Config, FheUint128, FheUint16, FheUint256,
-    FheUint32, FheUint64,
+    CompressedFheUint256, CompressedFheUint512, CompressedPublicKey, Config, Error, FheAsciiString,
+    FheFloat16, FheInt16, FheInt8, FheIntegerBool, FheUint, FheUint128, FheUint16, FheUint2048,
+    FheUint256, FheUint32, FheUint512, FheUint64, FunctionEvaluationError, LazyInteger, Seed,
 };
 
 #[test]
@@ -53,86 +54,86 @@ fn test_uint8_compare() {
     // Test comparing encrypted with encrypted
     {
         let result = &a.eq(&b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a == clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a == clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.eq(&a);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a == clear_a);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a == clear_a;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.ne(&b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a != clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a != clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.ne(&a);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a != clear_a);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a != clear_a;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.le(&b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a <= clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a <= clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.lt(&b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a < clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a < clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.ge(&b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a >= clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a >= clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.gt(&b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a > clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a > clear_b;
         assert_eq!(decrypted_result, clear_result);
     }
 
     // Test comparing encrypted with clear
     {
         let result = &a.eq(clear_b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a == clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a == clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.eq(clear_a);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a == clear_a);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a == clear_a;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.ne(clear_b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a != clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a != clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.ne(clear_a);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a != clear_a);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a != clear_a;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.le(clear_b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a <= clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a <= clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.lt(clear_b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a < clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a < clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.ge(clear_b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a >= clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a >= clear_b;
         assert_eq!(decrypted_result, clear_result);
 
         let result = &a.gt(clear_b);
-        let decrypted_result: u8 = result.decrypt(&client_key);
-        let clear_result = u8::from(clear_a > clear_b);
+        let decrypted_result: bool = result.decrypt(&client_key);
+        let clear_result = clear_a > clear_b;
         assert_eq!(decrypted_result, clear_result);
     }
 }
@@ -213,6 +214,772 @@ fn test_uint32() {
     assert_eq!(decrypted, !clear_c);
 }
 
+#[test]
+fn test_uint8_if_then_else() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u8>();
+    let clear_b = rng.gen::<u8>();
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let result = a.le(&b).if_then_else(&a, &b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        if clear_a <= clear_b { clear_a } else { clear_b }
+    );
+
+    let result = a.ge(&b).if_then_else(&a, &b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        if clear_a >= clear_b { clear_a } else { clear_b }
+    );
+
+    let result = FheUint8::if_then_else(&a.lt(&b), &a, &b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        if clear_a < clear_b { clear_a } else { clear_b }
+    );
+
+    let result = FheUint8::select(&a.gt(&b), &a, &b);
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        if clear_a > clear_b { clear_a } else { clear_b }
+    );
+}
+
+#[test]
+fn test_int8_if_then_else() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<i8>();
+    let clear_b = rng.gen::<i8>();
+
+    let a = FheInt8::encrypt(clear_a, &client_key);
+    let b = FheInt8::encrypt(clear_b, &client_key);
+
+    let result = a.le(&b).if_then_else(&a, &b);
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        if clear_a <= clear_b { clear_a } else { clear_b }
+    );
+
+    let result = a.ge(&b).if_then_else(&a, &b);
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(
+        decrypted_result,
+        if clear_a >= clear_b { clear_a } else { clear_b }
+    );
+}
+
+#[test]
+fn test_integer_bool() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 27u8;
+    let clear_b = 128u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let lt = a.lt(&b);
+    let eq = a.eq(clear_a);
+    let ffalse = FheIntegerBool::encrypt(false, &client_key);
+    let ttrue = FheIntegerBool::encrypt_trivial(true);
+
+    let decrypted: bool = (&lt & &eq).decrypt(&client_key);
+    assert!(decrypted);
+    let decrypted: bool = (&lt & &ffalse).decrypt(&client_key);
+    assert!(!decrypted);
+    let decrypted: bool = (&ffalse | &eq).decrypt(&client_key);
+    assert!(decrypted);
+    let decrypted: bool = (&lt ^ &ttrue).decrypt(&client_key);
+    assert!(!decrypted);
+    let decrypted: bool = (!&ffalse).decrypt(&client_key);
+    assert!(decrypted);
+    let decrypted: bool = lt.eq(&eq).decrypt(&client_key);
+    assert!(decrypted);
+    let decrypted: bool = lt.ne(&ffalse).decrypt(&client_key);
+    assert!(decrypted);
+
+    let decrypted: bool = a.ge(&b).decrypt(&client_key);
+    assert!(!decrypted);
+    let decrypted: bool = a.ne(&b).decrypt(&client_key);
+    assert!(decrypted);
+    let decrypted: bool = a.le(clear_a).decrypt(&client_key);
+    assert!(decrypted);
+    let decrypted: bool = b.gt(clear_a).decrypt(&client_key);
+    assert!(decrypted);
+
+    let result = ffalse.if_then_else(&a, &b);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_b);
+
+    let decrypted: u8 = FheUint8::from(lt.clone()).decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+    let decrypted: u16 = FheUint16::from(ffalse).decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+    let decrypted: i8 = FheInt8::from(lt).decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+}
+
+#[test]
+fn test_uint8_checked_ops() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 200u8;
+    let clear_b = 100u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let (result, overflowed) = a.checked_add(&b);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b));
+    assert!(overflowed.decrypt(&client_key));
+
+    let (result, overflowed) = a.checked_sub(&b);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a - clear_b);
+    assert!(!overflowed.decrypt(&client_key));
+
+    let (result, overflowed) = b.checked_sub(&a);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_b.wrapping_sub(clear_a));
+    assert!(overflowed.decrypt(&client_key));
+
+    let (result, overflowed) = a.checked_mul(&b);
+    let decrypted: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_b));
+    assert!(overflowed.decrypt(&client_key));
+}
+
+#[test]
+fn test_uint8_saturating_ops() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 200u8;
+    let clear_b = 100u8;
+    let clear_c = 2u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+    let c = FheUint8::encrypt(clear_c, &client_key);
+
+    let decrypted: u8 = a.saturating_add(&b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.saturating_add(clear_b));
+    let decrypted: u8 = b.saturating_add(&c).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b.saturating_add(clear_c));
+
+    let decrypted: u8 = b.saturating_sub(&a).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b.saturating_sub(clear_a));
+    let decrypted: u8 = a.saturating_sub(&b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.saturating_sub(clear_b));
+
+    let decrypted: u8 = a.saturating_mul(&c).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.saturating_mul(clear_c));
+    let decrypted: u8 = b.saturating_mul(&c).decrypt(&client_key);
+    assert_eq!(decrypted, clear_b.saturating_mul(clear_c));
+}
+
+#[test]
+fn test_uint8_bit_counting() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    for clear in [0u8, 0b0110_1000u8] {
+        let a = FheUint8::encrypt(clear, &client_key);
+
+        let decrypted: u8 = a.leading_zeros().decrypt(&client_key);
+        assert_eq!(u32::from(decrypted), clear.leading_zeros());
+        let decrypted: u8 = a.trailing_zeros().decrypt(&client_key);
+        assert_eq!(u32::from(decrypted), clear.trailing_zeros());
+        let decrypted: u8 = a.count_ones().decrypt(&client_key);
+        assert_eq!(u32::from(decrypted), clear.count_ones());
+        let decrypted: u8 = a.count_zeros().decrypt(&client_key);
+        assert_eq!(u32::from(decrypted), clear.count_zeros());
+        if clear != 0 {
+            let decrypted: u8 = a.ilog2().decrypt(&client_key);
+            assert_eq!(u32::from(decrypted), clear.ilog2());
+        }
+    }
+}
+
+#[test]
+fn test_uint16_sum() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clears = [1234u16, 65000u16, 27u16, 4096u16, 513u16];
+    let values = clears
+        .iter()
+        .map(|&clear| FheUint16::encrypt(clear, &client_key))
+        .collect::<Vec<_>>();
+    let expected = clears.iter().fold(0u16, |acc, &x| acc.wrapping_add(x));
+
+    let sum: FheUint16 = values.iter().sum();
+    let decrypted: u16 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+
+    let sum: FheUint16 = values.into_iter().sum();
+    let decrypted: u16 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+
+    let sum: FheUint16 = std::iter::empty::<FheUint16>().sum();
+    let decrypted: u16 = sum.decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+}
+
+#[test]
+fn test_uint16_dot_product_with_clear() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clears = [1234u16, 65000u16, 27u16];
+    let weights = [3u64, 0u64, 1025u64];
+    let values = clears
+        .iter()
+        .map(|&clear| FheUint16::encrypt(clear, &client_key))
+        .collect::<Vec<_>>();
+    let expected = clears
+        .iter()
+        .zip(weights.iter())
+        .fold(0u16, |acc, (&clear, &weight)| {
+            acc.wrapping_add(clear.wrapping_mul(weight as u16))
+        });
+
+    let result = FheUint16::dot_product_with_clear(&values, &weights);
+    let decrypted: u16 = result.decrypt(&client_key);
+    assert_eq!(decrypted, expected);
+}
+
+#[test]
+fn test_uint8_pow_and_isqrt() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 7u8;
+    let clear_b = 3u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let decrypted: u8 = a.pow(&b).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_pow(u32::from(clear_b)));
+    let decrypted: u8 = a.scalar_pow(5).decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_pow(5));
+    let decrypted: u8 = a.scalar_pow(0).decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+
+    let c = FheUint8::encrypt(250u8, &client_key);
+    let decrypted: u8 = c.isqrt().decrypt(&client_key);
+    assert_eq!(decrypted, 15);
+}
+
+#[test]
+fn test_generic_width_uint() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_custom_integers(
+            crate::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let public_key = CompactPublicKey::new(&client_key);
+
+    let mut rng = rand::thread_rng();
+    let modulus = 1u32 << 24;
+    let clear_a = rng.gen::<u32>() % modulus;
+    let clear_b = rng.gen::<u32>() % modulus;
+
+    let a = FheUint::<24>::encrypt(clear_a, &client_key);
+    assert_eq!(a.ciphertext.blocks.len(), 12);
+    let b = FheUint::<24>::try_encrypt(clear_b, &public_key).unwrap();
+
+    let c = &a + &b;
+    let decrypted: u32 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b) % modulus);
+
+    let compressed = crate::CompressedFheUint::<24>::encrypt(clear_a, &client_key);
+    let decompressed = compressed.decompress();
+    let decrypted: u32 = decompressed.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a);
+
+    let clears = [clear_a, clear_b];
+    let compact_list = crate::CompactFheUintList::<24>::encrypt(clears.as_slice(), &public_key);
+    let expanded = compact_list.expand();
+    assert_eq!(expanded.len(), clears.len());
+    for (encrypted, clear) in expanded.iter().zip(clears) {
+        let decrypted: u32 = encrypted.decrypt(&client_key);
+        assert_eq!(decrypted, clear);
+    }
+
+    // Casting to a wider type keeps the value, casting to a narrower one truncates it
+    let wide: FheUint32 = a.clone().cast_into();
+    let decrypted: u32 = wide.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a);
+    let narrow = FheUint::<24>::cast_from(FheUint32::encrypt(u32::MAX, &client_key));
+    let decrypted: u32 = narrow.decrypt(&client_key);
+    assert_eq!(decrypted, modulus - 1);
+}
+
+#[test]
+fn test_generic_width_uint_blocks_from_parameters() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_custom_integers(
+            crate::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    // With 1 bit per block, 10 blocks are needed
+    let a = FheUint::<10>::encrypt(1000u16, &client_key);
+    assert_eq!(a.ciphertext.blocks.len(), 10);
+    let decrypted: u16 = a.decrypt(&client_key);
+    assert_eq!(decrypted, 1000);
+
+    let b = FheUint::<10>::encrypt_trivial(24u16);
+    let c = &a + &b;
+    let decrypted: u16 = c.decrypt(&client_key);
+    assert_eq!(decrypted, 0);
+}
+
+#[test]
+fn test_ufixed() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    type Fixed = crate::FheUFixed<8, 8>;
+
+    let clear_a = 13.25;
+    let clear_b = 2.5;
+    let a = Fixed::try_encrypt(clear_a, &client_key).unwrap();
+    let b = Fixed::try_encrypt(clear_b, &client_key).unwrap();
+
+    let clear: f64 = a.decrypt(&client_key);
+    assert_eq!(clear, clear_a);
+
+    let clear: f64 = (&a + &b).decrypt(&client_key);
+    assert_eq!(clear, clear_a + clear_b);
+
+    let clear: f64 = (&a - &b).decrypt(&client_key);
+    assert_eq!(clear, clear_a - clear_b);
+
+    let clear: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(clear, clear_a * clear_b);
+
+    // The quotient is truncated to 8 fractional bits
+    let clear: f64 = (&a / &b).decrypt(&client_key);
+    assert_eq!(clear, ((clear_a / clear_b) * 256.0).floor() / 256.0);
+
+    // Values are rounded to the nearest representable value
+    let c = Fixed::try_encrypt(0.1, &client_key).unwrap();
+    let clear: f64 = c.decrypt(&client_key);
+    assert_eq!(clear, 26.0 / 256.0);
+
+    // Fractional bits of the product are truncated
+    let clear: f64 = (&c * &c).decrypt(&client_key);
+    assert_eq!(clear, 2.0 / 256.0);
+
+    let mut d = Fixed::encrypt_trivial(200.0);
+    d *= &b;
+    let clear: f64 = d.decrypt(&client_key);
+    assert_eq!(clear, 500.0 % 256.0);
+
+    let is_lower = a.lt(&b);
+    assert!(!is_lower.decrypt(&client_key));
+    let is_greater = a.gt(&b);
+    assert!(is_greater.decrypt(&client_key));
+
+    let clear: f64 = a.max(&b).decrypt(&client_key);
+    assert_eq!(clear, clear_a);
+}
+
+#[test]
+fn test_ifixed() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    type Fixed = crate::FheIFixed<8, 8>;
+
+    let clear_a = -13.25;
+    let clear_b = 2.5;
+    let a = Fixed::try_encrypt(clear_a, &client_key).unwrap();
+    let b = Fixed::try_encrypt(clear_b, &client_key).unwrap();
+
+    let clear: f64 = a.decrypt(&client_key);
+    assert_eq!(clear, clear_a);
+
+    let clear: f64 = (&a + &b).decrypt(&client_key);
+    assert_eq!(clear, clear_a + clear_b);
+
+    let clear: f64 = (&a - &b).decrypt(&client_key);
+    assert_eq!(clear, clear_a - clear_b);
+
+    let clear: f64 = (&a * &b).decrypt(&client_key);
+    assert_eq!(clear, clear_a * clear_b);
+
+    // The quotient is rounded towards zero
+    let clear: f64 = (&a / &b).decrypt(&client_key);
+    assert_eq!(clear, ((clear_a / clear_b) * 256.0).trunc() / 256.0);
+
+    // Values are rounded to the nearest representable value
+    let c = Fixed::try_encrypt(-0.1, &client_key).unwrap();
+    let clear: f64 = c.decrypt(&client_key);
+    assert_eq!(clear, -26.0 / 256.0);
+
+    // The product is rounded towards negative infinity
+    let clear: f64 = (&c * &c).decrypt(&client_key);
+    assert_eq!(clear, 2.0 / 256.0);
+    let d = Fixed::try_encrypt(0.1, &client_key).unwrap();
+    let clear: f64 = (&c * &d).decrypt(&client_key);
+    assert_eq!(clear, -3.0 / 256.0);
+
+    let mut e = Fixed::encrypt_trivial(100.0);
+    e *= &b;
+    let clear: f64 = e.decrypt(&client_key);
+    assert_eq!(clear, 250.0 - 256.0);
+
+    let is_lower = a.lt(&b);
+    assert!(is_lower.decrypt(&client_key));
+    let is_greater = a.gt(&b);
+    assert!(!is_greater.decrypt(&client_key));
+
+    let clear: f64 = a.max(&b).decrypt(&client_key);
+    assert_eq!(clear, clear_b);
+    let clear: f64 = a.min(&b).decrypt(&client_key);
+    assert_eq!(clear, clear_a);
+}
+
+#[test]
+fn test_float16() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    eprintln!("FLOAT16 step 4 {:?}", std::time::Instant::now());
+    set_server_key(server_key);
+    eprintln!("FLOAT16 step 5 {:?}", std::time::Instant::now());
+
+    let clear_a = 6.5f64;
+    eprintln!("FLOAT16 step 7 {:?}", std::time::Instant::now());
+    let clear_b = -1.75f64;
+    eprintln!("FLOAT16 step 8 {:?}", std::time::Instant::now());
+    let a = FheFloat16::try_encrypt(clear_a, &client_key).unwrap();
+    eprintln!("FLOAT16 step 9 {:?}", std::time::Instant::now());
+    let b = FheFloat16::try_encrypt(clear_b, &client_key).unwrap();
+    eprintln!("FLOAT16 step 10 {:?}", std::time::Instant::now());
+
+    let clear: f64 = a.decrypt(&client_key);
+    eprintln!("FLOAT16 step 12 {:?}", std::time::Instant::now());
+    assert_eq!(clear, clear_a);
+    eprintln!("FLOAT16 step 13 {:?}", std::time::Instant::now());
+    let clear: f64 = b.decrypt(&client_key);
+    eprintln!("FLOAT16 step 14 {:?}", std::time::Instant::now());
+    assert_eq!(clear, clear_b);
+    eprintln!("FLOAT16 step 15 {:?}", std::time::Instant::now());
+
+    let clear: f64 = (&a + &b).decrypt(&client_key);
+    eprintln!("FLOAT16 step 17 {:?}", std::time::Instant::now());
+    assert_eq!(clear, clear_a + clear_b);
+    eprintln!("FLOAT16 step 18 {:?}", std::time::Instant::now());
+
+    let clear: f64 = (&b - &a).decrypt(&client_key);
+    eprintln!("FLOAT16 step 20 {:?}", std::time::Instant::now());
+    assert_eq!(clear, clear_b - clear_a);
+    eprintln!("FLOAT16 step 21 {:?}", std::time::Instant::now());
+
+    let clear: f64 = (&a * &b).decrypt(&client_key);
+    eprintln!("FLOAT16 step 23 {:?}", std::time::Instant::now());
+    assert_eq!(clear, clear_a * clear_b);
+    eprintln!("FLOAT16 step 24 {:?}", std::time::Instant::now());
+
+    // Opposite values cancel out to a positive zero
+    let clear: f64 = (&a + &(-&a)).decrypt(&client_key);
+    eprintln!("FLOAT16 step 27 {:?}", std::time::Instant::now());
+    assert_eq!(clear, 0.0);
+    eprintln!("FLOAT16 step 28 {:?}", std::time::Instant::now());
+    assert!(clear.is_sign_positive());
+    eprintln!("FLOAT16 step 29 {:?}", std::time::Instant::now());
+
+    let is_lower = b.lt(&a);
+    eprintln!("FLOAT16 step 31 {:?}", std::time::Instant::now());
+    assert!(is_lower.decrypt(&client_key));
+    eprintln!("FLOAT16 step 32 {:?}", std::time::Instant::now());
+    let is_equal = a.eq(&b);
+    eprintln!("FLOAT16 step 33 {:?}", std::time::Instant::now());
+    assert!(!is_equal.decrypt(&client_key));
+    eprintln!("FLOAT16 step 34 {:?}", std::time::Instant::now());
+
+    // Comparisons with clear values, of both signs, some of them out of the range of FheFloat16
+    for clear in [clear_a, clear_b, 3.0, -4.0, 0.0, 1e10, -1e10, f64::INFINITY] {
+        assert_eq!(a.eq(clear).decrypt(&client_key), clear_a == clear);
+        assert_eq!(b.ne(clear).decrypt(&client_key), clear_b != clear);
+        assert_eq!(b.lt(clear).decrypt(&client_key), clear_b < clear);
+        assert_eq!(a.le(clear).decrypt(&client_key), clear_a <= clear);
+        assert_eq!(b.gt(clear).decrypt(&client_key), clear_b > clear);
+        assert_eq!(a.ge(clear).decrypt(&client_key), clear_a >= clear);
+        eprintln!("FLOAT16 clear {clear}");
+    }
+
+    assert!(FheFloat16::try_encrypt(1e10, &client_key).is_err());
+    eprintln!("FLOAT16 step 46 {:?}", std::time::Instant::now());
+
+    let c = FheUint8::try_encrypt(200u8, &client_key).unwrap();
+    eprintln!("FLOAT16 step 48 {:?}", std::time::Instant::now());
+    let c = FheFloat16::from_uint(&c);
+    eprintln!("FLOAT16 step 49 {:?}", std::time::Instant::now());
+    let clear: f64 = c.decrypt(&client_key);
+    eprintln!("FLOAT16 step 50 {:?}", std::time::Instant::now());
+    assert_eq!(clear, 200.0);
+    eprintln!("FLOAT16 step 51 {:?}", std::time::Instant::now());
+
+    let d: FheUint8 = (&c + &a).to_uint();
+    eprintln!("FLOAT16 step 53 {:?}", std::time::Instant::now());
+    let clear: u8 = d.decrypt(&client_key);
+    eprintln!("FLOAT16 step 54 {:?}", std::time::Instant::now());
+    assert_eq!(clear, 206);
+    eprintln!("FLOAT16 step 55 {:?}", std::time::Instant::now());
+}
+
+#[test]
+fn test_uint8_array_access() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clears = [14u8, 97, 3, 42, 255];
+    let mut values = clears
+        .iter()
+        .map(|&clear| FheUint8::encrypt(clear, &client_key))
+        .collect::<Vec<_>>();
+
+    for index in [0u8, 3, 7] {
+        let encrypted_index = FheUint8::encrypt(index, &client_key);
+        let expected = clears.get(index as usize).copied().unwrap_or(0);
+
+        let result = FheUint8::select_at_index(&values, &encrypted_index);
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+
+        let result = FheUint8::select_at_index_tree(&values, &encrypted_index);
+        let decrypted: u8 = result.decrypt(&client_key);
+        assert_eq!(decrypted, expected);
+    }
+
+    // The index can have a different type than the values
+    let index = FheUint16::encrypt(1u16, &client_key);
+    let value = FheUint8::encrypt(128u8, &client_key);
+    FheUint8::write_at_index(&mut values, &index, &value);
+    let decrypted = values
+        .iter()
+        .map(|value| value.decrypt(&client_key))
+        .collect::<Vec<u8>>();
+    assert_eq!(decrypted, vec![14, 128, 3, 42, 255]);
+}
+
+#[test]
+fn test_uint8_sort_and_arg_extrema() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clears = [42u8, 7, 255, 7, 128];
+    let values = clears
+        .iter()
+        .map(|&clear| FheUint8::encrypt(clear, &client_key))
+        .collect::<Vec<_>>();
+
+    let sorted = FheUint8::sort(&values);
+    let decrypted = sorted
+        .iter()
+        .map(|value| value.decrypt(&client_key))
+        .collect::<Vec<u8>>();
+    assert_eq!(decrypted, vec![7, 7, 42, 128, 255]);
+
+    let top = FheUint8::top_k(&values, 2);
+    let decrypted = top
+        .iter()
+        .map(|value| value.decrypt(&client_key))
+        .collect::<Vec<u8>>();
+    assert_eq!(decrypted, vec![255, 128]);
+
+    let decrypted: u8 = FheUint8::argmax(&values).decrypt(&client_key);
+    assert_eq!(decrypted, 2);
+    let decrypted: u8 = FheUint8::argmin(&values).decrypt(&client_key);
+    assert_eq!(decrypted, 1);
+}
+
+#[test]
+fn test_uint16_bits() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear = 0b1100_0101_0011_1010u16;
+    let a = FheUint16::encrypt(clear, &client_key);
+
+    let bits = a.to_bits();
+    assert_eq!(bits.len(), 16);
+    let decrypted = bits
+        .iter()
+        .map(|bit| bit.decrypt(&client_key))
+        .collect::<Vec<bool>>();
+    let expected = (0..16).map(|i| (clear >> i) & 1 == 1).collect::<Vec<_>>();
+    assert_eq!(decrypted, expected);
+
+    let result = FheUint16::from_bits(&bits);
+    let decrypted: u16 = result.decrypt(&client_key);
+    assert_eq!(decrypted, clear);
+
+    // Bits from comparisons can be packed too
+    let b = FheUint16::encrypt(1000u16, &client_key);
+    let bits = [a.gt(&b), a.lt(&b), a.eq(&b), bits[1].clone()];
+    let result = FheUint16::from_bits(&bits);
+    let decrypted: u16 = result.decrypt(&client_key);
+    assert_eq!(decrypted, 0b1001);
+
+    // And so can bits computed with boolean operations
+    let bits = [
+        &bits[0] ^ &bits[3],
+        &bits[0] & &bits[3],
+        &bits[1] | &bits[3],
+        !&bits[3],
+        bits[0].eq(&bits[1]),
+        bits[0].nand(&bits[3]),
+    ];
+    let result = FheUint16::from_bits(&bits);
+    let decrypted: u16 = result.decrypt(&client_key);
+    assert_eq!(decrypted, 0b00_0110);
+}
+
+#[test]
+fn test_ascii_string() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    set_server_key(server_key);
+
+    let s = FheAsciiString::try_encrypt_with_padding("Hello", 2, &client_key).unwrap();
+    let decrypted: String = s.decrypt(&client_key);
+    assert_eq!(decrypted, "Hello");
+    assert_eq!(s.max_len(), 7);
+
+    assert!(FheAsciiString::try_encrypt("h\u{e9}", &client_key).is_err());
+    assert!(FheAsciiString::try_encrypt("a\0b", &client_key).is_err());
+
+    let pattern = FheAsciiString::try_encrypt_with_padding("ll", 1, &client_key).unwrap();
+    assert!(s.contains(&pattern).decrypt(&client_key));
+    assert!(!s.contains("lo!").decrypt(&client_key));
+    assert!(s.starts_with("He").decrypt(&client_key));
+    assert!(!s.starts_with(&pattern).decrypt(&client_key));
+
+    let (found, position) = s.find("l");
+    assert!(found.decrypt(&client_key));
+    let position: u16 = position.decrypt(&client_key);
+    assert_eq!(position, 2);
+
+    let (found, _) = s.find("x");
+    assert!(!found.decrypt(&client_key));
+
+    let decrypted: String = s.to_upper().decrypt(&client_key);
+    assert_eq!(decrypted, "HELLO");
+    let decrypted: String = s.to_lower().decrypt(&client_key);
+    assert_eq!(decrypted, "hello");
+
+    let suffix = FheAsciiString::try_encrypt(" you", &client_key).unwrap();
+    let concatenated = &s + &suffix;
+    let decrypted: String = concatenated.decrypt(&client_key);
+    assert_eq!(decrypted, "Hello you");
+
+    assert!(s.eq("Hello").decrypt(&client_key));
+    assert!(s.ne(&suffix).decrypt(&client_key));
+    assert!(s.lt("Help").decrypt(&client_key));
+    assert!(s.gt("Hell").decrypt(&client_key));
+    assert!(s.ge(&s).decrypt(&client_key));
+}
+
 fn fhe_uint32_shift(config: Config) {
     let (cks, sks) = generate_keys(config);
 
@@ -568,6 +1335,58 @@ fn test_trivial_fhe_uint256_small() {
     assert_eq!(clear, clear_a);
 }
 
+#[test]
+fn test_uint512() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_custom_integers(
+            crate::shortint::parameters::parameters_compact_pk::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+            None,
+        )
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+    let public_key = CompactPublicKey::new(&client_key);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<U512>();
+    let clear_b = rng.gen::<U512>();
+
+    let a = FheUint512::try_encrypt(clear_a, &client_key).unwrap();
+    let b = FheUint512::try_encrypt(clear_b, &public_key).unwrap();
+    let compressed_b = CompressedFheUint512::try_encrypt(clear_b, &client_key).unwrap();
+
+    let clear: U512 = a.decrypt(&client_key);
+    assert_eq!(clear, clear_a);
+    let clear: U512 = b.decrypt(&client_key);
+    assert_eq!(clear, clear_b);
+    let clear: U512 = FheUint512::from(compressed_b).decrypt(&client_key);
+    assert_eq!(clear, clear_b);
+
+    let c = &a + &b;
+    let clear: U512 = c.decrypt(&client_key);
+    assert_eq!(clear, clear_a + clear_b);
+
+    let casted: FheUint64 = c.cast_into();
+    let clear: u64 = casted.decrypt(&client_key);
+    assert_eq!(clear, (clear_a + clear_b).0[0]);
+}
+
+#[test]
+fn test_trivial_fhe_uint2048_small() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers_small()
+        .build();
+    let (client_key, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let clear_a = U2048::MAX - U2048::from(u128::MAX);
+    let a = FheUint2048::try_encrypt_trivial(clear_a).unwrap();
+    let clear: U2048 = a.decrypt(&client_key);
+    assert_eq!(clear, clear_a);
+}
+
 #[test]
 fn test_integer_casting() {
     let config = ConfigBuilder::all_disabled()
@@ -618,3 +1437,302 @@ fn test_integer_casting() {
         assert_eq!(da, clear);
     }
 }
+
+#[test]
+fn test_quickstart_int8() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = -27i8;
+    let clear_b = 100i8;
+
+    let a = FheInt8::encrypt(clear_a, &client_key);
+    let b = FheInt8::encrypt(clear_b, &client_key);
+
+    let result = &a + &b;
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_add(clear_b));
+
+    let result = &a - &b;
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_sub(clear_b));
+
+    let result = &a * &b;
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_mul(clear_b));
+
+    let result = &b / &a;
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_b / clear_a);
+
+    let result = &b % &a;
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_b % clear_a);
+
+    let result = -&b;
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, -clear_b);
+
+    let result = a.abs();
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.abs());
+
+    let result = &a >> 2u8;
+    let decrypted_result: i8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a >> 2);
+}
+
+#[test]
+fn test_int16_compare() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = -1_234i16;
+    let clear_b = 789i16;
+
+    let a = FheInt16::encrypt(clear_a, &client_key);
+    let b = FheInt16::encrypt(clear_b, &client_key);
+
+    let result = a.eq(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a == clear_b);
+
+    let result = a.ne(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a != clear_b);
+
+    let result = a.lt(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a < clear_b);
+
+    let result = a.le(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a <= clear_b);
+
+    let result = a.gt(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a > clear_b);
+
+    let result = a.ge(&b);
+    let decrypted_result: bool = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a >= clear_b);
+
+    let result = a.min(&b);
+    let decrypted_result: i16 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.min(clear_b));
+
+    let result = a.max(&b);
+    let decrypted_result: i16 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.max(clear_b));
+}
+
+#[test]
+fn test_trivial_fhe_int8() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let a = FheInt8::try_encrypt_trivial(-112i8).unwrap();
+
+    let clear: i8 = a.decrypt(&client_key);
+    assert_eq!(clear, -112);
+}
+
+#[test]
+fn test_lazy_uint8() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let clear_a = 213u8;
+    let clear_b = 98u8;
+    let clear_c = 31u8;
+
+    let a = FheUint8::encrypt(clear_a, &client_key).lazy();
+    let b = FheUint8::encrypt(clear_b, &client_key).lazy();
+    let c = FheUint8::encrypt(clear_c, &client_key).lazy();
+
+    // Long chain of additions, carries must be propagated in the middle
+    let sum = (0..10).fold(a.clone(), |acc, _| acc + &b - &c);
+    let clear_sum = (0..10).fold(clear_a, |acc, _| {
+        acc.wrapping_add(clear_b).wrapping_sub(clear_c)
+    });
+    let result: FheUint8 = sum.eval();
+    let decrypted_result: u8 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_sum);
+
+    // Shared sub-expression, used in linear and non-linear nodes
+    let product = &a * &b;
+    let first = &product + &c - 17u8;
+    let second = (-&product ^ &c) * 3u8;
+    let third = -(-&product);
+    // Non-linear nodes consuming results with non empty carries
+    let fourth = ((&a + &b) * 3u8) & (&b - &c);
+
+    let results = LazyInteger::eval_all(&[first, second, third, fourth]);
+    let decrypted_results = results
+        .iter()
+        .map(|result| result.decrypt(&client_key))
+        .collect::<Vec<u8>>();
+
+    let clear_product = clear_a.wrapping_mul(clear_b);
+    assert_eq!(
+        decrypted_results,
+        vec![
+            clear_product.wrapping_add(clear_c).wrapping_sub(17),
+            (clear_product.wrapping_neg() ^ clear_c).wrapping_mul(3),
+            clear_product,
+            clear_a.wrapping_add(clear_b).wrapping_mul(3) & clear_b.wrapping_sub(clear_c),
+        ]
+    );
+}
+
+#[test]
+fn test_oblivious_pseudo_random_uint8() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..10 {
+        let seed = Seed(rng.gen::<u128>());
+
+        let a = FheUint8::generate_oblivious_pseudo_random(seed);
+        let b = FheUint8::generate_oblivious_pseudo_random(seed);
+        let decrypted_a: u8 = a.decrypt(&client_key);
+        let decrypted_b: u8 = b.decrypt(&client_key);
+        assert_eq!(decrypted_a, decrypted_b);
+
+        let c = FheUint8::generate_oblivious_pseudo_random_bits(seed, 2);
+        let decrypted_c: u8 = c.decrypt(&client_key);
+        assert!(decrypted_c < 4);
+    }
+
+    let d = FheUint8::generate_oblivious_pseudo_random_bounded(Seed(rng.gen::<u128>()), 6);
+    let decrypted_d: u8 = d.decrypt(&client_key);
+    assert!(decrypted_d < 6);
+}
+
+#[test]
+fn test_uint8_function_evaluation() {
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .enable_function_evaluation_integers()
+        .build();
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u8>();
+    let clear_b = rng.gen::<u8>();
+
+    let a = FheUint8::encrypt(clear_a, &client_key);
+    let b = FheUint8::encrypt(clear_b, &client_key);
+
+    let c = a.map(|x| x * x + 3);
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_mul(clear_a).wrapping_add(3));
+
+    // Inputs with non empty carries
+    let c = (&a + &b).map(|x| x / 3);
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.wrapping_add(clear_b) / 3);
+
+    let c = a.bivariate_map(&b, |x, y| x.max(y) - x.min(y));
+    let decrypted: u8 = c.decrypt(&client_key);
+    assert_eq!(decrypted, clear_a.abs_diff(clear_b));
+}
+
+#[test]
+fn test_function_evaluation_config_errors() {
+    let result = ConfigBuilder::all_disabled()
+        .enable_default_integers_small()
+        .try_enable_function_evaluation_integers(16);
+    assert_eq!(
+        result.err(),
+        Some(Error::FunctionEvaluation(
+            FunctionEvaluationError::UnsupportedPBSOrder
+        ))
+    );
+
+    // There are no WoPBS parameters for blocks this wide
+    let block_parameters = crate::shortint::ClassicPBSParameters {
+        carry_modulus: crate::shortint::CarryModulus(4),
+        ..crate::shortint::parameters::PARAM_MESSAGE_8_CARRY_0_KS_PBS
+    };
+    let result = ConfigBuilder::all_disabled()
+        .enable_custom_integers(block_parameters, None)
+        .try_enable_function_evaluation_integers(16);
+    assert_eq!(
+        result.err(),
+        Some(Error::FunctionEvaluation(
+            FunctionEvaluationError::UnsupportedBlockWidth {
+                message_modulus: 256,
+                carry_modulus: 4,
+            }
+        ))
+    );
+
+    // bivariate_map on FheUint16
+    let result = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .try_enable_function_evaluation_integers(32);
+    assert_eq!(
+        result.err(),
+        Some(Error::FunctionEvaluation(
+            FunctionEvaluationError::InputTooWide {
+                max_input_bits: 32,
+                supported_input_bits: 16,
+            }
+        ))
+    );
+
+    let result = ConfigBuilder::all_disabled().try_enable_function_evaluation_integers(16);
+    assert_eq!(
+        result.err(),
+        Some(Error::FunctionEvaluation(
+            FunctionEvaluationError::IntegersNotEnabled
+        ))
+    );
+
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .try_enable_function_evaluation_integers(16)
+        .unwrap()
+        .build();
+    assert!(config.integer_config.wopbs_block_parameters.is_some());
+
+    // Function evaluation stays enabled when the integer parameters change
+    let config = ConfigBuilder::all_disabled()
+        .enable_default_integers()
+        .enable_function_evaluation_integers()
+        .enable_custom_integers(
+            crate::shortint::parameters::PARAM_MESSAGE_1_CARRY_1_KS_PBS,
+            None,
+        )
+        .build();
+    assert_eq!(
+        config.integer_config.wopbs_block_parameters,
+        Some(crate::shortint::parameters::parameters_wopbs_message_carry::WOPBS_PARAM_MESSAGE_1_CARRY_1_KS_PBS)
+    );
+}
@@ -0,0 +1,740 @@
+use std::borrow::Borrow;
+use std::ops::Add;
+
+use rayon::prelude::*;
+
+use crate::errors::{UninitializedClientKey, UnwrapResultExt};
+use crate::high_level_api::errors::Error;
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::internal_traits::TypeIdentifier;
+use crate::high_level_api::traits::{FheDecrypt, FheEq, FheOrd, FheTryEncrypt};
+use crate::high_level_api::ClientKey;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+
+use super::boolean::FheIntegerBool;
+use super::static_::{
+    FheUint16, FheUint16Id, FheUint16Parameters, FheUint8, FheUint8Id, FheUint8Parameters,
+};
+
+/// An encrypted ASCII string
+///
+/// The string is stored as a sequence of [FheUint8], one per character.
+///
+/// To hide the length of the string, it can be encrypted with some padding
+/// (see [FheAsciiString::try_encrypt_with_padding]): the padding is made of encrypted
+/// NUL characters added at the end of the string, so that only an upper bound of the length is
+/// known. For that reason, the strings cannot contain NUL characters.
+///
+/// All the operations work with padded strings, however some of them are faster
+/// when the strings are not padded, see their documentation.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let email = FheAsciiString::try_encrypt_with_padding("bob@zama.ai", 4, &client_key).unwrap();
+///
+/// let is_from_domain = email.contains("@zama");
+///
+/// let decrypted = is_from_domain.decrypt(&client_key);
+/// assert!(decrypted);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheAsciiString {
+    chars: Vec<FheUint8>,
+    /// Whether the last characters may be padding
+    padded: bool,
+}
+
+/// A pattern to look for in a [FheAsciiString]
+///
+/// It is usually not created directly, as it can be converted from
+/// a `&FheAsciiString` or a `&str`.
+#[derive(Copy, Clone)]
+pub enum AsciiPattern<'a> {
+    Encrypted(&'a FheAsciiString),
+    Clear(&'a str),
+}
+
+impl<'a> From<&'a FheAsciiString> for AsciiPattern<'a> {
+    fn from(value: &'a FheAsciiString) -> Self {
+        Self::Encrypted(value)
+    }
+}
+
+impl<'a> From<&'a str> for AsciiPattern<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::Clear(value)
+    }
+}
+
+impl<'a> AsciiPattern<'a> {
+    /// Returns the number of characters of the pattern, including the padding
+    fn len(&self) -> usize {
+        match self {
+            Self::Encrypted(pattern) => pattern.chars.len(),
+            Self::Clear(pattern) => pattern.len(),
+        }
+    }
+
+    fn is_padded(&self) -> bool {
+        match self {
+            Self::Encrypted(pattern) => pattern.padded,
+            Self::Clear(_) => false,
+        }
+    }
+
+    /// Returns an encryption of 1 if `ct` is the character of the pattern at `index`,
+    /// padding characters of the pattern match any character
+    fn matches_char_at(
+        &self,
+        server_key: &ServerKey,
+        ct: &RadixCiphertext,
+        index: usize,
+    ) -> RadixCiphertext {
+        match self {
+            Self::Encrypted(pattern) => {
+                let pattern_char = &pattern.chars[index].ciphertext;
+                if pattern.padded {
+                    let (is_same, is_padding) = rayon::join(
+                        || to_condition(server_key.eq_block_parallelized(ct, pattern_char)),
+                        || {
+                            to_condition(
+                                server_key.scalar_eq_block_parallelized(pattern_char, 0u64),
+                            )
+                        },
+                    );
+                    server_key.bitor_parallelized(&is_same, &is_padding)
+                } else {
+                    to_condition(server_key.eq_block_parallelized(ct, pattern_char))
+                }
+            }
+            Self::Clear(pattern) => {
+                let pattern_char = pattern.as_bytes()[index];
+                to_condition(server_key.scalar_eq_block_parallelized(ct, pattern_char))
+            }
+        }
+    }
+
+    /// Returns an encryption of 1 if the character of the pattern at `index` is padding
+    fn is_padding_at(&self, server_key: &ServerKey, index: usize) -> RadixCiphertext {
+        match self {
+            Self::Encrypted(pattern) if pattern.padded => to_condition(
+                server_key.scalar_eq_block_parallelized(&pattern.chars[index].ciphertext, 0u64),
+            ),
+            _ => server_key.create_trivial_zero_radix(1),
+        }
+    }
+
+    fn check(&self) {
+        if let Self::Clear(pattern) = self {
+            assert!(
+                is_valid_ascii_string(pattern),
+                "The pattern must only contain non NUL ASCII characters"
+            );
+        }
+    }
+}
+
+fn is_valid_ascii_string(value: &str) -> bool {
+    value.is_ascii() && !value.contains('\0')
+}
+
+/// Wraps the block of a comparison result in a one-block integer, so it can be used as a condition
+fn to_condition(comparison_result: Ciphertext) -> RadixCiphertext {
+    RadixCiphertext::from(vec![comparison_result])
+}
+
+/// Returns an encryption of 1 if all the conditions encrypt 1
+fn all_conditions(server_key: &ServerKey, conditions: Vec<RadixCiphertext>) -> RadixCiphertext {
+    conditions
+        .into_par_iter()
+        .reduce_with(|lhs, rhs| server_key.bitand_parallelized(&lhs, &rhs))
+        .unwrap_or_else(|| server_key.create_trivial_radix(1u64, 1))
+}
+
+/// Returns an encryption of 1 if any of the conditions encrypts 1
+fn any_condition(server_key: &ServerKey, conditions: Vec<RadixCiphertext>) -> RadixCiphertext {
+    conditions
+        .into_par_iter()
+        .reduce_with(|lhs, rhs| server_key.bitor_parallelized(&lhs, &rhs))
+        .unwrap_or_else(|| server_key.create_trivial_zero_radix(1))
+}
+
+impl FheAsciiString {
+    /// Encrypts the string, followed by `padding` encrypted NUL characters
+    ///
+    /// Returns an error if the string contains characters that are not ASCII, or NUL characters.
+    pub fn try_encrypt_with_padding(
+        value: &str,
+        padding: usize,
+        key: &ClientKey,
+    ) -> Result<Self, Error> {
+        if !is_valid_ascii_string(value) {
+            return Err(Error::OutOfRange);
+        }
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(FheUint8Id.type_variant()))?;
+        let num_blocks =
+            FheUint8Parameters::num_blocks(integer_client_key.parameters().message_modulus());
+
+        let chars = value
+            .bytes()
+            .chain(std::iter::repeat(0).take(padding))
+            .map(|char| {
+                let ciphertext = integer_client_key.encrypt_radix(char, num_blocks);
+                FheUint8::new(ciphertext, FheUint8Id)
+            })
+            .collect();
+        Ok(Self {
+            chars,
+            padded: padding > 0,
+        })
+    }
+
+    /// Returns the characters of the string, including the padding
+    pub fn chars(&self) -> &[FheUint8] {
+        &self.chars
+    }
+
+    /// Returns the number of encrypted characters, including the padding
+    ///
+    /// This is an upper bound of the length of the string.
+    pub fn max_len(&self) -> usize {
+        self.chars.len()
+    }
+
+    fn char_blocks(&self, server_key: &ServerKey) -> usize {
+        self.chars.first().map_or_else(
+            || FheUint8Parameters::num_blocks(server_key.key.message_modulus),
+            |char| char.ciphertext.blocks.len(),
+        )
+    }
+
+    /// Returns the string as a single integer of `num_chars` characters,
+    /// the first character being the most significant
+    ///
+    /// Missing characters are NUL characters, so the order of the integers
+    /// is the lexicographic order of the strings.
+    fn to_lexicographic_integer(
+        &self,
+        server_key: &ServerKey,
+        num_chars: usize,
+    ) -> RadixCiphertext {
+        let char_blocks = self.char_blocks(server_key);
+        let mut blocks = server_key
+            .create_trivial_zero_radix(char_blocks * (num_chars - self.chars.len()))
+            .blocks;
+        for char in self.chars.iter().rev() {
+            blocks.extend(char.ciphertext.blocks.iter().cloned());
+        }
+        RadixCiphertext::from(blocks)
+    }
+
+    fn trivial(server_key: &ServerKey, value: &str, num_blocks: usize) -> Self {
+        let chars = value
+            .bytes()
+            .map(|char| {
+                let ciphertext = server_key.create_trivial_radix(char, num_blocks);
+                FheUint8::new(ciphertext, FheUint8Id)
+            })
+            .collect();
+        Self {
+            chars,
+            padded: false,
+        }
+    }
+
+    /// Returns the encryption of 1 if the pattern is found at `offset`
+    fn matches_at(
+        &self,
+        server_key: &ServerKey,
+        pattern: AsciiPattern<'_>,
+        offset: usize,
+    ) -> RadixCiphertext {
+        let conditions = (0..pattern.len())
+            .into_par_iter()
+            .map(|i| match self.chars.get(offset + i) {
+                Some(char) => pattern.matches_char_at(server_key, &char.ciphertext, i),
+                // Beyond the end of self, the pattern must be padding
+                None => pattern.is_padding_at(server_key, i),
+            })
+            .collect::<Vec<_>>();
+        all_conditions(server_key, conditions)
+    }
+
+    /// Returns the offsets at which the pattern may be found
+    fn candidate_offsets(&self, pattern: AsciiPattern<'_>) -> std::ops::RangeInclusive<usize> {
+        if pattern.is_padded() {
+            // The real pattern may be shorter, or even empty
+            0..=self.chars.len()
+        } else if pattern.len() <= self.chars.len() {
+            0..=self.chars.len() - pattern.len()
+        } else {
+            // The pattern does not fit
+            #[allow(clippy::reversed_empty_ranges)]
+            {
+                1..=0
+            }
+        }
+    }
+
+    /// Returns whether the string starts with the pattern
+    ///
+    /// The pattern can be a `&FheAsciiString` or a `&str`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let s = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    /// let prefix = FheAsciiString::try_encrypt_with_padding("tf", 2, &client_key).unwrap();
+    ///
+    /// let result = s.starts_with(&prefix);
+    ///
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn starts_with<'a>(&self, pattern: impl Into<AsciiPattern<'a>>) -> FheIntegerBool {
+        let pattern = pattern.into();
+        pattern.check();
+        let result = FheUint8Id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            if self.candidate_offsets(pattern).is_empty() {
+                server_key.create_trivial_zero_radix(1)
+            } else {
+                self.matches_at(server_key, pattern, 0)
+            }
+        });
+        FheIntegerBool::from_condition(result)
+    }
+
+    /// Returns the encryption of 1 for each offset at which the pattern is found
+    fn matches(&self, server_key: &ServerKey, pattern: AsciiPattern<'_>) -> Vec<RadixCiphertext> {
+        self.candidate_offsets(pattern)
+            .into_par_iter()
+            .map(|offset| self.matches_at(server_key, pattern, offset))
+            .collect()
+    }
+
+    /// Returns whether the string contains the pattern
+    ///
+    /// The pattern can be a `&FheAsciiString` or a `&str`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let s = FheAsciiString::try_encrypt("tfhe-rs", &client_key).unwrap();
+    ///
+    /// let result = s.contains("he-");
+    ///
+    /// let decrypted = result.decrypt(&client_key);
+    /// assert!(decrypted);
+    /// ```
+    pub fn contains<'a>(&self, pattern: impl Into<AsciiPattern<'a>>) -> FheIntegerBool {
+        let pattern = pattern.into();
+        pattern.check();
+        let result = FheUint8Id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            any_condition(server_key, self.matches(server_key, pattern))
+        });
+        FheIntegerBool::from_condition(result)
+    }
+
+    /// Returns whether the pattern was found, and the position of its first occurrence
+    ///
+    /// The pattern can be a `&FheAsciiString` or a `&str`.
+    /// If the pattern is not found, the position is an encryption of 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let s = FheAsciiString::try_encrypt("abcabc", &client_key).unwrap();
+    ///
+    /// let (found, position) = s.find("ca");
+    ///
+    /// let found = found.decrypt(&client_key);
+    /// let position: u16 = position.decrypt(&client_key);
+    /// assert!(found);
+    /// assert_eq!(position, 2);
+    /// ```
+    pub fn find<'a>(&self, pattern: impl Into<AsciiPattern<'a>>) -> (FheIntegerBool, FheUint16) {
+        let pattern = pattern.into();
+        pattern.check();
+        assert!(
+            self.chars.len() <= u16::MAX as usize,
+            "The positions must fit in a FheUint16"
+        );
+        let (found, position) = FheUint16Id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let position_blocks = FheUint16Parameters::num_blocks(server_key.key.message_modulus);
+
+            let matches = self.matches(server_key, pattern);
+            if matches.is_empty() {
+                return (
+                    server_key.create_trivial_zero_radix(1),
+                    server_key.create_trivial_zero_radix(position_blocks),
+                );
+            }
+            // The first maximum is the first match, if there is one.
+            // Without a match, argmax returns the first position, which is 0
+            let extended_matches = matches
+                .par_iter()
+                .map(|is_match| {
+                    server_key
+                        .extend_radix_with_trivial_zero_blocks_msb(is_match, position_blocks - 1)
+                })
+                .collect::<Vec<_>>();
+            rayon::join(
+                || any_condition(server_key, matches),
+                || server_key.argmax_parallelized(&extended_matches),
+            )
+        });
+        (
+            FheIntegerBool::from_condition(found),
+            FheUint16::new(position, FheUint16Id),
+        )
+    }
+
+    fn map_chars(
+        &self,
+        func: impl Fn(&ServerKey, &RadixCiphertext) -> RadixCiphertext + Sync,
+    ) -> Self {
+        let chars = FheUint8Id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            self.chars
+                .par_iter()
+                .map(|char| FheUint8::new(func(server_key, &char.ciphertext), FheUint8Id))
+                .collect()
+        });
+        Self {
+            chars,
+            padded: self.padded,
+        }
+    }
+
+    /// Returns a copy of the string in which the characters in `first..=last` are
+    /// shifted by `offset`
+    fn shift_range(&self, first: u8, last: u8, offset: u8, upwards: bool) -> Self {
+        self.map_chars(|server_key, char| {
+            let (is_in_range, shifted) = rayon::join(
+                || {
+                    let (is_ge, is_le) = rayon::join(
+                        || to_condition(server_key.scalar_ge_block_parallelized(char, first)),
+                        || to_condition(server_key.scalar_le_block_parallelized(char, last)),
+                    );
+                    server_key.bitand_parallelized(&is_ge, &is_le)
+                },
+                || {
+                    if upwards {
+                        server_key.scalar_add_parallelized(char, offset)
+                    } else {
+                        server_key.scalar_sub_parallelized(char, offset)
+                    }
+                },
+            );
+            server_key.if_then_else_parallelized(&is_in_range, &shifted, char)
+        })
+    }
+
+    /// Returns a copy of the string where the ASCII lowercase letters are converted to uppercase
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheAsciiString};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let s = FheAsciiString::try_encrypt("Zama!", &client_key).unwrap();
+    ///
+    /// let result = s.to_upper();
+    ///
+    /// let decrypted: String = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, "ZAMA!");
+    /// ```
+    pub fn to_upper(&self) -> Self {
+        self.shift_range(b'a', b'z', b'a' - b'A', false)
+    }
+
+    /// Returns a copy of the string where the ASCII uppercase letters are converted to lowercase
+    pub fn to_lower(&self) -> Self {
+        self.shift_range(b'A', b'Z', b'a' - b'A', true)
+    }
+
+    /// Returns the encryption of 1 if the strings are equal
+    fn equals(&self, server_key: &ServerKey, other: AsciiPattern<'_>) -> RadixCiphertext {
+        let num_chars = self.chars.len().max(other.len());
+        let conditions = (0..num_chars)
+            .into_par_iter()
+            .map(|i| match (self.chars.get(i), i < other.len()) {
+                (Some(char), true) => match other {
+                    AsciiPattern::Encrypted(other) => to_condition(
+                        server_key
+                            .eq_block_parallelized(&char.ciphertext, &other.chars[i].ciphertext),
+                    ),
+                    AsciiPattern::Clear(other) => to_condition(
+                        server_key
+                            .scalar_eq_block_parallelized(&char.ciphertext, other.as_bytes()[i]),
+                    ),
+                },
+                // The extra characters of the longest string must be padding
+                (Some(char), false) => {
+                    to_condition(server_key.scalar_eq_block_parallelized(&char.ciphertext, 0u64))
+                }
+                (None, true) => other.is_padding_at(server_key, i),
+                (None, false) => unreachable!(),
+            })
+            .collect::<Vec<_>>();
+        all_conditions(server_key, conditions)
+    }
+
+    fn compare(
+        &self,
+        other: AsciiPattern<'_>,
+        comparison: fn(&ServerKey, &RadixCiphertext, &RadixCiphertext) -> Ciphertext,
+    ) -> FheIntegerBool {
+        other.check();
+        let result = FheUint8Id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let num_chars = self.chars.len().max(other.len());
+            let trivial_other;
+            let other = match other {
+                AsciiPattern::Encrypted(other) => other,
+                AsciiPattern::Clear(other) => {
+                    trivial_other = Self::trivial(server_key, other, self.char_blocks(server_key));
+                    &trivial_other
+                }
+            };
+            let (lhs, rhs) = (
+                self.to_lexicographic_integer(server_key, num_chars),
+                other.to_lexicographic_integer(server_key, num_chars),
+            );
+            comparison(server_key, &lhs, &rhs)
+        });
+        FheIntegerBool::new(result)
+    }
+
+    /// Concatenates the two strings
+    ///
+    /// If `self` is not padded, the characters are simply appended. Otherwise, the characters of
+    /// `rhs` must be moved obliviously just after the end of `self`, which costs one encrypted
+    /// selection for each pair of characters of the two strings.
+    pub fn concat(&self, rhs: &Self) -> Self {
+        if !self.padded {
+            let mut chars = self.chars.clone();
+            chars.extend(rhs.chars.iter().cloned());
+            return Self {
+                chars,
+                padded: rhs.padded,
+            };
+        }
+
+        let chars = FheUint8Id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let char_blocks = self.char_blocks(server_key);
+
+            // is_length[j] encrypts 1 if the length of self is j
+            let is_length = {
+                // The length must not wrap around
+                let bits_per_block = server_key.key.message_modulus.0.ilog2();
+                let length_bits = usize::BITS - self.chars.len().leading_zeros();
+                let length_blocks = ((length_bits + bits_per_block - 1) / bits_per_block) as usize;
+                let is_not_padding = self
+                    .chars
+                    .par_iter()
+                    .map(|char| {
+                        let is_not_padding =
+                            server_key.scalar_ne_block_parallelized(&char.ciphertext, 0u64);
+                        server_key.extend_radix_with_trivial_zero_blocks_msb(
+                            &to_condition(is_not_padding),
+                            length_blocks.max(1) - 1,
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let length = server_key
+                    .sum_ciphertexts_vec_parallelized(is_not_padding)
+                    .unwrap();
+                (0..=self.chars.len())
+                    .into_par_iter()
+                    .map(|j| {
+                        to_condition(server_key.scalar_eq_block_parallelized(&length, j as u64))
+                    })
+                    .collect::<Vec<_>>()
+            };
+
+            let zero = server_key.create_trivial_zero_radix(char_blocks);
+            (0..self.chars.len() + rhs.chars.len())
+                .into_par_iter()
+                .map(|k| {
+                    // At most one of the terms is not zero: the character of self if k is
+                    // before its end, or the character of rhs that must go at k
+                    let mut terms = (0..=k.min(self.chars.len()))
+                        .into_par_iter()
+                        .filter_map(|j| {
+                            let rhs_char = rhs.chars.get(k - j)?;
+                            Some(server_key.if_then_else_parallelized(
+                                &is_length[j],
+                                &rhs_char.ciphertext,
+                                &zero,
+                            ))
+                        })
+                        .collect::<Vec<_>>();
+                    if let Some(char) = self.chars.get(k) {
+                        terms.push(char.ciphertext.clone());
+                    }
+                    let ciphertext = server_key
+                        .sum_ciphertexts_vec_parallelized(terms)
+                        .unwrap_or_else(|| zero.clone());
+                    FheUint8::new(ciphertext, FheUint8Id)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Self {
+            chars,
+            padded: true,
+        }
+    }
+}
+
+impl FheTryEncrypt<&str, ClientKey> for FheAsciiString {
+    type Error = Error;
+
+    /// Encrypts the string without padding
+    ///
+    /// Returns an error if the string contains characters that are not ASCII, or NUL characters.
+    fn try_encrypt(value: &str, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt_with_padding(value, 0, key)
+    }
+}
+
+impl FheDecrypt<String> for FheAsciiString {
+    fn decrypt(&self, key: &ClientKey) -> String {
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(FheUint8Id.type_variant()))
+            .unwrap_display();
+        self.chars
+            .iter()
+            .map(|char| integer_client_key.decrypt_radix::<u64>(&char.ciphertext) as u8)
+            .take_while(|&char| char != 0)
+            .map(char::from)
+            .collect()
+    }
+}
+
+impl<'a, T> FheEq<T> for FheAsciiString
+where
+    T: Into<AsciiPattern<'a>>,
+{
+    type Output = FheIntegerBool;
+
+    /// Returns whether the strings are equal, the padding is ignored
+    fn eq(&self, other: T) -> Self::Output {
+        let other = other.into();
+        other.check();
+        let result = FheUint8Id
+            .with_unwrapped_global(|integer_key| self.equals(integer_key.pbs_key(), other));
+        FheIntegerBool::from_condition(result)
+    }
+
+    fn ne(&self, other: T) -> Self::Output {
+        !self.eq(other)
+    }
+}
+
+impl<'a, T> FheOrd<T> for FheAsciiString
+where
+    T: Into<AsciiPattern<'a>>,
+{
+    type Output = FheIntegerBool;
+
+    /// Returns whether self is before `other` in lexicographic order
+    fn lt(&self, other: T) -> Self::Output {
+        self.compare(other.into(), ServerKey::lt_block_parallelized)
+    }
+
+    fn le(&self, other: T) -> Self::Output {
+        self.compare(other.into(), ServerKey::le_block_parallelized)
+    }
+
+    fn gt(&self, other: T) -> Self::Output {
+        self.compare(other.into(), ServerKey::gt_block_parallelized)
+    }
+
+    fn ge(&self, other: T) -> Self::Output {
+        self.compare(other.into(), ServerKey::ge_block_parallelized)
+    }
+}
+
+impl<B> Add<B> for &FheAsciiString
+where
+    B: Borrow<FheAsciiString>,
+{
+    type Output = FheAsciiString;
+
+    /// Concatenates the two strings, see [FheAsciiString::concat]
+    fn add(self, rhs: B) -> Self::Output {
+        self.concat(rhs.borrow())
+    }
+}
+
+impl<B> Add<B> for FheAsciiString
+where
+    B: Borrow<Self>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: B) -> Self::Output {
+        <&Self as Add<B>>::add(&self, rhs)
+    }
+}
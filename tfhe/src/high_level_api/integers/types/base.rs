@@ -1,4 +1,5 @@
 use std::borrow::Borrow;
+use std::iter::Sum;
 use std::ops::{
     Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Mul, MulAssign,
     Neg, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
@@ -15,14 +16,15 @@ use crate::high_level_api::internal_traits::{DecryptionKey, TypeIdentifier};
 use crate::high_level_api::keys::{CompressedPublicKey, RefKeyFromKeyChain};
 use crate::high_level_api::traits::{
     FheBootstrap, FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
-    FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign,
+    FheTryTrivialEncrypt, IfThenElse, RotateLeft, RotateLeftAssign, RotateRight, RotateRightAssign,
 };
-use crate::high_level_api::{ClientKey, PublicKey};
+use crate::high_level_api::{ClientKey, PublicKey, Seed};
 use crate::integer::block_decomposition::DecomposableInto;
 use crate::integer::ciphertext::RadixCiphertext;
-use crate::integer::U256;
 use crate::CompactPublicKey;
 
+use super::boolean::FheIntegerBool;
+
 /// A Generic FHE unsigned integer
 ///
 /// Contrary to *shortints*, these integers can in theory by parametrized to
@@ -120,8 +122,8 @@ where
     {
         crate::high_level_api::global_state::with_internal_keys(|keys| {
             let integer_key = keys.integer_key.pbs_key();
-            let current_num_blocks = P::num_blocks();
-            let target_num_blocks = P2::num_blocks();
+            let current_num_blocks = self.ciphertext.blocks.len();
+            let target_num_blocks = P2::num_blocks(integer_key.key.message_modulus);
 
             if target_num_blocks > current_num_blocks {
                 let num_blocks_to_add = target_num_blocks - current_num_blocks;
@@ -146,14 +148,6 @@ where
 {
     type Error = GenericIntegerBlockError;
     fn try_from(other: RadixCiphertext) -> Result<GenericInteger<P>, GenericIntegerBlockError> {
-        // Check number of blocks
-        if other.blocks.len() != P::num_blocks() {
-            return Err(GenericIntegerBlockError::NumberOfBlocks(
-                P::num_blocks(),
-                other.blocks.len(),
-            ));
-        }
-
         // Get correct carry modulus and message modulus from ServerKey
         let id = P::Id::default();
         let (correct_carry_mod, correct_message_mod) = id.with_unwrapped_global(|integer_key| {
@@ -163,6 +157,15 @@ where
             )
         });
 
+        // Check number of blocks
+        let num_blocks = P::num_blocks(correct_message_mod);
+        if other.blocks.len() != num_blocks {
+            return Err(GenericIntegerBlockError::NumberOfBlocks(
+                num_blocks,
+                other.blocks.len(),
+            ));
+        }
+
         // For each block, check that carry modulus and message modulus are valid
         for block in &other.blocks {
             let (input_carry_mod, input_message_mod) = (block.carry_modulus, block.message_modulus);
@@ -226,7 +229,8 @@ where
             .as_ref()
             .ok_or(UninitializedClientKey(id.type_variant()))
             .unwrap_display();
-        let ciphertext = integer_client_key.encrypt_radix(value, P::num_blocks());
+        let num_blocks = P::num_blocks(integer_client_key.parameters().message_modulus());
+        let ciphertext = integer_client_key.encrypt_radix(value, num_blocks);
         Ok(Self::new(ciphertext, id))
     }
 }
@@ -246,7 +250,8 @@ where
             .as_ref()
             .ok_or(UninitializedPublicKey(id.type_variant()))
             .unwrap_display();
-        let ciphertext = integer_public_key.encrypt_radix(value, P::num_blocks());
+        let num_blocks = P::num_blocks(integer_public_key.parameters().message_modulus());
+        let ciphertext = integer_public_key.encrypt_radix(value, num_blocks);
         Ok(Self::new(ciphertext, id))
     }
 }
@@ -266,14 +271,15 @@ where
             .as_ref()
             .ok_or(UninitializedCompressedPublicKey(id.type_variant()))
             .unwrap_display();
-        let ciphertext = integer_public_key.encrypt_radix(value, P::num_blocks());
+        let num_blocks = P::num_blocks(integer_public_key.parameters().message_modulus());
+        let ciphertext = integer_public_key.encrypt_radix(value, num_blocks);
         Ok(Self::new(ciphertext, id))
     }
 }
 
 impl<P, T> FheTryEncrypt<T, CompactPublicKey> for GenericInteger<P>
 where
-    T: Into<U256>,
+    T: crate::integer::block_decomposition::DecomposableInto<u64>,
     P: IntegerParameter,
     P::Id: Default + TypeIdentifier,
 {
@@ -283,7 +289,7 @@ where
         let id = P::Id::default();
         let ciphertext = key
             .integer_key
-            .try_encrypt(value, P::num_blocks())
+            .try_encrypt::<P, _>(value)
             .ok_or(UninitializedPublicKey(id.type_variant()))
             .unwrap_display();
         Ok(Self::new(ciphertext, id))
@@ -301,9 +307,10 @@ where
     fn try_encrypt_trivial(value: T) -> Result<Self, Self::Error> {
         let id = P::Id::default();
         let ciphertext = id.with_unwrapped_global(|integer_key| {
-            integer_key
-                .pbs_key()
-                .create_trivial_radix(value, P::num_blocks())
+            integer_key.pbs_key().create_trivial_radix(
+                value,
+                P::num_blocks(integer_key.pbs_key().key.message_modulus),
+            )
         });
         Ok(Self::new(ciphertext, id))
     }
@@ -401,24 +408,24 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = IntegerServerKey>,
 {
-    type Output = Self;
+    type Output = FheIntegerBool;
 
     fn eq(&self, rhs: Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .eq_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .eq_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn ne(&self, rhs: Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .ne_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .ne_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 }
 
@@ -428,24 +435,24 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = IntegerServerKey>,
 {
-    type Output = Self;
+    type Output = FheIntegerBool;
 
     fn eq(&self, rhs: &Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .eq_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .eq_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn ne(&self, rhs: &Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .ne_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .ne_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 }
 
@@ -456,24 +463,24 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = IntegerServerKey>,
 {
-    type Output = Self;
+    type Output = FheIntegerBool;
 
     fn eq(&self, rhs: Clear) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .scalar_eq_parallelized(&self.ciphertext, rhs)
+                .scalar_eq_block_parallelized(&self.ciphertext, rhs)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn ne(&self, rhs: Clear) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .scalar_ne_parallelized(&self.ciphertext, rhs)
+                .scalar_ne_block_parallelized(&self.ciphertext, rhs)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 }
 
@@ -483,42 +490,42 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = IntegerServerKey>,
 {
-    type Output = Self;
+    type Output = FheIntegerBool;
 
     fn lt(&self, rhs: Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .lt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .lt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn le(&self, rhs: Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .le_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .le_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn gt(&self, rhs: Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .gt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .gt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn ge(&self, rhs: Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .ge_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .ge_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 }
 
@@ -528,42 +535,42 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = IntegerServerKey>,
 {
-    type Output = Self;
+    type Output = FheIntegerBool;
 
     fn lt(&self, rhs: &Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .lt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .lt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn le(&self, rhs: &Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .le_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .le_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn gt(&self, rhs: &Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .gt_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .gt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn ge(&self, rhs: &Self) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .ge_parallelized(&self.ciphertext, &rhs.ciphertext)
+                .ge_block_parallelized(&self.ciphertext, &rhs.ciphertext)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 }
 
@@ -574,42 +581,138 @@ where
     GenericInteger<P>: Clone,
     P::Id: WithGlobalKey<Key = IntegerServerKey>,
 {
-    type Output = Self;
+    type Output = FheIntegerBool;
 
     fn lt(&self, rhs: Clear) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .scalar_lt_parallelized(&self.ciphertext, rhs)
+                .scalar_lt_block_parallelized(&self.ciphertext, rhs)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn le(&self, rhs: Clear) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .scalar_le_parallelized(&self.ciphertext, rhs)
+                .scalar_le_block_parallelized(&self.ciphertext, rhs)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn gt(&self, rhs: Clear) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .scalar_gt_parallelized(&self.ciphertext, rhs)
+                .scalar_gt_block_parallelized(&self.ciphertext, rhs)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
     }
 
     fn ge(&self, rhs: Clear) -> Self::Output {
         let inner_result = self.id.with_unwrapped_global(|integer_key| {
             integer_key
                 .pbs_key()
-                .scalar_ge_parallelized(&self.ciphertext, rhs)
+                .scalar_ge_block_parallelized(&self.ciphertext, rhs)
         });
-        GenericInteger::new(inner_result, self.id)
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<P> Sum<Self> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default + WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Sums all the integers of the iterator
+    ///
+    /// The sum of an empty iterator is a trivial encryption of 0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [14u8, 97, 3, 42];
+    /// let values = clears
+    ///     .iter()
+    ///     .map(|&clear| FheUint8::encrypt(clear, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let sum: FheUint8 = values.into_iter().sum();
+    ///
+    /// let decrypted: u8 = sum.decrypt(&client_key);
+    /// assert_eq!(decrypted, clears.iter().sum::<u8>());
+    /// ```
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        let ciphertexts = iter.map(|integer| integer.ciphertext).collect::<Vec<_>>();
+        let id = P::Id::default();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .sum_ciphertexts_vec_parallelized(ciphertexts)
+                .unwrap_or_else(|| {
+                    integer_key
+                        .pbs_key()
+                        .create_trivial_zero_radix(P::num_blocks(
+                            integer_key.pbs_key().key.message_modulus,
+                        ))
+                })
+        });
+        Self::new(ciphertext, id)
+    }
+}
+
+impl<'a, P> Sum<&'a Self> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default + WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Sums all the integers of the iterator
+    ///
+    /// The sum of an empty iterator is a trivial encryption of 0.
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        let id = P::Id::default();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .sum_ciphertexts_parallelized(iter.map(|integer| &integer.ciphertext))
+                .unwrap_or_else(|| {
+                    integer_key
+                        .pbs_key()
+                        .create_trivial_zero_radix(P::num_blocks(
+                            integer_key.pbs_key().key.message_modulus,
+                        ))
+                })
+        });
+        Self::new(ciphertext, id)
+    }
+}
+
+fn assert_function_evaluation_input_bits(integer_key: &IntegerServerKey, input_bits: u32) {
+    let max_input_bits = integer_key.function_evaluation_max_input_bits();
+    assert!(
+        input_bits <= max_input_bits,
+        "Function evaluation was enabled in the config for functions with at most \
+        {max_input_bits} input bits, this function has {input_bits} input bits"
+    );
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+{
+    fn message_bits(integer_key: &IntegerServerKey) -> u32 {
+        let message_modulus = integer_key.pbs_key().key.message_modulus;
+        P::num_blocks(message_modulus) as u32 * message_modulus.0.ilog2()
     }
 }
 
@@ -623,9 +726,44 @@ where
             RadixCiphertext,
         >,
 {
+    /// Computes a function over the encrypted integer, and returns the encrypted result
+    ///
+    /// The result of `func` is reduced modulo the modulus of the integer type.
+    ///
+    /// Function evaluation must have been enabled in the config,
+    /// see [ConfigBuilder::enable_function_evaluation_integers].
+    ///
+    /// # Panics
+    ///
+    /// Panics if function evaluation was not enabled, or if the integer type has more bits
+    /// than the maximum number of input bits set in the config (16 by default).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .enable_function_evaluation_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    ///
+    /// let result = a.map(|x| (x * x) / 3);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((27u64 * 27) / 3 % 256) as u8);
+    /// ```
+    ///
+    /// [ConfigBuilder::enable_function_evaluation_integers]: crate::ConfigBuilder::enable_function_evaluation_integers
     fn map<F: Fn(u64) -> u64>(&self, func: F) -> Self {
         use crate::high_level_api::integers::server_key::WopbsEvaluationKey;
         self.id.with_unwrapped_global(|integer_key| {
+            assert_function_evaluation_input_bits(integer_key, Self::message_bits(integer_key));
             let res = integer_key
                 .wopbs_key
                 .as_ref()
@@ -651,12 +789,51 @@ where
             RadixCiphertext,
         >,
 {
-    pub fn bivariate_function<F>(&self, other: &Self, func: F) -> Self
+    /// Computes a function of two encrypted integers, and returns the encrypted result
+    ///
+    /// `func` is called with the value of `self` as first argument,
+    /// and the value of `other` as second argument.
+    /// Its result is reduced modulo the modulus of the integer type.
+    ///
+    /// Function evaluation must have been enabled in the config,
+    /// see [ConfigBuilder::enable_function_evaluation_integers].
+    ///
+    /// # Panics
+    ///
+    /// Panics if function evaluation was not enabled, or if the two inputs have more bits in
+    /// total than the maximum number of input bits set in the config (16 by default,
+    /// e.g. this works on [FheUint8](crate::FheUint8) but not on [FheUint16](crate::FheUint16)).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .enable_function_evaluation_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(7u8, &client_key);
+    ///
+    /// let result = a.bivariate_map(&b, |x, y| x.pow(2) / (y + 1));
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, ((200u64 * 200) / 8 % 256) as u8);
+    /// ```
+    ///
+    /// [ConfigBuilder::enable_function_evaluation_integers]: crate::ConfigBuilder::enable_function_evaluation_integers
+    pub fn bivariate_map<F>(&self, other: &Self, func: F) -> Self
     where
         F: Fn(u64, u64) -> u64,
     {
         use crate::high_level_api::integers::server_key::WopbsEvaluationKey;
         self.id.with_unwrapped_global(|integer_key| {
+            assert_function_evaluation_input_bits(integer_key, 2 * Self::message_bits(integer_key));
             let lhs = &self.ciphertext;
             let rhs = &other.ciphertext;
             let res = integer_key
@@ -667,6 +844,883 @@ where
             GenericInteger::<P>::new(res, self.id)
         })
     }
+
+    /// Computes a function of two encrypted integers
+    ///
+    /// See [Self::bivariate_map].
+    pub fn bivariate_function<F>(&self, other: &Self, func: F) -> Self
+    where
+        F: Fn(u64, u64) -> u64,
+    {
+        self.bivariate_map(other, func)
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Selects between two encrypted integers
+    ///
+    /// Returns `ct_then` if `condition` encrypts `true`, `ct_else` otherwise.
+    ///
+    /// This is the same as `condition.if_then_else(ct_then, ct_else)`,
+    /// see [IfThenElse::if_then_else].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    /// let b = FheUint8::encrypt(128u8, &client_key);
+    ///
+    /// let result = FheUint8::if_then_else(&a.gt(&b), &a, &b);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 128);
+    /// ```
+    pub fn if_then_else(condition: &FheIntegerBool, ct_then: &Self, ct_else: &Self) -> Self {
+        condition.if_then_else(ct_then, ct_else)
+    }
+
+    /// Selects between two encrypted integers
+    ///
+    /// Returns `ct_when_true` if `condition` encrypts `true`, `ct_when_false` otherwise.
+    ///
+    /// See [Self::if_then_else].
+    pub fn select(condition: &FheIntegerBool, ct_when_true: &Self, ct_when_false: &Self) -> Self {
+        Self::if_then_else(condition, ct_when_true, ct_when_false)
+    }
+
+    /// Adds two integers, and returns the wrapped result along with
+    /// an encrypted boolean telling whether the addition overflowed
+    ///
+    /// Contrary to [u8::checked_add], the result is always returned,
+    /// as whether there was an overflow can only be known by decrypting the boolean.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    ///
+    /// let (result, overflowed) = a.checked_add(&b);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 200u8.wrapping_add(100u8));
+    /// assert!(overflowed.decrypt(&client_key));
+    /// ```
+    pub fn checked_add(&self, rhs: &Self) -> (Self, FheIntegerBool) {
+        let (ciphertext, overflowed) = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .overflowing_add_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        (
+            GenericInteger::new(ciphertext, self.id),
+            FheIntegerBool::new(overflowed),
+        )
+    }
+
+    /// Subtracts two integers, and returns the wrapped result along with
+    /// an encrypted boolean telling whether the subtraction overflowed
+    ///
+    /// Contrary to [u8::checked_sub], the result is always returned,
+    /// as whether there was an overflow can only be known by decrypting the boolean.
+    pub fn checked_sub(&self, rhs: &Self) -> (Self, FheIntegerBool) {
+        let (ciphertext, overflowed) = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .overflowing_sub_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        (
+            GenericInteger::new(ciphertext, self.id),
+            FheIntegerBool::new(overflowed),
+        )
+    }
+
+    /// Multiplies two integers, and returns the wrapped result along with
+    /// an encrypted boolean telling whether the multiplication overflowed
+    ///
+    /// Contrary to [u8::checked_mul], the result is always returned,
+    /// as whether there was an overflow can only be known by decrypting the boolean.
+    pub fn checked_mul(&self, rhs: &Self) -> (Self, FheIntegerBool) {
+        let (ciphertext, overflowed) = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .overflowing_mul_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        (
+            GenericInteger::new(ciphertext, self.id),
+            FheIntegerBool::new(overflowed),
+        )
+    }
+
+    /// Adds two integers, saturating at the numeric bounds instead of overflowing
+    ///
+    /// If the operation overflows, the result is clamped to maximum value of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    ///
+    /// let result = a.saturating_add(&b);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, u8::MAX);
+    /// ```
+    pub fn saturating_add(&self, rhs: &Self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .saturating_add_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Subtracts two integers, saturating at the numeric bounds instead of overflowing
+    ///
+    /// If the operation overflows, the result is clamped to 0.
+    pub fn saturating_sub(&self, rhs: &Self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .saturating_sub_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Multiplies two integers, saturating at the numeric bounds instead of overflowing
+    ///
+    /// If the operation overflows, the result is clamped to maximum value of the type.
+    pub fn saturating_mul(&self, rhs: &Self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .saturating_mul_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Returns the number of leading zeros in the binary representation of self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(0b0010_1100u8, &client_key);
+    ///
+    /// let result = a.leading_zeros();
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted as u32, 0b0010_1100u8.leading_zeros());
+    /// ```
+    pub fn leading_zeros(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .leading_zeros_parallelized(&self.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Returns the number of trailing zeros in the binary representation of self
+    pub fn trailing_zeros(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .trailing_zeros_parallelized(&self.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Returns the number of ones in the binary representation of self
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(0b0010_1100u8, &client_key);
+    ///
+    /// let result = a.count_ones();
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted as u32, 0b0010_1100u8.count_ones());
+    /// ```
+    pub fn count_ones(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .count_ones_parallelized(&self.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Returns the number of zeros in the binary representation of self
+    pub fn count_zeros(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .count_zeros_parallelized(&self.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Returns the base 2 logarithm of self, rounded down
+    ///
+    /// Contrary to [u8::ilog2], this does not panic if self encrypts 0,
+    /// the result is then the maximum value of the type.
+    pub fn ilog2(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().ilog2_parallelized(&self.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Returns the integer square root of self, rounded down
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    ///
+    /// let result = a.isqrt();
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 14);
+    /// ```
+    pub fn isqrt(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().isqrt_parallelized(&self.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Raises self to the power of an encrypted exponent, wrapping around on overflow
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(3u8, &client_key);
+    /// let b = FheUint8::encrypt(5u8, &client_key);
+    ///
+    /// let result = a.pow(&b);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3u8.wrapping_pow(5));
+    /// ```
+    pub fn pow(&self, exponent: &Self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .pow_parallelized(&self.ciphertext, &exponent.ciphertext)
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Raises self to the power of a clear exponent, wrapping around on overflow
+    pub fn scalar_pow(&self, exponent: u32) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .scalar_pow_parallelized(&self.ciphertext, u64::from(exponent))
+        });
+        GenericInteger::new(ciphertext, self.id)
+    }
+
+    /// Returns the encrypted bits of self,
+    /// from the least significant bit to the most significant one
+    ///
+    /// The bits support the same operations as [FheBool](crate::high_level_api::FheBool),
+    /// and can be packed back into an integer using [Self::from_bits].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(0b1001_0110u8, &client_key);
+    ///
+    /// let bits = a.to_bits();
+    ///
+    /// let decrypted = bits
+    ///     .iter()
+    ///     .map(|bit| bit.decrypt(&client_key))
+    ///     .collect::<Vec<bool>>();
+    /// assert_eq!(
+    ///     decrypted,
+    ///     vec![false, true, true, false, true, false, false, true]
+    /// );
+    /// ```
+    pub fn to_bits(&self) -> Vec<FheIntegerBool> {
+        let bits = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .extract_bits_parallelized(&self.ciphertext)
+        });
+        bits.into_iter().map(FheIntegerBool::new).collect()
+    }
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default + WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Computes the dot product between encrypted values and clear weights,
+    /// that is the sum of each value multiplied by its weight
+    ///
+    /// The dot product of empty slices is a trivial encryption of 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` and `weights` do not have the same length.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let values = [FheUint16::encrypt(310u16, &client_key), FheUint16::encrypt(27u16, &client_key)];
+    /// let weights = [4u64, 11u64];
+    ///
+    /// let result = FheUint16::dot_product_with_clear(&values, &weights);
+    ///
+    /// let decrypted: u16 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 310 * 4 + 27 * 11);
+    /// ```
+    pub fn dot_product_with_clear(values: &[Self], weights: &[u64]) -> Self {
+        assert_eq!(
+            values.len(),
+            weights.len(),
+            "There must be as many weights as there are values"
+        );
+        let id = P::Id::default();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            if values.is_empty() {
+                return integer_key
+                    .pbs_key()
+                    .create_trivial_zero_radix(P::num_blocks(
+                        integer_key.pbs_key().key.message_modulus,
+                    ));
+            }
+            let ciphertexts = values
+                .iter()
+                .map(|value| value.ciphertext.clone())
+                .collect::<Vec<_>>();
+            integer_key
+                .pbs_key()
+                .dot_product_with_clear_parallelized(&ciphertexts, weights)
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Returns the value at the encrypted position `index`
+    ///
+    /// If `index` is out of bounds, the result is an encryption of 0.
+    ///
+    /// Each value is compared to the index, which makes the cost linear in the number of
+    /// values, [Self::select_at_index_tree] is usually faster for large slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let clears = [14u8, 97, 3, 42];
+    /// let values = clears
+    ///     .iter()
+    ///     .map(|&clear| FheUint8::encrypt(clear, &client_key))
+    ///     .collect::<Vec<_>>();
+    /// let index = FheUint8::encrypt(2u8, &client_key);
+    ///
+    /// let result = FheUint8::select_at_index(&values, &index);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, clears[2]);
+    /// ```
+    pub fn select_at_index<P2>(values: &[Self], index: &GenericInteger<P2>) -> Self
+    where
+        P2: IntegerParameter,
+    {
+        let id = P::Id::default();
+        let ciphertexts = values
+            .iter()
+            .map(|value| &value.ciphertext)
+            .collect::<Vec<_>>();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .select_at_index_parallelized(&ciphertexts, &index.ciphertext)
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Returns the value at the encrypted position `index`,
+    /// using a tree of encrypted selections driven by the bits of the index
+    ///
+    /// If `index` is out of bounds, the result is an encryption of 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty, or if `index` cannot represent all the positions of `values`.
+    pub fn select_at_index_tree<P2>(values: &[Self], index: &GenericInteger<P2>) -> Self
+    where
+        P2: IntegerParameter,
+    {
+        let id = P::Id::default();
+        let ciphertexts = values
+            .iter()
+            .map(|value| &value.ciphertext)
+            .collect::<Vec<_>>();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .select_at_index_tree_parallelized(&ciphertexts, &index.ciphertext)
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Replaces the value at the encrypted position `index` by `value`,
+    /// without revealing which one was replaced
+    ///
+    /// If `index` is out of bounds, none of the values is modified.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let mut values = [14u8, 97, 3]
+    ///     .iter()
+    ///     .map(|&clear| FheUint8::encrypt(clear, &client_key))
+    ///     .collect::<Vec<_>>();
+    /// let index = FheUint8::encrypt(1u8, &client_key);
+    /// let value = FheUint8::encrypt(200u8, &client_key);
+    ///
+    /// FheUint8::write_at_index(&mut values, &index, &value);
+    ///
+    /// let decrypted = values
+    ///     .iter()
+    ///     .map(|value| value.decrypt(&client_key))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(decrypted, vec![14, 200, 3]);
+    /// ```
+    pub fn write_at_index<P2>(values: &mut [Self], index: &GenericInteger<P2>, value: &Self)
+    where
+        P2: IntegerParameter,
+    {
+        let id = P::Id::default();
+        let mut ciphertexts = values
+            .iter_mut()
+            .map(|value| &mut value.ciphertext)
+            .collect::<Vec<_>>();
+        id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().write_at_index_parallelized(
+                &mut ciphertexts,
+                &index.ciphertext,
+                &value.ciphertext,
+            )
+        });
+    }
+
+    /// Sorts the values in ascending order
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let values = [97u8, 14, 42, 3]
+    ///     .iter()
+    ///     .map(|&clear| FheUint8::encrypt(clear, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let sorted = FheUint8::sort(&values);
+    ///
+    /// let decrypted = sorted
+    ///     .iter()
+    ///     .map(|value| value.decrypt(&client_key))
+    ///     .collect::<Vec<u8>>();
+    /// assert_eq!(decrypted, vec![3, 14, 42, 97]);
+    /// ```
+    pub fn sort(values: &[Self]) -> Vec<Self> {
+        let id = P::Id::default();
+        let ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        let sorted = id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().sort_parallelized(&ciphertexts)
+        });
+        sorted
+            .into_iter()
+            .map(|ciphertext| Self::new(ciphertext, id))
+            .collect()
+    }
+
+    /// Returns the `k` greatest values, in descending order
+    ///
+    /// If `k` is greater than the number of values, all of them are returned.
+    pub fn top_k(values: &[Self], k: usize) -> Vec<Self> {
+        let id = P::Id::default();
+        let ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        let top = id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().top_k_parallelized(&ciphertexts, k)
+        });
+        top.into_iter()
+            .map(|ciphertext| Self::new(ciphertext, id))
+            .collect()
+    }
+
+    /// Returns the encrypted position of the greatest value
+    ///
+    /// If the greatest value appears several times, the position of its first occurrence
+    /// is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty, or if the type cannot represent all the positions
+    /// of `values`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let values = [14u8, 97, 42, 3]
+    ///     .iter()
+    ///     .map(|&clear| FheUint8::encrypt(clear, &client_key))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let (position_of_max, position_of_min) = (FheUint8::argmax(&values), FheUint8::argmin(&values));
+    ///
+    /// let decrypted: u8 = position_of_max.decrypt(&client_key);
+    /// assert_eq!(decrypted, 1);
+    /// let decrypted: u8 = position_of_min.decrypt(&client_key);
+    /// assert_eq!(decrypted, 3);
+    /// ```
+    pub fn argmax(values: &[Self]) -> Self {
+        let id = P::Id::default();
+        let ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().argmax_parallelized(&ciphertexts)
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Returns the encrypted position of the smallest value
+    ///
+    /// If the smallest value appears several times, the position of its first occurrence
+    /// is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` is empty, or if the type cannot represent all the positions
+    /// of `values`.
+    pub fn argmin(values: &[Self]) -> Self {
+        let id = P::Id::default();
+        let ciphertexts = values
+            .iter()
+            .map(|value| value.ciphertext.clone())
+            .collect::<Vec<_>>();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().argmin_parallelized(&ciphertexts)
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Creates an integer from its encrypted bits,
+    /// given from the least significant bit to the most significant one
+    ///
+    /// The missing most significant bits are zeros.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more bits than the type can hold.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(0b1001_0110u8, &client_key);
+    /// let mut bits = a.to_bits();
+    /// bits.reverse();
+    /// bits[0] = &bits[0] ^ &bits[3];
+    ///
+    /// let result = FheUint8::from_bits(&bits);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 0b0110_1000);
+    /// ```
+    pub fn from_bits(bits: &[FheIntegerBool]) -> Self {
+        let id = P::Id::default();
+        let bits = bits
+            .iter()
+            .map(|bit| bit.ciphertext.clone())
+            .collect::<Vec<_>>();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            let num_blocks = P::num_blocks(integer_key.key.message_modulus);
+            let mut ciphertext = integer_key.pack_bits_parallelized(&bits);
+            assert!(
+                ciphertext.blocks.len() <= num_blocks,
+                "{} bits do not fit in {} blocks",
+                bits.len(),
+                num_blocks
+            );
+            let num_blocks_to_add = num_blocks - ciphertext.blocks.len();
+            integer_key.extend_radix_with_trivial_zero_blocks_msb_assign(
+                &mut ciphertext,
+                num_blocks_to_add,
+            );
+            ciphertext
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Generates an encrypted pseudo-random integer, uniform over all the values of the type,
+    /// using only the server key
+    ///
+    /// The value is fully determined by the `seed` and the client key, but as the client key is
+    /// unknown to the server, nobody knows the value without decrypting it.
+    /// The same seed gives encryptions of the same value, so a new seed must be used
+    /// each time a new value is needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::generate_oblivious_pseudo_random(Seed(0));
+    /// let b = FheUint8::generate_oblivious_pseudo_random(Seed(0));
+    ///
+    /// let decrypted_a: u8 = a.decrypt(&client_key);
+    /// let decrypted_b: u8 = b.decrypt(&client_key);
+    /// assert_eq!(decrypted_a, decrypted_b);
+    /// ```
+    pub fn generate_oblivious_pseudo_random(seed: Seed) -> Self {
+        let id = P::Id::default();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            let num_blocks = P::num_blocks(integer_key.key.message_modulus);
+            integer_key.generate_oblivious_pseudo_random_parallelized(seed, num_blocks)
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Generates an encrypted pseudo-random integer, uniform in `[0, 2^random_bits_count[`,
+    /// using only the server key
+    ///
+    /// See [Self::generate_oblivious_pseudo_random] for more details, and
+    /// [Self::generate_oblivious_pseudo_random_bounded] for ranges whose size is not a power of
+    /// two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `random_bits_count` is greater than the number of bits of the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint16, Seed};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint16::generate_oblivious_pseudo_random_bits(Seed(7), 10);
+    ///
+    /// let decrypted: u16 = a.decrypt(&client_key);
+    /// assert!(decrypted < 1 << 10);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_bits(seed: Seed, random_bits_count: u64) -> Self {
+        let id = P::Id::default();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            let num_blocks = P::num_blocks(integer_key.key.message_modulus);
+            integer_key.generate_oblivious_pseudo_random_bits_parallelized(
+                seed,
+                random_bits_count,
+                num_blocks,
+            )
+        });
+        Self::new(ciphertext, id)
+    }
+
+    /// Generates an encrypted pseudo-random integer in `[0, bound[`, using only the server key
+    ///
+    /// When `bound` is not a power of two, the distribution is within a statistical distance
+    /// of `2^-64` from the uniform distribution, and the generation is more expensive than
+    /// [Self::generate_oblivious_pseudo_random_bits].
+    ///
+    /// See [Self::generate_oblivious_pseudo_random] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is 0, or if `bound - 1` does not fit in the type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8, Seed};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// // A dice roll, in [1, 6]
+    /// let roll = FheUint8::generate_oblivious_pseudo_random_bounded(Seed(7), 6) + 1u8;
+    ///
+    /// let decrypted: u8 = roll.decrypt(&client_key);
+    /// assert!((1..=6).contains(&decrypted));
+    /// ```
+    pub fn generate_oblivious_pseudo_random_bounded(seed: Seed, bound: u64) -> Self {
+        let id = P::Id::default();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            let num_blocks = P::num_blocks(integer_key.key.message_modulus);
+            integer_key
+                .generate_oblivious_pseudo_random_bounded_parallelized(seed, bound, num_blocks)
+        });
+        Self::new(ciphertext, id)
+    }
 }
 
 macro_rules! generic_integer_impl_operation (
@@ -0,0 +1,335 @@
+use std::borrow::Borrow;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::errors::{UninitializedClientKey, UnwrapResultExt};
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::IntegerServerKey;
+use crate::high_level_api::internal_traits::TypeIdentifier;
+use crate::high_level_api::keys::RefKeyFromKeyChain;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt, IfThenElse,
+};
+use crate::high_level_api::ClientKey;
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::Ciphertext;
+
+use super::base::GenericInteger;
+use super::signed::GenericSignedInteger;
+use super::static_::FheIntegerBoolId;
+
+/// An encrypted boolean, using the integer keys
+///
+/// This is the type returned by comparisons between integers (e.g. [FheUint8], [FheInt8]).
+/// Contrary to the comparisons results, which used to be full integers,
+/// it is only made of one block.
+///
+/// It has the same operations as [FheBool] (`&`, `|`, `^`, `!`, [FheEq], decryption to `bool`).
+/// [FheBool] is encrypted under the boolean keys, so the two types cannot be mixed,
+/// but as this type uses the same keys as the integer types, it can be used to select between
+/// integers using [IfThenElse::if_then_else], and it can be converted back
+/// to any integer type using [From].
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(128u8, &client_key);
+///
+/// let is_in_range = a.ge(10u8) & a.lt(&b);
+/// let decrypted = is_in_range.decrypt(&client_key);
+/// assert!(decrypted);
+///
+/// let as_integer = FheUint8::from(!is_in_range);
+/// let decrypted: u8 = as_integer.decrypt(&client_key);
+/// assert_eq!(decrypted, 0);
+/// ```
+///
+/// [FheUint8]: crate::high_level_api::FheUint8
+/// [FheInt8]: crate::high_level_api::FheInt8
+/// [FheBool]: crate::high_level_api::FheBool
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheIntegerBool {
+    pub(in crate::high_level_api::integers) ciphertext: Ciphertext,
+    pub(in crate::high_level_api::integers) id: FheIntegerBoolId,
+}
+
+impl FheIntegerBool {
+    pub(in crate::high_level_api::integers) fn new(ciphertext: Ciphertext) -> Self {
+        Self {
+            ciphertext,
+            id: FheIntegerBoolId,
+        }
+    }
+
+    /// Creates a boolean from a one-block integer encrypting 0 or 1
+    pub(in crate::high_level_api::integers) fn from_condition(
+        mut condition: RadixCiphertext,
+    ) -> Self {
+        assert_eq!(
+            condition.blocks.len(),
+            1,
+            "A condition must be made of exactly one block"
+        );
+        Self::new(condition.blocks.pop().unwrap())
+    }
+
+    pub fn nand(&self, rhs: &Self) -> Self {
+        !(self & rhs)
+    }
+
+    /// Returns the blocks of an integer of `num_blocks` blocks
+    /// encrypting 1 if `self` is `true`, 0 otherwise
+    fn to_radix_blocks(&self, num_blocks: usize) -> Vec<Ciphertext> {
+        let mut ciphertext = RadixCiphertext::from(vec![self.ciphertext.clone()]);
+        self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .extend_radix_with_trivial_zero_blocks_msb_assign(&mut ciphertext, num_blocks - 1);
+        });
+        ciphertext.blocks
+    }
+}
+
+impl<P> IfThenElse<GenericInteger<P>> for FheIntegerBool
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Selects between two encrypted integers
+    ///
+    /// Returns `ct_then` if `self` encrypts `true`, `ct_else` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    /// let b = FheUint8::encrypt(128u8, &client_key);
+    ///
+    /// let result = a.gt(&b).if_then_else(&a, &b);
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 128);
+    /// ```
+    fn if_then_else(
+        &self,
+        ct_then: &GenericInteger<P>,
+        ct_else: &GenericInteger<P>,
+    ) -> GenericInteger<P> {
+        let condition = RadixCiphertext::from(vec![self.ciphertext.clone()]);
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().if_then_else_parallelized(
+                &condition,
+                &ct_then.ciphertext,
+                &ct_else.ciphertext,
+            )
+        });
+        GenericInteger::new(ciphertext, ct_then.id)
+    }
+}
+
+impl<P> IfThenElse<GenericSignedInteger<P>> for FheIntegerBool
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Selects between two encrypted signed integers
+    ///
+    /// Returns `ct_then` if `self` encrypts `true`, `ct_else` otherwise.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheInt8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheInt8::encrypt(-27i8, &client_key);
+    /// let b = FheInt8::encrypt(100i8, &client_key);
+    ///
+    /// let result = a.lt(&b).if_then_else(&a, &b);
+    ///
+    /// let decrypted: i8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, -27);
+    /// ```
+    fn if_then_else(
+        &self,
+        ct_then: &GenericSignedInteger<P>,
+        ct_else: &GenericSignedInteger<P>,
+    ) -> GenericSignedInteger<P> {
+        let condition = RadixCiphertext::from(vec![self.ciphertext.clone()]);
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            // The selection does not depend on how the bits are interpreted
+            integer_key.pbs_key().if_then_else_parallelized(
+                &condition,
+                &ct_then.ciphertext.to_unsigned(),
+                &ct_else.ciphertext.to_unsigned(),
+            )
+        });
+        GenericSignedInteger::new(SignedRadixCiphertext::from_unsigned(ciphertext), ct_then.id)
+    }
+}
+
+impl<P> From<FheIntegerBool> for GenericInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default,
+{
+    fn from(value: FheIntegerBool) -> Self {
+        let blocks = value.to_radix_blocks(P::num_blocks(value.ciphertext.message_modulus));
+        Self::new(RadixCiphertext::from(blocks), P::Id::default())
+    }
+}
+
+impl<P> From<FheIntegerBool> for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: Default,
+{
+    fn from(value: FheIntegerBool) -> Self {
+        let blocks = value.to_radix_blocks(P::num_blocks(value.ciphertext.message_modulus));
+        Self::new(SignedRadixCiphertext::from(blocks), P::Id::default())
+    }
+}
+
+impl FheDecrypt<bool> for FheIntegerBool {
+    #[track_caller]
+    fn decrypt(&self, key: &ClientKey) -> bool {
+        let key = self.id.unwrapped_ref_key(key);
+        key.decrypt_one_block(&self.ciphertext) != 0
+    }
+}
+
+impl FheTryEncrypt<bool, ClientKey> for FheIntegerBool {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: bool, key: &ClientKey) -> Result<Self, Self::Error> {
+        let id = FheIntegerBoolId;
+
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(id.type_variant()))
+            .unwrap_display();
+        let mut ciphertext = integer_client_key.encrypt_one_block(u64::from(value));
+        // The block only encrypts 0 or 1
+        ciphertext.degree = Degree(1);
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl FheTryTrivialEncrypt<bool> for FheIntegerBool {
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt_trivial(value: bool) -> Result<Self, Self::Error> {
+        let ciphertext = FheIntegerBoolId.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().key.create_trivial(u64::from(value))
+        });
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl FheTrivialEncrypt<bool> for FheIntegerBool {
+    #[track_caller]
+    fn encrypt_trivial(value: bool) -> Self {
+        Self::try_encrypt_trivial(value).unwrap()
+    }
+}
+
+impl<B> FheEq<B> for FheIntegerBool
+where
+    B: Borrow<Self>,
+{
+    type Output = Self;
+
+    fn eq(&self, other: B) -> Self {
+        !self.ne(other)
+    }
+
+    fn ne(&self, other: B) -> Self {
+        self ^ other.borrow()
+    }
+}
+
+// The operations are computed from the sum of the two bits, so that the results
+// still encrypt 0 or 1 with a degree of 1, and can be packed back into integers
+macro_rules! fhe_integer_bool_impl_operation(
+    ($trait_name:ident($trait_method:ident) => |$sum:ident| $sum_to_bit:expr) => {
+        impl<B> $trait_name<B> for FheIntegerBool
+        where B: Borrow<FheIntegerBool>,
+        {
+            type Output = FheIntegerBool;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $trait_name<B>>::$trait_method(&self, rhs)
+            }
+        }
+
+        impl<B> $trait_name<B> for &FheIntegerBool
+        where B: Borrow<FheIntegerBool>,
+        {
+            type Output = FheIntegerBool;
+
+            fn $trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+                    let key = &integer_key.pbs_key().key;
+                    let lut = key.generate_lookup_table(|$sum| u64::from($sum_to_bit));
+                    let sum = key.unchecked_add(&self.ciphertext, &rhs.borrow().ciphertext);
+                    key.apply_lookup_table(&sum, &lut)
+                });
+                FheIntegerBool::new(ciphertext)
+            }
+        }
+    };
+);
+
+fhe_integer_bool_impl_operation!(BitAnd(bitand) => |sum| sum == 2);
+fhe_integer_bool_impl_operation!(BitOr(bitor) => |sum| sum != 0);
+fhe_integer_bool_impl_operation!(BitXor(bitxor) => |sum| sum == 1);
+
+impl Not for FheIntegerBool {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        !&self
+    }
+}
+
+impl Not for &FheIntegerBool {
+    type Output = FheIntegerBool;
+
+    fn not(self) -> Self::Output {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            let key = &integer_key.pbs_key().key;
+            let lut = key.generate_lookup_table(|x| u64::from(x == 0));
+            key.apply_lookup_table(&self.ciphertext, &lut)
+        });
+        FheIntegerBool::new(ciphertext)
+    }
+}
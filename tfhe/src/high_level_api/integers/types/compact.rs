@@ -59,7 +59,7 @@ where
         let id = P::Id::default();
         let ciphertext = key
             .integer_key
-            .try_encrypt_compact(&[value], P::num_blocks())
+            .try_encrypt_compact::<P, _>(&[value])
             .ok_or(UninitializedPublicKey(id.type_variant()))
             .unwrap_display();
         Ok(Self {
@@ -81,7 +81,7 @@ where
         let id = P::Id::default();
         let ciphertext = key
             .integer_key
-            .try_encrypt_compact(values, P::num_blocks())
+            .try_encrypt_compact::<P, _>(values)
             .ok_or(UninitializedPublicKey(id.type_variant()))
             .unwrap_display();
         Ok(Self {
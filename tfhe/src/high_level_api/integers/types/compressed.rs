@@ -5,7 +5,6 @@ use crate::high_level_api::internal_traits::TypeIdentifier;
 use crate::high_level_api::traits::FheTryEncrypt;
 use crate::high_level_api::ClientKey;
 use crate::integer::ciphertext::CompressedRadixCiphertext;
-use crate::integer::U256;
 
 #[derive(Clone, serde::Serialize, serde::Deserialize)]
 pub struct CompressedGenericInteger<P>
@@ -48,14 +47,13 @@ where
 
 impl<P, T> FheTryEncrypt<T, ClientKey> for CompressedGenericInteger<P>
 where
-    T: Into<U256>,
+    T: crate::integer::block_decomposition::DecomposableInto<u64>,
     P: IntegerParameter,
     P::Id: Default + TypeIdentifier,
 {
     type Error = crate::high_level_api::errors::Error;
 
     fn try_encrypt(value: T, key: &ClientKey) -> Result<Self, Self::Error> {
-        let value = value.into();
         let id = P::Id::default();
         let integer_client_key = key
             .integer_key
@@ -63,7 +61,8 @@ where
             .as_ref()
             .ok_or(UninitializedClientKey(id.type_variant()))
             .unwrap_display();
-        let inner = integer_client_key.encrypt_radix_compressed(value, P::num_blocks());
+        let num_blocks = P::num_blocks(integer_client_key.parameters().message_modulus());
+        let inner = integer_client_key.encrypt_radix_compressed(value, num_blocks);
         Ok(Self::new(inner, id))
     }
 }
@@ -0,0 +1,661 @@
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+use crate::errors::{UninitializedClientKey, UnwrapResultExt};
+use crate::high_level_api::errors::Error;
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::internal_traits::TypeIdentifier;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
+    FheTryTrivialEncrypt,
+};
+use crate::high_level_api::ClientKey;
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::MessageModulus;
+
+use super::boolean::FheIntegerBool;
+use super::static_::{FheIFixedId, FheUFixedId};
+
+/// An encrypted unsigned fixed-point number
+///
+/// The number has `INT_BITS` bits of integer part and `FRAC_BITS` bits of
+/// fractional part, it is stored as the unsigned integer `value * 2^FRAC_BITS`
+/// encrypted in a radix ciphertext (see [FheUint](crate::high_level_api::FheUint)).
+///
+/// As for [FheUint](crate::high_level_api::FheUint), the number of blocks is the smallest
+/// that can hold `INT_BITS + FRAC_BITS` bits, so the values wrap around at the next multiple
+/// of the number of bits in a block.
+///
+/// The results of multiplications and divisions are truncated to `FRAC_BITS`
+/// fractional bits (i.e. rounded towards zero).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUFixed};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let price = FheUFixed::<8, 8>::encrypt(12.5, &client_key);
+/// let quantity = FheUFixed::<8, 8>::encrypt(3.25, &client_key);
+///
+/// let total = price * quantity;
+///
+/// let decrypted: f64 = total.decrypt(&client_key);
+/// assert_eq!(decrypted, 40.625);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheUFixed<const INT_BITS: usize, const FRAC_BITS: usize> {
+    pub(in crate::high_level_api::integers) ciphertext: RadixCiphertext,
+    pub(in crate::high_level_api::integers) id: FheUFixedId<INT_BITS, FRAC_BITS>,
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheUFixed<INT_BITS, FRAC_BITS> {
+    pub(in crate::high_level_api::integers) fn new(ciphertext: RadixCiphertext) -> Self {
+        Self {
+            ciphertext,
+            id: FheUFixedId,
+        }
+    }
+
+    /// Evaluated at compile time when the type is encrypted, so that invalid
+    /// numbers of bits are rejected by the compiler
+    const VALID_NUM_BITS: () = assert!(
+        INT_BITS + FRAC_BITS > 0 && INT_BITS + FRAC_BITS <= u128::BITS as usize,
+        "FheUFixed must have between 1 and 128 bits"
+    );
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        let () = Self::VALID_NUM_BITS;
+        let bits_per_block = message_modulus.0.ilog2() as usize;
+        let num_blocks = (INT_BITS + FRAC_BITS + bits_per_block - 1) / bits_per_block;
+        assert!(
+            num_blocks * bits_per_block <= u128::BITS as usize,
+            "FheUFixed<{INT_BITS}, {FRAC_BITS}> does not fit in 128 bits"
+        );
+        num_blocks
+    }
+
+    /// Returns the number of blocks needed to hold `FRAC_BITS` bits
+    fn num_fractional_blocks(message_modulus: MessageModulus) -> usize {
+        let bits_per_block = message_modulus.0.ilog2() as usize;
+        (FRAC_BITS + bits_per_block - 1) / bits_per_block
+    }
+
+    fn scale() -> f64 {
+        2f64.powi(FRAC_BITS as i32)
+    }
+
+    /// Returns the integer encoding the value, rounded to the nearest
+    /// representable value
+    ///
+    /// Returns an error if the value is out of the range of the type (or is NaN).
+    fn encode(value: f64) -> Result<u128, Error> {
+        let () = Self::VALID_NUM_BITS;
+        let max_value = 2f64.powi(INT_BITS as i32);
+        if !(0.0..max_value).contains(&value) {
+            return Err(Error::OutOfRange);
+        }
+        let max_encoded = u128::MAX >> (u128::BITS as usize - (INT_BITS + FRAC_BITS));
+        Ok(((value * Self::scale()).round() as u128).min(max_encoded))
+    }
+
+    fn decode(encoded: u128) -> f64 {
+        encoded as f64 / Self::scale()
+    }
+
+    /// Multiplies the two encodings, and removes the extra `FRAC_BITS` of the product
+    fn mul_encoded(
+        server_key: &ServerKey,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        // The product needs FRAC_BITS more bits than the result, so that the shift
+        // does not bring wrapped bits into the result
+        let num_extra_blocks = Self::num_fractional_blocks(server_key.key.message_modulus);
+        let lhs = server_key.extend_radix_with_trivial_zero_blocks_msb(lhs, num_extra_blocks);
+        let rhs = server_key.extend_radix_with_trivial_zero_blocks_msb(rhs, num_extra_blocks);
+
+        let product = server_key.mul_parallelized(&lhs, &rhs);
+        let mut result = server_key.scalar_right_shift_parallelized(&product, FRAC_BITS as u64);
+        server_key.trim_radix_blocks_msb_assign(&mut result, num_extra_blocks);
+        result
+    }
+
+    /// Divides the two encodings, after having added `FRAC_BITS` to the numerator
+    fn div_encoded(
+        server_key: &ServerKey,
+        numerator: &RadixCiphertext,
+        divisor: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let num_extra_blocks = Self::num_fractional_blocks(server_key.key.message_modulus);
+        let numerator =
+            server_key.extend_radix_with_trivial_zero_blocks_msb(numerator, num_extra_blocks);
+        let numerator = server_key.scalar_left_shift_parallelized(&numerator, FRAC_BITS as u64);
+        let divisor =
+            server_key.extend_radix_with_trivial_zero_blocks_msb(divisor, num_extra_blocks);
+
+        let (mut quotient, _) = server_key.div_rem_parallelized(&numerator, &divisor);
+        server_key.trim_radix_blocks_msb_assign(&mut quotient, num_extra_blocks);
+        quotient
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheTryEncrypt<f64, ClientKey>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    type Error = Error;
+
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let id = FheUFixedId::<INT_BITS, FRAC_BITS>;
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(id.type_variant()))
+            .unwrap_display();
+        let num_blocks = Self::num_blocks(integer_client_key.parameters().message_modulus());
+        let ciphertext = integer_client_key.encrypt_radix(Self::encode(value)?, num_blocks);
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheTryTrivialEncrypt<f64>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    type Error = Error;
+
+    fn try_encrypt_trivial(value: f64) -> Result<Self, Self::Error> {
+        let id = FheUFixedId::<INT_BITS, FRAC_BITS>;
+        let encoded = Self::encode(value)?;
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            let num_blocks = Self::num_blocks(integer_key.key.message_modulus);
+            integer_key.create_trivial_radix(encoded, num_blocks)
+        });
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheTrivialEncrypt<f64>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    #[track_caller]
+    fn encrypt_trivial(value: f64) -> Self {
+        Self::try_encrypt_trivial(value).unwrap()
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheDecrypt<f64>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(self.id.type_variant()))
+            .unwrap_display();
+        let encoded: u128 = integer_client_key.decrypt_radix(&self.ciphertext);
+        Self::decode(encoded)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheEq<&Self>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = FheIntegerBool;
+
+    fn eq(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .eq_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ne(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .ne_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheOrd<&Self>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = FheIntegerBool;
+
+    fn lt(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .lt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn le(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .le_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn gt(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .gt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ge(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .ge_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheMax<&Self>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = Self;
+
+    fn max(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .max_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheMin<&Self>
+    for FheUFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = Self;
+
+    fn min(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .min_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result)
+    }
+}
+
+/// An encrypted signed fixed-point number
+///
+/// The number has `INT_BITS` bits of integer part, including the sign bit, and `FRAC_BITS`
+/// bits of fractional part, it is stored as the two's complement integer `value * 2^FRAC_BITS`
+/// encrypted in a radix ciphertext (see [FheInt8](crate::high_level_api::FheInt8)).
+///
+/// As for [FheUFixed], the number of blocks is the smallest that can hold
+/// `INT_BITS + FRAC_BITS` bits, the sign bit being the most significant bit of the blocks.
+///
+/// The results of multiplications are rounded towards negative infinity,
+/// and the results of divisions are rounded towards zero.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheIFixed};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let balance = FheIFixed::<8, 8>::encrypt(12.5, &client_key);
+/// let change = FheIFixed::<8, 8>::encrypt(-3.25, &client_key);
+///
+/// let total = balance * change;
+///
+/// let decrypted: f64 = total.decrypt(&client_key);
+/// assert_eq!(decrypted, -40.625);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheIFixed<const INT_BITS: usize, const FRAC_BITS: usize> {
+    pub(in crate::high_level_api::integers) ciphertext: SignedRadixCiphertext,
+    pub(in crate::high_level_api::integers) id: FheIFixedId<INT_BITS, FRAC_BITS>,
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheIFixed<INT_BITS, FRAC_BITS> {
+    pub(in crate::high_level_api::integers) fn new(ciphertext: SignedRadixCiphertext) -> Self {
+        Self {
+            ciphertext,
+            id: FheIFixedId,
+        }
+    }
+
+    /// Evaluated at compile time when the type is encrypted, so that invalid
+    /// numbers of bits are rejected by the compiler
+    const VALID_NUM_BITS: () = assert!(
+        INT_BITS > 0 && INT_BITS + FRAC_BITS <= u128::BITS as usize,
+        "FheIFixed must have at least the sign bit and at most 128 bits"
+    );
+
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        let () = Self::VALID_NUM_BITS;
+        let bits_per_block = message_modulus.0.ilog2() as usize;
+        let num_blocks = (INT_BITS + FRAC_BITS + bits_per_block - 1) / bits_per_block;
+        assert!(
+            num_blocks * bits_per_block <= u128::BITS as usize,
+            "FheIFixed<{INT_BITS}, {FRAC_BITS}> does not fit in 128 bits"
+        );
+        num_blocks
+    }
+
+    fn scale() -> f64 {
+        2f64.powi(FRAC_BITS as i32)
+    }
+
+    /// Returns the integer encoding the value, rounded to the nearest
+    /// representable value
+    ///
+    /// Returns an error if the value is out of the range of the type (or is NaN).
+    fn encode(value: f64) -> Result<i128, Error> {
+        let () = Self::VALID_NUM_BITS;
+        let max_value = 2f64.powi(INT_BITS as i32 - 1);
+        if !(-max_value..max_value).contains(&value) {
+            return Err(Error::OutOfRange);
+        }
+        let max_encoded = i128::MAX >> (u128::BITS as usize - (INT_BITS + FRAC_BITS));
+        Ok(((value * Self::scale()).round() as i128).min(max_encoded))
+    }
+
+    fn decode(encoded: i128) -> f64 {
+        encoded as f64 / Self::scale()
+    }
+
+    /// Multiplies the two encodings, and removes the extra `FRAC_BITS` of the product
+    fn mul_encoded(
+        server_key: &ServerKey,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        // Same as for FheUFixed, but the inputs are sign extended
+        // and the shift is arithmetic
+        let num_extra_blocks =
+            FheUFixed::<INT_BITS, FRAC_BITS>::num_fractional_blocks(server_key.key.message_modulus);
+        let lhs = server_key.extend_signed_radix_with_sign_msb(lhs, num_extra_blocks);
+        let rhs = server_key.extend_signed_radix_with_sign_msb(rhs, num_extra_blocks);
+
+        let product = server_key.signed_mul_parallelized(&lhs, &rhs);
+        let mut result =
+            server_key.signed_scalar_right_shift_parallelized(&product, FRAC_BITS as u64);
+        result
+            .blocks
+            .truncate(result.blocks.len() - num_extra_blocks);
+        result
+    }
+
+    /// Divides the two encodings, after having added `FRAC_BITS` to the numerator
+    fn div_encoded(
+        server_key: &ServerKey,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let num_extra_blocks =
+            FheUFixed::<INT_BITS, FRAC_BITS>::num_fractional_blocks(server_key.key.message_modulus);
+        let numerator = server_key.extend_signed_radix_with_sign_msb(numerator, num_extra_blocks);
+        // In two's complement, the left shift is the same for signed and unsigned values
+        let numerator = server_key.scalar_left_shift_parallelized(
+            &RadixCiphertext::from(numerator.blocks),
+            FRAC_BITS as u64,
+        );
+        let divisor = server_key.extend_signed_radix_with_sign_msb(divisor, num_extra_blocks);
+
+        let mut quotient = server_key
+            .signed_div_parallelized(&SignedRadixCiphertext::from_unsigned(numerator), &divisor);
+        quotient
+            .blocks
+            .truncate(quotient.blocks.len() - num_extra_blocks);
+        quotient
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheTryEncrypt<f64, ClientKey>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    type Error = Error;
+
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let id = FheIFixedId::<INT_BITS, FRAC_BITS>;
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(id.type_variant()))
+            .unwrap_display();
+        let num_blocks = Self::num_blocks(integer_client_key.parameters().message_modulus());
+        let ciphertext = integer_client_key.encrypt_signed_radix(Self::encode(value)?, num_blocks);
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheTryTrivialEncrypt<f64>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    type Error = Error;
+
+    fn try_encrypt_trivial(value: f64) -> Result<Self, Self::Error> {
+        let id = FheIFixedId::<INT_BITS, FRAC_BITS>;
+        let encoded = Self::encode(value)?;
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            let num_blocks = Self::num_blocks(integer_key.key.message_modulus);
+            integer_key.create_trivial_signed_radix(encoded, num_blocks)
+        });
+        Ok(Self::new(ciphertext))
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheTrivialEncrypt<f64>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    #[track_caller]
+    fn encrypt_trivial(value: f64) -> Self {
+        Self::try_encrypt_trivial(value).unwrap()
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheDecrypt<f64>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(self.id.type_variant()))
+            .unwrap_display();
+        let encoded: i128 = integer_client_key.decrypt_signed_radix(&self.ciphertext);
+        Self::decode(encoded)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheEq<&Self>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = FheIntegerBool;
+
+    fn eq(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_eq_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ne(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_ne_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheOrd<&Self>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = FheIntegerBool;
+
+    fn lt(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_lt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn le(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_le_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn gt(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_gt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ge(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_ge_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheMax<&Self>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = Self;
+
+    fn max(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_max_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result)
+    }
+}
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> FheMin<&Self>
+    for FheIFixed<INT_BITS, FRAC_BITS>
+{
+    type Output = Self;
+
+    fn min(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_min_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result)
+    }
+}
+
+macro_rules! fixed_impl_operation (
+    ($fixed:ident: $rust_trait_name:ident($rust_trait_method:ident) => $method:expr) => {
+        impl<const INT_BITS: usize, const FRAC_BITS: usize, B> $rust_trait_name<B>
+            for $fixed<INT_BITS, FRAC_BITS>
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<const INT_BITS: usize, const FRAC_BITS: usize, B> $rust_trait_name<B>
+            for &$fixed<INT_BITS, FRAC_BITS>
+        where
+            B: Borrow<$fixed<INT_BITS, FRAC_BITS>>,
+        {
+            type Output = $fixed<INT_BITS, FRAC_BITS>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+                    let borrowed = rhs.borrow();
+                    $method(integer_key.pbs_key(), &self.ciphertext, &borrowed.ciphertext)
+                });
+                $fixed::new(ciphertext)
+            }
+        }
+    }
+);
+
+macro_rules! fixed_impl_operation_assign (
+    ($fixed:ident: $rust_trait_name:ident($rust_trait_method:ident) => $rust_op_method:ident) => {
+        impl<const INT_BITS: usize, const FRAC_BITS: usize, I> $rust_trait_name<I>
+            for $fixed<INT_BITS, FRAC_BITS>
+        where
+            I: Borrow<Self>,
+        {
+            fn $rust_trait_method(&mut self, rhs: I) {
+                *self = (&*self).$rust_op_method(rhs);
+            }
+        }
+    }
+);
+
+fixed_impl_operation!(FheUFixed: Add(add) => ServerKey::add_parallelized);
+fixed_impl_operation!(FheUFixed: Sub(sub) => ServerKey::sub_parallelized);
+fixed_impl_operation!(FheUFixed: Mul(mul) => FheUFixed::<INT_BITS, FRAC_BITS>::mul_encoded);
+fixed_impl_operation!(FheUFixed: Div(div) => FheUFixed::<INT_BITS, FRAC_BITS>::div_encoded);
+
+fixed_impl_operation_assign!(FheUFixed: AddAssign(add_assign) => add);
+fixed_impl_operation_assign!(FheUFixed: SubAssign(sub_assign) => sub);
+fixed_impl_operation_assign!(FheUFixed: MulAssign(mul_assign) => mul);
+fixed_impl_operation_assign!(FheUFixed: DivAssign(div_assign) => div);
+
+fixed_impl_operation!(FheIFixed: Add(add) => ServerKey::signed_add_parallelized);
+fixed_impl_operation!(FheIFixed: Sub(sub) => ServerKey::signed_sub_parallelized);
+fixed_impl_operation!(FheIFixed: Mul(mul) => FheIFixed::<INT_BITS, FRAC_BITS>::mul_encoded);
+fixed_impl_operation!(FheIFixed: Div(div) => FheIFixed::<INT_BITS, FRAC_BITS>::div_encoded);
+
+fixed_impl_operation_assign!(FheIFixed: AddAssign(add_assign) => add);
+fixed_impl_operation_assign!(FheIFixed: SubAssign(sub_assign) => sub);
+fixed_impl_operation_assign!(FheIFixed: MulAssign(mul_assign) => mul);
+fixed_impl_operation_assign!(FheIFixed: DivAssign(div_assign) => div);
@@ -0,0 +1,1074 @@
+use std::borrow::Borrow;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::errors::{OutOfRangeError, UninitializedClientKey, UnwrapResultExt};
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::internal_traits::TypeIdentifier;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheOrd, FheTrivialEncrypt, FheTryEncrypt, FheTryTrivialEncrypt,
+};
+use crate::high_level_api::ClientKey;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::{Ciphertext, MessageModulus};
+
+use super::base::GenericInteger;
+use super::boolean::FheIntegerBool;
+use super::static_::FheFloatId;
+
+/// An encrypted floating point number, with the same layout as the IEEE 754 binary formats
+///
+/// **This type is experimental**, its operations are expensive and do not follow
+/// IEEE 754 exactly:
+///
+/// - there are no infinities, no NaNs and no subnormal numbers, values too small to be represented
+///   are flushed to zero, and values too big saturate to the greatest finite value;
+/// - the results of operations are truncated (i.e. rounded towards zero);
+/// - zero has no sign.
+///
+/// Numbers can be compared to clear values, which are first rounded to the nearest representable
+/// value, as when they are encrypted.
+///
+/// The number is stored as three radix ciphertexts: the sign (one block), the biased exponent
+/// (`EXP_BITS` bits) and the mantissa (`MANT_BITS` bits, plus the leading 1 which is stored
+/// explicitly).
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat16};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheFloat16::encrypt(1.5f64, &client_key);
+/// let b = FheFloat16::encrypt(-0.375f64, &client_key);
+///
+/// let result = &a * &b;
+///
+/// let decrypted: f64 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, -0.5625);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct FheFloat<const EXP_BITS: usize, const MANT_BITS: usize> {
+    pub(in crate::high_level_api::integers) sign: RadixCiphertext,
+    pub(in crate::high_level_api::integers) exponent: RadixCiphertext,
+    pub(in crate::high_level_api::integers) mantissa: RadixCiphertext,
+    pub(in crate::high_level_api::integers) id: FheFloatId<EXP_BITS, MANT_BITS>,
+}
+
+/// An encrypted floating point number with the layout of an IEEE 754 `binary16`
+pub type FheFloat16 = FheFloat<5, 10>;
+/// An encrypted floating point number with the layout of an IEEE 754 `binary32`
+pub type FheFloat32 = FheFloat<8, 23>;
+
+/// The number of blocks of each part of a [FheFloat],
+/// and of the wider integers used during the computations
+#[derive(Copy, Clone)]
+struct FloatLayout {
+    bits_per_block: usize,
+    exponent_blocks: usize,
+    mantissa_blocks: usize,
+    /// Blocks of the exponents during the computations,
+    /// they must hold intermediate results that do not fit in `EXP_BITS` bits
+    work_exponent_blocks: usize,
+    /// Blocks of the mantissas during additions,
+    /// which have one guard block below the mantissa and room for a carry above it
+    work_mantissa_blocks: usize,
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheFloat<EXP_BITS, MANT_BITS> {
+    const BIAS: u64 = (1 << (EXP_BITS - 1)) - 1;
+    /// The greatest biased exponent, the all ones exponent being reserved
+    /// for infinities and NaNs in IEEE 754
+    const MAX_EXPONENT: u64 = (1 << EXP_BITS) - 2;
+    /// The greatest mantissa, including the leading 1
+    const MAX_MANTISSA: u64 = (1 << (MANT_BITS + 1)) - 1;
+
+    pub(in crate::high_level_api::integers) fn new(
+        sign: RadixCiphertext,
+        exponent: RadixCiphertext,
+        mantissa: RadixCiphertext,
+    ) -> Self {
+        Self {
+            sign,
+            exponent,
+            mantissa,
+            id: FheFloatId,
+        }
+    }
+
+    fn layout(message_modulus: MessageModulus) -> FloatLayout {
+        assert!(
+            (2..=11).contains(&EXP_BITS) && (1..=52).contains(&MANT_BITS),
+            "FheFloat<{EXP_BITS}, {MANT_BITS}> is not supported, \
+            the exponent must have at most 11 bits and the mantissa at most 52 bits"
+        );
+        let bits_per_block = message_modulus.0.ilog2() as usize;
+        let blocks_for = |num_bits: usize| (num_bits + bits_per_block - 1) / bits_per_block;
+
+        let mantissa_blocks = blocks_for(MANT_BITS + 1);
+        let work_mantissa_blocks = mantissa_blocks + 2;
+        let work_mantissa_bits = work_mantissa_blocks * bits_per_block;
+        // Large enough for the sum of two exponents, for the leading zeros of the
+        // work mantissas, and for the shifts of small integers
+        let work_exponent_bits = EXP_BITS
+            .max(usize::BITS as usize - work_mantissa_bits.leading_zeros() as usize)
+            .max(8)
+            + 2;
+
+        FloatLayout {
+            bits_per_block,
+            exponent_blocks: blocks_for(EXP_BITS),
+            mantissa_blocks,
+            work_exponent_blocks: blocks_for(work_exponent_bits),
+            work_mantissa_blocks,
+        }
+    }
+
+    /// Returns the sign, the biased exponent and the mantissa (with its leading 1)
+    /// of the value, rounded to the nearest representable value
+    ///
+    /// Returns `None` if the value is not finite or is too big to be represented.
+    fn try_encode(value: f64) -> Option<(u64, u64, u64)> {
+        if !value.is_finite() {
+            return None;
+        }
+        let bits = value.to_bits();
+        let f64_exponent = ((bits >> 52) & 0x7ff) as i64;
+        if f64_exponent == 0 {
+            // Zero or subnormal
+            return Some((0, 0, 0));
+        }
+
+        let mut mantissa = (bits & ((1 << 52) - 1)) | (1 << 52);
+        let mut exponent = f64_exponent - 1023 + Self::BIAS as i64;
+        let num_dropped_bits = 52 - MANT_BITS;
+        if num_dropped_bits > 0 {
+            mantissa = (mantissa + (1 << (num_dropped_bits - 1))) >> num_dropped_bits;
+        }
+        if mantissa > Self::MAX_MANTISSA {
+            // The rounding carried into a new bit
+            mantissa >>= 1;
+            exponent += 1;
+        }
+
+        if exponent <= 0 {
+            return Some((0, 0, 0));
+        }
+        if exponent > Self::MAX_EXPONENT as i64 {
+            return None;
+        }
+        Some((bits >> 63, exponent as u64, mantissa))
+    }
+
+    /// Same as [Self::try_encode], but panics if the value cannot be represented
+    fn encode(value: f64) -> (u64, u64, u64) {
+        Self::try_encode(value).unwrap_or_else(|| {
+            panic!(
+                "{value} is out of the range of FheFloat<{EXP_BITS}, {MANT_BITS}>, \
+                which only supports finite values"
+            )
+        })
+    }
+
+    fn decode(sign: u64, exponent: u64, mantissa: u64) -> f64 {
+        if exponent == 0 {
+            return 0.0;
+        }
+        let magnitude =
+            mantissa as f64 * 2f64.powi(exponent as i32 - Self::BIAS as i32 - MANT_BITS as i32);
+        if sign == 1 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    /// Returns the magnitude of the number as a single integer,
+    /// whose order is the order of the absolute values
+    fn magnitude(&self) -> RadixCiphertext {
+        let mut blocks = self.mantissa.blocks.clone();
+        blocks.extend(self.exponent.blocks.iter().cloned());
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Returns a representation of the number as a single integer,
+    /// two numbers being equal if and only if their representations are equal
+    fn packed(&self) -> RadixCiphertext {
+        let mut packed = self.magnitude();
+        packed.blocks.extend(self.sign.blocks.iter().cloned());
+        packed
+    }
+
+    /// Builds the final result of an operation from its parts
+    ///
+    /// `exponent` has at least `exponent_blocks` blocks, and is only meaningful if neither
+    /// `is_zero` nor `is_overflow` is true.
+    fn finalize(
+        server_key: &ServerKey,
+        layout: FloatLayout,
+        sign: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        mantissa: &RadixCiphertext,
+        is_zero: &RadixCiphertext,
+        is_overflow: &RadixCiphertext,
+    ) -> Self {
+        let zero_if = |value: &RadixCiphertext| {
+            let zero = server_key.create_trivial_zero_radix(value.blocks.len());
+            server_key.if_then_else_parallelized(is_zero, &zero, value)
+        };
+        let select = |value: &RadixCiphertext, overflow_value: u64| {
+            let overflow_value =
+                server_key.create_trivial_radix(overflow_value, value.blocks.len());
+            zero_if(&server_key.if_then_else_parallelized(is_overflow, &overflow_value, value))
+        };
+
+        let (sign, (mut exponent, mantissa)) = rayon::join(
+            || zero_if(sign),
+            || {
+                rayon::join(
+                    || select(exponent, Self::MAX_EXPONENT),
+                    || select(mantissa, Self::MAX_MANTISSA),
+                )
+            },
+        );
+        let num_blocks_to_remove = exponent.blocks.len() - layout.exponent_blocks;
+        server_key.trim_radix_blocks_msb_assign(&mut exponent, num_blocks_to_remove);
+        Self::new(sign, exponent, mantissa)
+    }
+
+    fn add_impl(server_key: &ServerKey, lhs: &Self, rhs: &Self) -> Self {
+        let layout = Self::layout(server_key.key.message_modulus);
+        let work_mantissa_bits = layout.work_mantissa_blocks * layout.bits_per_block;
+
+        // The operands are ordered by absolute value, so that the smaller one is shifted
+        // to be aligned with the bigger one
+        let (rhs_is_bigger, signs_differ) = rayon::join(
+            || to_condition(server_key.gt_block_parallelized(&rhs.magnitude(), &lhs.magnitude())),
+            || server_key.bitxor_parallelized(&lhs.sign, &rhs.sign),
+        );
+        let select = |for_rhs: &RadixCiphertext, for_lhs: &RadixCiphertext| {
+            server_key.if_then_else_parallelized(&rhs_is_bigger, for_rhs, for_lhs)
+        };
+        let ((sign, big_exponent), (small_exponent, (big_mantissa, small_mantissa))) = rayon::join(
+            || {
+                rayon::join(
+                    || select(&rhs.sign, &lhs.sign),
+                    || select(&rhs.exponent, &lhs.exponent),
+                )
+            },
+            || {
+                rayon::join(
+                    || select(&lhs.exponent, &rhs.exponent),
+                    || {
+                        rayon::join(
+                            || select(&rhs.mantissa, &lhs.mantissa),
+                            || select(&lhs.mantissa, &rhs.mantissa),
+                        )
+                    },
+                )
+            },
+        );
+
+        let widen = |mantissa: &RadixCiphertext| {
+            let mantissa = server_key.extend_radix_with_trivial_zero_blocks_lsb(mantissa, 1);
+            server_key.extend_radix_with_trivial_zero_blocks_msb(
+                &mantissa,
+                layout.work_mantissa_blocks - layout.mantissa_blocks - 1,
+            )
+        };
+        let big_mantissa = widen(&big_mantissa);
+
+        let aligned_small_mantissa = {
+            let small_mantissa = widen(&small_mantissa);
+            let exponent_difference = server_key.sub_parallelized(&big_exponent, &small_exponent);
+            // The shift only looks at the bits that are needed for shifts smaller than the
+            // number of bits, so bigger shifts are handled separately
+            let (is_too_far, shifted) = rayon::join(
+                || {
+                    to_condition(server_key.scalar_ge_block_parallelized(
+                        &exponent_difference,
+                        work_mantissa_bits as u64,
+                    ))
+                },
+                || {
+                    let shift = resize(
+                        server_key,
+                        &exponent_difference,
+                        layout.work_mantissa_blocks,
+                    );
+                    server_key.right_shift_parallelized(&small_mantissa, &shift)
+                },
+            );
+            let zero = server_key.create_trivial_zero_radix(layout.work_mantissa_blocks);
+            server_key.if_then_else_parallelized(&is_too_far, &zero, &shifted)
+        };
+
+        let (sum, difference) = rayon::join(
+            || server_key.add_parallelized(&big_mantissa, &aligned_small_mantissa),
+            || server_key.sub_parallelized(&big_mantissa, &aligned_small_mantissa),
+        );
+        let result = server_key.if_then_else_parallelized(&signs_differ, &difference, &sum);
+
+        // Normalization: the result is shifted so that its leading 1 is at the position
+        // of the leading 1 of the mantissas, the exponent is adjusted accordingly
+        let (leading_zeros, result_is_zero) = rayon::join(
+            || server_key.leading_zeros_parallelized(&result),
+            || to_condition(server_key.scalar_eq_block_parallelized(&result, 0u64)),
+        );
+        let (mantissa, (exponent, is_underflow)) = rayon::join(
+            || {
+                let normalized = server_key.left_shift_parallelized(&result, &leading_zeros);
+                let mut mantissa = server_key.scalar_right_shift_parallelized(
+                    &normalized,
+                    (work_mantissa_bits - 1 - MANT_BITS) as u64,
+                );
+                server_key.trim_radix_blocks_msb_assign(
+                    &mut mantissa,
+                    layout.work_mantissa_blocks - layout.mantissa_blocks,
+                );
+                mantissa
+            },
+            || {
+                let leading_zeros = resize(server_key, &leading_zeros, layout.work_exponent_blocks);
+                let big_exponent = resize(server_key, &big_exponent, layout.work_exponent_blocks);
+                let exponent_if_no_leading_zeros = server_key.scalar_add_parallelized(
+                    &big_exponent,
+                    (work_mantissa_bits - 1 - MANT_BITS - layout.bits_per_block) as u64,
+                );
+                rayon::join(
+                    || server_key.sub_parallelized(&exponent_if_no_leading_zeros, &leading_zeros),
+                    || {
+                        to_condition(
+                            server_key.le_block_parallelized(
+                                &exponent_if_no_leading_zeros,
+                                &leading_zeros,
+                            ),
+                        )
+                    },
+                )
+            },
+        );
+        let (is_zero, is_overflow) = rayon::join(
+            || server_key.bitor_parallelized(&is_underflow, &result_is_zero),
+            || to_condition(server_key.scalar_gt_block_parallelized(&exponent, Self::MAX_EXPONENT)),
+        );
+
+        Self::finalize(
+            server_key,
+            layout,
+            &sign,
+            &exponent,
+            &mantissa,
+            &is_zero,
+            &is_overflow,
+        )
+    }
+
+    fn sub_impl(server_key: &ServerKey, lhs: &Self, rhs: &Self) -> Self {
+        Self::add_impl(server_key, lhs, &Self::neg_impl(server_key, rhs))
+    }
+
+    fn mul_impl(server_key: &ServerKey, lhs: &Self, rhs: &Self) -> Self {
+        let layout = Self::layout(server_key.key.message_modulus);
+        let mantissa_blocks = layout.mantissa_blocks;
+
+        let ((sign, (product, product_is_high)), (exponent_sum, has_zero_input)) = rayon::join(
+            || {
+                rayon::join(
+                    || server_key.bitxor_parallelized(&lhs.sign, &rhs.sign),
+                    || {
+                        let lhs_mantissa = server_key.extend_radix_with_trivial_zero_blocks_msb(
+                            &lhs.mantissa,
+                            mantissa_blocks,
+                        );
+                        let rhs_mantissa = server_key.extend_radix_with_trivial_zero_blocks_msb(
+                            &rhs.mantissa,
+                            mantissa_blocks,
+                        );
+                        let product = server_key.mul_parallelized(&lhs_mantissa, &rhs_mantissa);
+                        // The product of the mantissas is in [2^(2M), 2^(2M+2)),
+                        // its most significant bit tells where its leading 1 is
+                        let mut is_high = server_key
+                            .scalar_right_shift_parallelized(&product, 2 * MANT_BITS as u64 + 1);
+                        server_key
+                            .trim_radix_blocks_msb_assign(&mut is_high, 2 * mantissa_blocks - 1);
+                        (product, is_high)
+                    },
+                )
+            },
+            || {
+                rayon::join(
+                    || {
+                        let lhs_exponent =
+                            resize(server_key, &lhs.exponent, layout.work_exponent_blocks);
+                        let rhs_exponent =
+                            resize(server_key, &rhs.exponent, layout.work_exponent_blocks);
+                        server_key.add_parallelized(&lhs_exponent, &rhs_exponent)
+                    },
+                    || {
+                        let (lhs_is_zero, rhs_is_zero) = rayon::join(
+                            || server_key.scalar_eq_block_parallelized(&lhs.exponent, 0u64),
+                            || server_key.scalar_eq_block_parallelized(&rhs.exponent, 0u64),
+                        );
+                        server_key.bitor_parallelized(
+                            &to_condition(lhs_is_zero),
+                            &to_condition(rhs_is_zero),
+                        )
+                    },
+                )
+            },
+        );
+
+        let (mantissa, (exponent, (is_underflow, is_overflow))) = rayon::join(
+            || {
+                let (high, low) = rayon::join(
+                    || server_key.scalar_right_shift_parallelized(&product, MANT_BITS as u64 + 1),
+                    || server_key.scalar_right_shift_parallelized(&product, MANT_BITS as u64),
+                );
+                let mut mantissa =
+                    server_key.if_then_else_parallelized(&product_is_high, &high, &low);
+                server_key.trim_radix_blocks_msb_assign(&mut mantissa, mantissa_blocks);
+                mantissa
+            },
+            || {
+                let product_is_high =
+                    resize(server_key, &product_is_high, layout.work_exponent_blocks);
+                let exponent_sum = server_key.add_parallelized(&exponent_sum, &product_is_high);
+                rayon::join(
+                    || server_key.scalar_sub_parallelized(&exponent_sum, Self::BIAS),
+                    || {
+                        rayon::join(
+                            || {
+                                to_condition(
+                                    server_key
+                                        .scalar_le_block_parallelized(&exponent_sum, Self::BIAS),
+                                )
+                            },
+                            || {
+                                to_condition(server_key.scalar_gt_block_parallelized(
+                                    &exponent_sum,
+                                    Self::BIAS + Self::MAX_EXPONENT,
+                                ))
+                            },
+                        )
+                    },
+                )
+            },
+        );
+        let is_zero = server_key.bitor_parallelized(&is_underflow, &has_zero_input);
+
+        Self::finalize(
+            server_key,
+            layout,
+            &sign,
+            &exponent,
+            &mantissa,
+            &is_zero,
+            &is_overflow,
+        )
+    }
+
+    fn neg_impl(server_key: &ServerKey, value: &Self) -> Self {
+        // Zero has no sign, so only the sign of non zero values is flipped
+        let is_not_zero =
+            to_condition(server_key.scalar_ne_block_parallelized(&value.exponent, 0u64));
+        let sign = server_key.bitxor_parallelized(&value.sign, &is_not_zero);
+        Self::new(sign, value.exponent.clone(), value.mantissa.clone())
+    }
+
+    /// Compares two numbers using the comparison of their magnitudes
+    ///
+    /// When the signs are different, the result is the sign of `sign_source`
+    fn compare(
+        &self,
+        rhs: &Self,
+        sign_source: &RadixCiphertext,
+        positive_comparison: fn(&ServerKey, &RadixCiphertext, &RadixCiphertext) -> Ciphertext,
+        negative_comparison: fn(&ServerKey, &RadixCiphertext, &RadixCiphertext) -> Ciphertext,
+    ) -> FheIntegerBool {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let (lhs_magnitude, rhs_magnitude) = (self.magnitude(), rhs.magnitude());
+            let ((if_positive, if_negative), signs_differ) = rayon::join(
+                || {
+                    rayon::join(
+                        || {
+                            to_condition(positive_comparison(
+                                server_key,
+                                &lhs_magnitude,
+                                &rhs_magnitude,
+                            ))
+                        },
+                        || {
+                            to_condition(negative_comparison(
+                                server_key,
+                                &lhs_magnitude,
+                                &rhs_magnitude,
+                            ))
+                        },
+                    )
+                },
+                || server_key.bitxor_parallelized(&self.sign, &rhs.sign),
+            );
+            // When the signs are the same, both are the sign of self
+            let same_signs_result =
+                server_key.if_then_else_parallelized(&self.sign, &if_negative, &if_positive);
+            server_key.if_then_else_parallelized(&signs_differ, sign_source, &same_signs_result)
+        });
+        FheIntegerBool::from_condition(inner_result)
+    }
+
+    /// Returns the sign of a clear value, and its magnitude as [Self::magnitude] does
+    /// for an encrypted one
+    fn clear_sign_and_magnitude(layout: FloatLayout, value: f64) -> (u64, u128) {
+        let (sign, exponent, mantissa) = Self::encode(value);
+        let exponent_shift = layout.mantissa_blocks * layout.bits_per_block;
+        let magnitude = u128::from(mantissa) | (u128::from(exponent) << exponent_shift);
+        (sign, magnitude)
+    }
+
+    /// Returns the representation of a clear value as a single integer,
+    /// as [Self::packed] does for an encrypted one
+    fn clear_packed(layout: FloatLayout, value: f64) -> u128 {
+        let (sign, magnitude) = Self::clear_sign_and_magnitude(layout, value);
+        let sign_shift = (layout.mantissa_blocks + layout.exponent_blocks) * layout.bits_per_block;
+        magnitude | (u128::from(sign) << sign_shift)
+    }
+
+    /// Compares the number to a clear value using the comparison of their magnitudes
+    ///
+    /// As the sign of `rhs` is known, only the comparison of the magnitudes matching
+    /// this sign is computed. When the signs are different, the result is `is_lower`
+    /// if `rhs` is positive, and the opposite otherwise.
+    ///
+    /// Values of `rhs` that are out of range are greater (or lower) than any encrypted
+    /// number, so the result is known without computation. NaN compares to nothing.
+    fn scalar_compare(
+        &self,
+        rhs: f64,
+        positive_comparison: fn(&ServerKey, &RadixCiphertext, u128) -> Ciphertext,
+        negative_comparison: fn(&ServerKey, &RadixCiphertext, u128) -> Ciphertext,
+        is_lower: bool,
+    ) -> FheIntegerBool {
+        if Self::try_encode(rhs).is_none() {
+            let result = if is_lower { rhs > 0.0 } else { rhs < 0.0 };
+            return FheIntegerBool::encrypt_trivial(result);
+        }
+
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let layout = Self::layout(server_key.key.message_modulus);
+            let (rhs_sign, rhs_magnitude) = Self::clear_sign_and_magnitude(layout, rhs);
+
+            let comparison = if rhs_sign == 0 {
+                positive_comparison
+            } else {
+                negative_comparison
+            };
+            let magnitude_result = comparison(server_key, &self.magnitude(), rhs_magnitude);
+
+            let if_signs_differ = u64::from(is_lower == (rhs_sign == 0));
+            server_key.key.unchecked_evaluate_bivariate_function(
+                &self.sign.blocks[0],
+                &magnitude_result,
+                |sign, magnitude_result| {
+                    if sign == rhs_sign {
+                        magnitude_result
+                    } else {
+                        if_signs_differ
+                    }
+                },
+            )
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    /// Converts an encrypted unsigned integer to a floating point number
+    ///
+    /// The value is truncated if it has more significant bits than the mantissa,
+    /// and saturates to the greatest finite value if it is too big.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat16, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(200u8, &client_key);
+    ///
+    /// let result = FheFloat16::from_uint(&a);
+    ///
+    /// let decrypted: f64 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 200.0);
+    /// ```
+    pub fn from_uint<P>(value: &GenericInteger<P>) -> Self
+    where
+        P: IntegerParameter,
+    {
+        let id = FheFloatId::<EXP_BITS, MANT_BITS>;
+        id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let layout = Self::layout(server_key.key.message_modulus);
+            let value = &value.ciphertext;
+            let num_bits = value.blocks.len() * layout.bits_per_block;
+            // The exponent of the value before normalization may not fit in the exponent of
+            // the number, but it must fit in the exponent used for the computations
+            let max_exponent = Self::BIAS + num_bits as u64 - 1;
+            let exponent_blocks = layout.work_exponent_blocks.max(
+                (u64::BITS - max_exponent.leading_zeros()) as usize / layout.bits_per_block + 1,
+            );
+
+            let (leading_zeros, is_zero) = rayon::join(
+                || server_key.leading_zeros_parallelized(value),
+                || to_condition(server_key.scalar_eq_block_parallelized(value, 0u64)),
+            );
+            let (mantissa, (exponent, is_overflow)) = rayon::join(
+                || {
+                    // The leading 1 is moved to the most significant bit,
+                    // then the most significant bits are kept
+                    let normalized = server_key.left_shift_parallelized(value, &leading_zeros);
+                    if num_bits > MANT_BITS {
+                        let mantissa = server_key.scalar_right_shift_parallelized(
+                            &normalized,
+                            (num_bits - 1 - MANT_BITS) as u64,
+                        );
+                        resize(server_key, &mantissa, layout.mantissa_blocks)
+                    } else {
+                        let mantissa = resize(server_key, &normalized, layout.mantissa_blocks);
+                        server_key.scalar_left_shift_parallelized(
+                            &mantissa,
+                            (MANT_BITS + 1 - num_bits) as u64,
+                        )
+                    }
+                },
+                || {
+                    let leading_zeros = resize(server_key, &leading_zeros, exponent_blocks);
+                    let exponent_if_no_leading_zeros =
+                        server_key.create_trivial_radix(max_exponent, exponent_blocks);
+                    let exponent =
+                        server_key.sub_parallelized(&exponent_if_no_leading_zeros, &leading_zeros);
+                    let is_overflow = to_condition(
+                        server_key.scalar_gt_block_parallelized(&exponent, Self::MAX_EXPONENT),
+                    );
+                    (exponent, is_overflow)
+                },
+            );
+
+            let sign = server_key.create_trivial_zero_radix(1);
+            Self::finalize(
+                server_key,
+                layout,
+                &sign,
+                &exponent,
+                &mantissa,
+                &is_zero,
+                &is_overflow,
+            )
+        })
+    }
+
+    /// Converts the number to an encrypted unsigned integer
+    ///
+    /// The value is truncated towards zero, negative values give 0, and values too big
+    /// to be represented saturate to the greatest value of the integer type.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheFloat16, FheUint8};
+    ///
+    /// let config = ConfigBuilder::all_disabled()
+    ///     .enable_default_integers()
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheFloat16::encrypt(42.75f64, &client_key);
+    ///
+    /// let result: FheUint8 = a.to_uint();
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 42);
+    /// ```
+    pub fn to_uint<P>(&self) -> GenericInteger<P>
+    where
+        P: IntegerParameter,
+        P::Id: Default,
+    {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let layout = Self::layout(server_key.key.message_modulus);
+            let num_blocks = P::num_blocks(server_key.key.message_modulus);
+            let num_bits = num_blocks * layout.bits_per_block;
+            // Large enough to hold the mantissa shifted by less than num_bits
+            let work_blocks = num_blocks + layout.mantissa_blocks;
+
+            let exponent = resize(server_key, &self.exponent, layout.work_exponent_blocks);
+            let ((is_too_small, is_too_big), value) = rayon::join(
+                || {
+                    rayon::join(
+                        || {
+                            let is_below_one = to_condition(
+                                server_key.scalar_lt_block_parallelized(&exponent, Self::BIAS),
+                            );
+                            server_key.bitor_parallelized(&is_below_one, &self.sign)
+                        },
+                        || {
+                            to_condition(server_key.scalar_ge_block_parallelized(
+                                &exponent,
+                                Self::BIAS + num_bits as u64,
+                            ))
+                        },
+                    )
+                },
+                || {
+                    // value = mantissa * 2^(exponent - bias) / 2^MANT_BITS
+                    let shift = server_key.scalar_sub_parallelized(&exponent, Self::BIAS);
+                    let shift = resize(server_key, &shift, work_blocks);
+                    let mantissa = resize(server_key, &self.mantissa, work_blocks);
+                    let shifted = server_key.left_shift_parallelized(&mantissa, &shift);
+                    let mut value =
+                        server_key.scalar_right_shift_parallelized(&shifted, MANT_BITS as u64);
+                    server_key.trim_radix_blocks_msb_assign(&mut value, work_blocks - num_blocks);
+                    value
+                },
+            );
+
+            let max_value = RadixCiphertext::from(vec![
+                server_key.key.create_trivial(
+                    server_key.key.message_modulus.0 as u64 - 1
+                );
+                num_blocks
+            ]);
+            let zero = server_key.create_trivial_zero_radix(num_blocks);
+            let value = server_key.if_then_else_parallelized(&is_too_big, &max_value, &value);
+            server_key.if_then_else_parallelized(&is_too_small, &zero, &value)
+        });
+        GenericInteger::new(ciphertext, P::Id::default())
+    }
+}
+
+/// Wraps the block of a comparison result in a one-block integer, so it can be used as a condition
+fn to_condition(comparison_result: Ciphertext) -> RadixCiphertext {
+    RadixCiphertext::from(vec![comparison_result])
+}
+
+/// Changes the number of blocks of the ciphertext,
+/// by adding trivial zero blocks or removing the most significant ones
+fn resize(server_key: &ServerKey, ct: &RadixCiphertext, num_blocks: usize) -> RadixCiphertext {
+    let current_num_blocks = ct.blocks.len();
+    if num_blocks >= current_num_blocks {
+        server_key.extend_radix_with_trivial_zero_blocks_msb(ct, num_blocks - current_num_blocks)
+    } else {
+        let mut ct = ct.clone();
+        server_key.trim_radix_blocks_msb_assign(&mut ct, current_num_blocks - num_blocks);
+        ct
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheTryEncrypt<f64, ClientKey>
+    for FheFloat<EXP_BITS, MANT_BITS>
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: f64, key: &ClientKey) -> Result<Self, Self::Error> {
+        let id = FheFloatId::<EXP_BITS, MANT_BITS>;
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(id.type_variant()))
+            .unwrap_display();
+        let layout = Self::layout(integer_client_key.parameters().message_modulus());
+        let (sign, exponent, mantissa) = Self::try_encode(value).ok_or(OutOfRangeError)?;
+        Ok(Self::new(
+            integer_client_key.encrypt_radix(sign, 1),
+            integer_client_key.encrypt_radix(exponent, layout.exponent_blocks),
+            integer_client_key.encrypt_radix(mantissa, layout.mantissa_blocks),
+        ))
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheTryEncrypt<f32, ClientKey>
+    for FheFloat<EXP_BITS, MANT_BITS>
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: f32, key: &ClientKey) -> Result<Self, Self::Error> {
+        Self::try_encrypt(f64::from(value), key)
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheTryTrivialEncrypt<f64>
+    for FheFloat<EXP_BITS, MANT_BITS>
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt_trivial(value: f64) -> Result<Self, Self::Error> {
+        let id = FheFloatId::<EXP_BITS, MANT_BITS>;
+        let (sign, exponent, mantissa) = Self::try_encode(value).ok_or(OutOfRangeError)?;
+        let result = id.with_unwrapped_global(|integer_key| {
+            let integer_key = integer_key.pbs_key();
+            let layout = Self::layout(integer_key.key.message_modulus);
+            Self::new(
+                integer_key.create_trivial_radix(sign, 1),
+                integer_key.create_trivial_radix(exponent, layout.exponent_blocks),
+                integer_key.create_trivial_radix(mantissa, layout.mantissa_blocks),
+            )
+        });
+        Ok(result)
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheTrivialEncrypt<f64>
+    for FheFloat<EXP_BITS, MANT_BITS>
+{
+    #[track_caller]
+    fn encrypt_trivial(value: f64) -> Self {
+        Self::try_encrypt_trivial(value).unwrap()
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheDecrypt<f64>
+    for FheFloat<EXP_BITS, MANT_BITS>
+{
+    fn decrypt(&self, key: &ClientKey) -> f64 {
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(self.id.type_variant()))
+            .unwrap_display();
+        let sign: u64 = integer_client_key.decrypt_radix(&self.sign);
+        let exponent: u64 = integer_client_key.decrypt_radix(&self.exponent);
+        let mantissa: u64 = integer_client_key.decrypt_radix(&self.mantissa);
+        Self::decode(sign, exponent, mantissa)
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheDecrypt<f32>
+    for FheFloat<EXP_BITS, MANT_BITS>
+{
+    fn decrypt(&self, key: &ClientKey) -> f32 {
+        let value: f64 = self.decrypt(key);
+        value as f32
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheEq<&Self> for FheFloat<EXP_BITS, MANT_BITS> {
+    type Output = FheIntegerBool;
+
+    fn eq(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .eq_block_parallelized(&self.packed(), &rhs.packed())
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ne(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .ne_block_parallelized(&self.packed(), &rhs.packed())
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheOrd<&Self>
+    for FheFloat<EXP_BITS, MANT_BITS>
+{
+    type Output = FheIntegerBool;
+
+    fn lt(&self, rhs: &Self) -> Self::Output {
+        self.compare(
+            rhs,
+            &self.sign,
+            ServerKey::lt_block_parallelized,
+            ServerKey::gt_block_parallelized,
+        )
+    }
+
+    fn le(&self, rhs: &Self) -> Self::Output {
+        self.compare(
+            rhs,
+            &self.sign,
+            ServerKey::le_block_parallelized,
+            ServerKey::ge_block_parallelized,
+        )
+    }
+
+    fn gt(&self, rhs: &Self) -> Self::Output {
+        self.compare(
+            rhs,
+            &rhs.sign,
+            ServerKey::gt_block_parallelized,
+            ServerKey::lt_block_parallelized,
+        )
+    }
+
+    fn ge(&self, rhs: &Self) -> Self::Output {
+        self.compare(
+            rhs,
+            &rhs.sign,
+            ServerKey::ge_block_parallelized,
+            ServerKey::le_block_parallelized,
+        )
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheEq<f64> for FheFloat<EXP_BITS, MANT_BITS> {
+    type Output = FheIntegerBool;
+
+    fn eq(&self, rhs: f64) -> Self::Output {
+        if Self::try_encode(rhs).is_none() {
+            return FheIntegerBool::encrypt_trivial(false);
+        }
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let layout = Self::layout(server_key.key.message_modulus);
+            let packed = Self::clear_packed(layout, rhs);
+            server_key.scalar_eq_block_parallelized(&self.packed(), packed)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ne(&self, rhs: f64) -> Self::Output {
+        if Self::try_encode(rhs).is_none() {
+            return FheIntegerBool::encrypt_trivial(true);
+        }
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let layout = Self::layout(server_key.key.message_modulus);
+            let packed = Self::clear_packed(layout, rhs);
+            server_key.scalar_ne_block_parallelized(&self.packed(), packed)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> FheOrd<f64> for FheFloat<EXP_BITS, MANT_BITS> {
+    type Output = FheIntegerBool;
+
+    fn lt(&self, rhs: f64) -> Self::Output {
+        self.scalar_compare(
+            rhs,
+            ServerKey::scalar_lt_block_parallelized,
+            ServerKey::scalar_gt_block_parallelized,
+            true,
+        )
+    }
+
+    fn le(&self, rhs: f64) -> Self::Output {
+        self.scalar_compare(
+            rhs,
+            ServerKey::scalar_le_block_parallelized,
+            ServerKey::scalar_ge_block_parallelized,
+            true,
+        )
+    }
+
+    fn gt(&self, rhs: f64) -> Self::Output {
+        self.scalar_compare(
+            rhs,
+            ServerKey::scalar_gt_block_parallelized,
+            ServerKey::scalar_lt_block_parallelized,
+            false,
+        )
+    }
+
+    fn ge(&self, rhs: f64) -> Self::Output {
+        self.scalar_compare(
+            rhs,
+            ServerKey::scalar_ge_block_parallelized,
+            ServerKey::scalar_le_block_parallelized,
+            false,
+        )
+    }
+}
+
+macro_rules! float_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $method:expr) => {
+        impl<const EXP_BITS: usize, const MANT_BITS: usize, B> $rust_trait_name<B>
+            for FheFloat<EXP_BITS, MANT_BITS>
+        where
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<const EXP_BITS: usize, const MANT_BITS: usize, B> $rust_trait_name<B>
+            for &FheFloat<EXP_BITS, MANT_BITS>
+        where
+            B: Borrow<FheFloat<EXP_BITS, MANT_BITS>>,
+        {
+            type Output = FheFloat<EXP_BITS, MANT_BITS>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                self.id.with_unwrapped_global(|integer_key| {
+                    $method(integer_key.pbs_key(), self, rhs.borrow())
+                })
+            }
+        }
+    }
+);
+
+macro_rules! float_impl_operation_assign (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $rust_op_method:ident) => {
+        impl<const EXP_BITS: usize, const MANT_BITS: usize, I> $rust_trait_name<I>
+            for FheFloat<EXP_BITS, MANT_BITS>
+        where
+            I: Borrow<Self>,
+        {
+            fn $rust_trait_method(&mut self, rhs: I) {
+                *self = (&*self).$rust_op_method(rhs);
+            }
+        }
+    }
+);
+
+float_impl_operation!(Add(add) => FheFloat::<EXP_BITS, MANT_BITS>::add_impl);
+float_impl_operation!(Sub(sub) => FheFloat::<EXP_BITS, MANT_BITS>::sub_impl);
+float_impl_operation!(Mul(mul) => FheFloat::<EXP_BITS, MANT_BITS>::mul_impl);
+
+float_impl_operation_assign!(AddAssign(add_assign) => add);
+float_impl_operation_assign!(SubAssign(sub_assign) => sub);
+float_impl_operation_assign!(MulAssign(mul_assign) => mul);
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> Neg for FheFloat<EXP_BITS, MANT_BITS> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> Neg for &FheFloat<EXP_BITS, MANT_BITS> {
+    type Output = FheFloat<EXP_BITS, MANT_BITS>;
+
+    fn neg(self) -> Self::Output {
+        self.id
+            .with_unwrapped_global(|integer_key| FheFloat::neg_impl(integer_key.pbs_key(), self))
+    }
+}
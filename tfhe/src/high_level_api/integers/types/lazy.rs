@@ -0,0 +1,574 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::ops::{Add, BitAnd, BitOr, BitXor, Mul, Neg, Sub};
+use std::sync::Arc;
+
+use rayon::prelude::*;
+
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::IntegerServerKey;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+
+use super::base::GenericInteger;
+
+/// A lazily evaluated FHE unsigned integer
+///
+/// Contrary to [GenericInteger] (e.g. [FheUint8](crate::high_level_api::FheUint8)),
+/// operations on a `LazyInteger` do not compute anything, they only record the operation
+/// in an expression graph. The computation happens when calling [LazyInteger::eval]
+/// (or [LazyInteger::eval_all] to evaluate multiple outputs sharing sub-expressions).
+///
+/// When evaluating, the scheduler:
+///
+/// - fuses chains of additions, subtractions, negations and scalar additions into a single linear
+///   combination;
+/// - uses the `unchecked` variant of an operation when the degrees of the input blocks allow it,
+///   and the `smart` variant otherwise, so that carries are only propagated when needed;
+/// - evaluates each shared sub-expression only once;
+/// - evaluates nodes that do not depend on each other in parallel.
+///
+/// The carries of the results are propagated at the end of the evaluation.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint8::encrypt(27u8, &client_key);
+/// let b = FheUint8::encrypt(13u8, &client_key);
+/// let c = FheUint8::encrypt(5u8, &client_key);
+///
+/// let (a, b, c) = (a.lazy(), b.lazy(), c.lazy());
+/// // No computation is done here
+/// let expr = &a + &b - &c + (&a * &b) + 3u8;
+///
+/// let result: FheUint8 = expr.eval();
+///
+/// let decrypted: u8 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, 27u8 + 13 - 5 + 27u8.wrapping_mul(13) + 3);
+/// ```
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone)]
+pub struct LazyInteger<P: IntegerParameter> {
+    node: Arc<LazyNode>,
+    id: P::Id,
+}
+
+#[derive(Copy, Clone)]
+enum BinaryOp {
+    Mul,
+    BitAnd,
+    BitOr,
+    BitXor,
+}
+
+enum LazyNode {
+    Input(RadixCiphertext),
+    Add(Arc<LazyNode>, Arc<LazyNode>),
+    Sub(Arc<LazyNode>, Arc<LazyNode>),
+    Neg(Arc<LazyNode>),
+    ScalarAdd(Arc<LazyNode>, u64),
+    ScalarSub(Arc<LazyNode>, u64),
+    ScalarMul(Arc<LazyNode>, u64),
+    Binary(BinaryOp, Arc<LazyNode>, Arc<LazyNode>),
+}
+
+impl<P> GenericInteger<P>
+where
+    P: IntegerParameter,
+{
+    /// Returns a lazy version of this integer
+    ///
+    /// Operations on the returned value are recorded instead of being computed,
+    /// see [LazyInteger].
+    pub fn lazy(&self) -> LazyInteger<P> {
+        LazyInteger::from(self.clone())
+    }
+}
+
+impl<P> From<GenericInteger<P>> for LazyInteger<P>
+where
+    P: IntegerParameter,
+{
+    fn from(value: GenericInteger<P>) -> Self {
+        Self {
+            node: Arc::new(LazyNode::Input(value.ciphertext)),
+            id: value.id,
+        }
+    }
+}
+
+impl<P> LazyInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Evaluates the expression
+    pub fn eval(&self) -> GenericInteger<P> {
+        Self::eval_all(std::slice::from_ref(self))
+            .pop()
+            .expect("eval_all returns one integer per expression")
+    }
+
+    /// Evaluates all the expressions at once
+    ///
+    /// Sub-expressions shared between the expressions are only computed once,
+    /// and independent parts of the expressions are computed in parallel.
+    ///
+    /// The results are in the same order as the expressions.
+    pub fn eval_all(expressions: &[Self]) -> Vec<GenericInteger<P>> {
+        let Some(first) = expressions.first() else {
+            return vec![];
+        };
+        let id = first.id;
+
+        let mut planner = Planner::default();
+        for expression in expressions {
+            planner.count_uses(&expression.node);
+        }
+        let outputs = expressions
+            .iter()
+            .map(|expression| planner.plan(&expression.node))
+            .collect::<Vec<_>>();
+
+        id.with_unwrapped_global(|integer_key| {
+            let server_key = integer_key.pbs_key();
+            let results = planner.execute(server_key);
+
+            outputs
+                .into_par_iter()
+                .map(|index| {
+                    let mut result = results[index].clone();
+                    if !result.block_carries_are_empty() {
+                        server_key.full_propagate_parallelized(&mut result);
+                    }
+                    result
+                })
+                .collect::<Vec<_>>()
+        })
+        .into_iter()
+        .map(|result| GenericInteger::new(result, id))
+        .collect()
+    }
+}
+
+#[derive(Default)]
+struct LinearCombination {
+    /// Indices of the steps to add, with whether they are negated
+    terms: Vec<(usize, bool)>,
+    /// Clear values to add, with whether they are negated
+    scalars: Vec<(u64, bool)>,
+}
+
+enum Step<'a> {
+    Input(&'a RadixCiphertext),
+    Linear(LinearCombination),
+    ScalarMul(usize, u64),
+    Binary(BinaryOp, usize, usize),
+}
+
+impl Step<'_> {
+    fn dependencies(&self) -> Vec<usize> {
+        match self {
+            Step::Input(_) => vec![],
+            Step::Linear(combination) => combination.terms.iter().map(|(i, _)| *i).collect(),
+            Step::ScalarMul(i, _) => vec![*i],
+            Step::Binary(_, lhs, rhs) => vec![*lhs, *rhs],
+        }
+    }
+
+    /// Computes the step, all its dependencies must have been computed
+    ///
+    /// The returned ciphertext may have non empty carries, consumers of the result pick the
+    /// `unchecked` variant of their operation when the degrees allow it, and the `smart`
+    /// variant (which propagates carries first) otherwise.
+    fn evaluate(
+        &self,
+        server_key: &ServerKey,
+        results: &[Option<RadixCiphertext>],
+    ) -> RadixCiphertext {
+        let get = |index: usize| {
+            results[index]
+                .as_ref()
+                .expect("dependencies are evaluated first")
+        };
+
+        match self {
+            Step::Input(ciphertext) => (*ciphertext).clone(),
+            Step::Linear(combination) => {
+                let num_blocks = combination
+                    .terms
+                    .first()
+                    .map(|(index, _)| get(*index).blocks.len())
+                    .expect("a linear combination has at least one term");
+                let mut result = server_key.create_trivial_zero_radix(num_blocks);
+
+                for (index, negated) in combination.terms.iter().copied() {
+                    let term = get(index);
+                    if negated {
+                        if server_key.is_sub_possible(&result, term) {
+                            server_key.unchecked_sub_assign(&mut result, term);
+                        } else {
+                            server_key
+                                .smart_sub_assign_parallelized(&mut result, &mut term.clone());
+                        }
+                    } else if server_key.is_add_possible(&result, term) {
+                        server_key.unchecked_add_assign(&mut result, term);
+                    } else {
+                        server_key.smart_add_assign_parallelized(&mut result, &mut term.clone());
+                    }
+                }
+
+                for (scalar, negated) in combination.scalars.iter().copied() {
+                    if negated {
+                        server_key.smart_scalar_sub_assign_parallelized(&mut result, scalar);
+                    } else {
+                        server_key.smart_scalar_add_assign_parallelized(&mut result, scalar);
+                    }
+                }
+
+                result
+            }
+            Step::ScalarMul(index, scalar) => {
+                let ct = get(*index);
+                if ct.block_carries_are_empty() {
+                    server_key.unchecked_scalar_mul_parallelized(ct, *scalar)
+                } else {
+                    server_key.smart_scalar_mul_parallelized(&mut ct.clone(), *scalar)
+                }
+            }
+            Step::Binary(op, lhs, rhs) => {
+                let (lhs, rhs) = (get(*lhs), get(*rhs));
+                if lhs.block_carries_are_empty() && rhs.block_carries_are_empty() {
+                    match op {
+                        BinaryOp::Mul => server_key.unchecked_mul_parallelized(lhs, rhs),
+                        BinaryOp::BitAnd => server_key.unchecked_bitand_parallelized(lhs, rhs),
+                        BinaryOp::BitOr => server_key.unchecked_bitor_parallelized(lhs, rhs),
+                        BinaryOp::BitXor => server_key.unchecked_bitxor_parallelized(lhs, rhs),
+                    }
+                } else {
+                    let (lhs, rhs) = (&mut lhs.clone(), &mut rhs.clone());
+                    match op {
+                        BinaryOp::Mul => server_key.smart_mul_parallelized(lhs, rhs),
+                        BinaryOp::BitAnd => server_key.smart_bitand_parallelized(lhs, rhs),
+                        BinaryOp::BitOr => server_key.smart_bitor_parallelized(lhs, rhs),
+                        BinaryOp::BitXor => server_key.smart_bitxor_parallelized(lhs, rhs),
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Turns the expression graph into a list of steps in topological order
+///
+/// Each step is assigned a level, steps of the same level do not depend on each other.
+#[derive(Default)]
+struct Planner<'a> {
+    steps: Vec<Step<'a>>,
+    levels: Vec<usize>,
+    indices: HashMap<*const LazyNode, usize>,
+    /// Number of times each node is used, as an operand or as an output
+    num_uses: HashMap<*const LazyNode, usize>,
+}
+
+impl<'a> Planner<'a> {
+    /// Records a use of the node, and of its operands the first time the node is seen
+    ///
+    /// All the outputs must be counted before planning them.
+    fn count_uses(&mut self, node: &Arc<LazyNode>) {
+        let num_uses = self.num_uses.entry(Arc::as_ptr(node)).or_insert(0);
+        *num_uses += 1;
+        if *num_uses > 1 {
+            return;
+        }
+
+        match node.as_ref() {
+            LazyNode::Input(_) => {}
+            LazyNode::Add(lhs, rhs) | LazyNode::Sub(lhs, rhs) | LazyNode::Binary(_, lhs, rhs) => {
+                self.count_uses(lhs);
+                self.count_uses(rhs);
+            }
+            LazyNode::Neg(node)
+            | LazyNode::ScalarAdd(node, _)
+            | LazyNode::ScalarSub(node, _)
+            | LazyNode::ScalarMul(node, _) => self.count_uses(node),
+        }
+    }
+
+    /// Returns the index of the step computing the node
+    fn plan(&mut self, node: &'a Arc<LazyNode>) -> usize {
+        let key = Arc::as_ptr(node);
+        if let Some(&index) = self.indices.get(&key) {
+            return index;
+        }
+
+        let step = match node.as_ref() {
+            LazyNode::Input(ciphertext) => Step::Input(ciphertext),
+            LazyNode::Add(..)
+            | LazyNode::Sub(..)
+            | LazyNode::Neg(..)
+            | LazyNode::ScalarAdd(..)
+            | LazyNode::ScalarSub(..) => {
+                let mut combination = LinearCombination::default();
+                self.flatten(node, false, &mut combination);
+                if let ([(index, false)], []) =
+                    (combination.terms.as_slice(), combination.scalars.as_slice())
+                {
+                    // e.g. -(-a), there is nothing to compute
+                    let index = *index;
+                    self.indices.insert(key, index);
+                    return index;
+                }
+                Step::Linear(combination)
+            }
+            LazyNode::ScalarMul(node, scalar) => Step::ScalarMul(self.plan(node), *scalar),
+            LazyNode::Binary(op, lhs, rhs) => Step::Binary(*op, self.plan(lhs), self.plan(rhs)),
+        };
+
+        let level = step
+            .dependencies()
+            .into_iter()
+            .map(|index| self.levels[index] + 1)
+            .max()
+            .unwrap_or(0);
+
+        let index = self.steps.len();
+        self.steps.push(step);
+        self.levels.push(level);
+        self.indices.insert(key, index);
+        index
+    }
+
+    /// Adds the terms of the linear node to the combination
+    fn flatten(
+        &mut self,
+        node: &'a Arc<LazyNode>,
+        negated: bool,
+        combination: &mut LinearCombination,
+    ) {
+        match node.as_ref() {
+            LazyNode::Add(lhs, rhs) => {
+                self.flatten_operand(lhs, negated, combination);
+                self.flatten_operand(rhs, negated, combination);
+            }
+            LazyNode::Sub(lhs, rhs) => {
+                self.flatten_operand(lhs, negated, combination);
+                self.flatten_operand(rhs, !negated, combination);
+            }
+            LazyNode::Neg(node) => self.flatten_operand(node, !negated, combination),
+            LazyNode::ScalarAdd(node, scalar) => {
+                self.flatten_operand(node, negated, combination);
+                combination.scalars.push((*scalar, negated));
+            }
+            LazyNode::ScalarSub(node, scalar) => {
+                self.flatten_operand(node, negated, combination);
+                combination.scalars.push((*scalar, !negated));
+            }
+            LazyNode::Input(_) | LazyNode::ScalarMul(..) | LazyNode::Binary(..) => {
+                unreachable!("only linear nodes are flattened")
+            }
+        }
+    }
+
+    /// Adds an operand of a linear node to the combination
+    ///
+    /// Linear operands are inlined, unless they are shared with other nodes (or are outputs),
+    /// in which case they get their own step so that they are only computed once.
+    fn flatten_operand(
+        &mut self,
+        node: &'a Arc<LazyNode>,
+        negated: bool,
+        combination: &mut LinearCombination,
+    ) {
+        let is_linear = matches!(
+            node.as_ref(),
+            LazyNode::Add(..)
+                | LazyNode::Sub(..)
+                | LazyNode::Neg(..)
+                | LazyNode::ScalarAdd(..)
+                | LazyNode::ScalarSub(..)
+        );
+        let is_shared = self.num_uses.get(&Arc::as_ptr(node)).copied().unwrap_or(1) > 1;
+
+        if is_linear && !is_shared {
+            self.flatten(node, negated, combination);
+        } else {
+            let index = self.plan(node);
+            combination.terms.push((index, negated));
+        }
+    }
+
+    /// Evaluates all the steps, level by level
+    fn execute(&self, server_key: &ServerKey) -> Vec<RadixCiphertext> {
+        let steps = &self.steps;
+        let mut results: Vec<Option<RadixCiphertext>> = vec![None; steps.len()];
+        let num_levels = self.levels.iter().max().map_or(0, |max| max + 1);
+
+        for level in 0..num_levels {
+            let indices = (0..steps.len())
+                .filter(|&index| self.levels[index] == level)
+                .collect::<Vec<_>>();
+
+            let level_results = indices
+                .par_iter()
+                .map(|&index| steps[index].evaluate(server_key, &results))
+                .collect::<Vec<_>>();
+
+            for (index, result) in indices.into_iter().zip(level_results) {
+                results[index] = Some(result);
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| result.expect("all steps are evaluated"))
+            .collect()
+    }
+}
+
+macro_rules! lazy_integer_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident) => |$lhs:ident, $rhs:ident| $node:expr) => {
+        impl<P, B> $rust_trait_name<B> for LazyInteger<P>
+        where
+            P: IntegerParameter,
+            B: Borrow<Self>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+        }
+
+        impl<P, B> $rust_trait_name<B> for &LazyInteger<P>
+        where
+            P: IntegerParameter,
+            B: Borrow<LazyInteger<P>>,
+        {
+            type Output = LazyInteger<P>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let $lhs = Arc::clone(&self.node);
+                let $rhs = Arc::clone(&rhs.borrow().node);
+                LazyInteger {
+                    node: Arc::new($node),
+                    id: self.id,
+                }
+            }
+        }
+    }
+);
+
+macro_rules! lazy_integer_impl_scalar_operation {
+    ($rust_trait_name:ident($rust_trait_method:ident) => $node_variant:ident($($scalar_type:ty),*)) => {
+        $(
+            impl<P> $rust_trait_name<$scalar_type> for LazyInteger<P>
+            where
+                P: IntegerParameter,
+            {
+                type Output = LazyInteger<P>;
+
+                fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
+                    <&Self as $rust_trait_name<$scalar_type>>::$rust_trait_method(&self, rhs)
+                }
+            }
+
+            impl<P> $rust_trait_name<$scalar_type> for &LazyInteger<P>
+            where
+                P: IntegerParameter,
+            {
+                type Output = LazyInteger<P>;
+
+                fn $rust_trait_method(self, rhs: $scalar_type) -> Self::Output {
+                    LazyInteger {
+                        node: Arc::new(LazyNode::$node_variant(Arc::clone(&self.node), u64::from(rhs))),
+                        id: self.id,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+lazy_integer_impl_operation!(Add(add) => |lhs, rhs| LazyNode::Add(lhs, rhs));
+lazy_integer_impl_operation!(Sub(sub) => |lhs, rhs| LazyNode::Sub(lhs, rhs));
+lazy_integer_impl_operation!(Mul(mul) => |lhs, rhs| LazyNode::Binary(BinaryOp::Mul, lhs, rhs));
+lazy_integer_impl_operation!(BitAnd(bitand) => |lhs, rhs| LazyNode::Binary(BinaryOp::BitAnd, lhs, rhs));
+lazy_integer_impl_operation!(BitOr(bitor) => |lhs, rhs| LazyNode::Binary(BinaryOp::BitOr, lhs, rhs));
+lazy_integer_impl_operation!(BitXor(bitxor) => |lhs, rhs| LazyNode::Binary(BinaryOp::BitXor, lhs, rhs));
+
+lazy_integer_impl_scalar_operation!(Add(add) => ScalarAdd(u8, u16, u32, u64));
+lazy_integer_impl_scalar_operation!(Sub(sub) => ScalarSub(u8, u16, u32, u64));
+lazy_integer_impl_scalar_operation!(Mul(mul) => ScalarMul(u8, u16, u32, u64));
+
+impl<P> Neg for LazyInteger<P>
+where
+    P: IntegerParameter,
+{
+    type Output = LazyInteger<P>;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl<P> Neg for &LazyInteger<P>
+where
+    P: IntegerParameter,
+{
+    type Output = LazyInteger<P>;
+
+    fn neg(self) -> Self::Output {
+        LazyInteger {
+            node: Arc::new(LazyNode::Neg(Arc::clone(&self.node))),
+            id: self.id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    #[test]
+    fn shared_linear_sub_expressions_are_planned_once() {
+        let client_key = crate::integer::ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        let input = |value: u64| Arc::new(LazyNode::Input(client_key.encrypt_radix(value, 4)));
+        let (a, b, c) = (input(1), input(2), input(3));
+
+        // a + b is used by both outputs
+        let sum = Arc::new(LazyNode::Add(a.clone(), b.clone()));
+        let first = Arc::new(LazyNode::ScalarAdd(sum.clone(), 7));
+        let second = Arc::new(LazyNode::Sub(sum, c));
+        let outputs = [first, second];
+
+        let mut planner = Planner::default();
+        for output in &outputs {
+            planner.count_uses(output);
+        }
+        for output in &outputs {
+            planner.plan(output);
+        }
+
+        let b_index = planner.indices[&Arc::as_ptr(&b)];
+        let num_steps_using_b = planner
+            .steps
+            .iter()
+            .filter(|step| step.dependencies().contains(&b_index))
+            .count();
+        // The sum is computed once, and the two outputs are computed from it
+        assert_eq!(num_steps_using_b, 1);
+        assert_eq!(planner.steps.len(), 6);
+    }
+}
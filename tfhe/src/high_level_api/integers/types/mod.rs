@@ -1,13 +1,28 @@
+pub use ascii_string::{AsciiPattern, FheAsciiString};
 pub use base::GenericInteger;
+pub use boolean::FheIntegerBool;
+pub use fixed::{FheIFixed, FheUFixed};
+pub use float::{FheFloat, FheFloat16, FheFloat32};
+pub use lazy::LazyInteger;
+pub use static_::{
+    CompactFheUint, CompactFheUintList, CompressedFheUint, FheInt128, FheInt16, FheInt256,
+    FheInt32, FheInt64, FheInt8, FheUint,
+};
 
 expand_pub_use_fhe_type!(
     pub use static_{
         FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
-        FheUint256
+        FheUint256, FheUint512, FheUint1024, FheUint2048
     };
 );
 
+pub(super) mod ascii_string;
 pub(super) mod base;
+pub(super) mod boolean;
 pub(super) mod compact;
 pub(super) mod compressed;
+pub(super) mod fixed;
+pub(super) mod float;
+pub(super) mod lazy;
+pub(super) mod signed;
 pub(super) mod static_;
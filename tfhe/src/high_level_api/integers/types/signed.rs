@@ -0,0 +1,437 @@
+use std::borrow::Borrow;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Shr, ShrAssign, Sub,
+    SubAssign,
+};
+
+use crate::core_crypto::prelude::SignedInteger;
+use crate::errors::{UninitializedClientKey, UnwrapResultExt};
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerParameter;
+use crate::high_level_api::integers::IntegerServerKey;
+use crate::high_level_api::internal_traits::{DecryptionKey, TypeIdentifier};
+use crate::high_level_api::keys::RefKeyFromKeyChain;
+use crate::high_level_api::traits::{
+    FheDecrypt, FheEq, FheMax, FheMin, FheOrd, FheTrivialEncrypt, FheTryEncrypt,
+    FheTryTrivialEncrypt,
+};
+use crate::high_level_api::ClientKey;
+use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
+use crate::integer::ciphertext::SignedRadixCiphertext;
+
+use super::boolean::FheIntegerBool;
+
+/// A Generic FHE signed integer
+///
+/// Values are encoded in two's complement, using the same radix
+/// decomposition as the unsigned integer types (e.g. [FheUint8]).
+///
+/// You will need to use one of this type specialization (e.g., [FheInt8], [FheInt16],
+/// [FheInt32]).
+///
+/// To be able to use this type, the cargo feature `integers` must be enabled,
+/// and your config should also enable the type with either default parameters or custom ones.
+///
+/// [FheUint8]: crate::high_level_api::FheUint8
+/// [FheInt8]: crate::high_level_api::FheInt8
+/// [FheInt16]: crate::high_level_api::FheInt16
+/// [FheInt32]: crate::high_level_api::FheInt32
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct GenericSignedInteger<P: IntegerParameter> {
+    pub(in crate::high_level_api::integers) ciphertext: SignedRadixCiphertext,
+    pub(in crate::high_level_api::integers) id: P::Id,
+}
+
+impl<P> GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+{
+    pub(in crate::high_level_api::integers) fn new(
+        ciphertext: SignedRadixCiphertext,
+        id: P::Id,
+    ) -> Self {
+        Self { ciphertext, id }
+    }
+}
+
+impl<P> GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Returns the absolute value
+    ///
+    /// As for [i64::wrapping_abs], the minimum value is its own absolute value.
+    pub fn abs(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_abs_parallelized(&self.ciphertext)
+        });
+        Self::new(ciphertext, self.id)
+    }
+}
+
+impl<P, ClearType> FheDecrypt<ClearType> for GenericSignedInteger<P>
+where
+    ClearType: RecomposableFrom<u64> + SignedInteger,
+    P: IntegerParameter,
+    P::Id: RefKeyFromKeyChain<Key = crate::integer::ClientKey>,
+    crate::integer::ClientKey: DecryptionKey<SignedRadixCiphertext, ClearType>,
+{
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        let key = self.id.unwrapped_ref_key(key);
+        key.decrypt(&self.ciphertext)
+    }
+}
+
+impl<P, T> FheTryEncrypt<T, ClientKey> for GenericSignedInteger<P>
+where
+    T: DecomposableInto<u64> + SignedInteger,
+    P: IntegerParameter,
+    P::Id: Default + TypeIdentifier,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt(value: T, key: &ClientKey) -> Result<Self, Self::Error> {
+        let id = P::Id::default();
+
+        let integer_client_key = key
+            .integer_key
+            .key
+            .as_ref()
+            .ok_or(UninitializedClientKey(id.type_variant()))
+            .unwrap_display();
+        let num_blocks = P::num_blocks(integer_client_key.parameters().message_modulus());
+        let ciphertext = integer_client_key.encrypt_signed_radix(value, num_blocks);
+        Ok(Self::new(ciphertext, id))
+    }
+}
+
+impl<P, T> FheTryTrivialEncrypt<T> for GenericSignedInteger<P>
+where
+    T: DecomposableInto<u64> + SignedInteger,
+    P: IntegerParameter,
+    P::Id: Default + WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Error = crate::high_level_api::errors::Error;
+
+    fn try_encrypt_trivial(value: T) -> Result<Self, Self::Error> {
+        let id = P::Id::default();
+        let ciphertext = id.with_unwrapped_global(|integer_key| {
+            integer_key.pbs_key().create_trivial_signed_radix(
+                value,
+                P::num_blocks(integer_key.pbs_key().key.message_modulus),
+            )
+        });
+        Ok(Self::new(ciphertext, id))
+    }
+}
+
+impl<P, T> FheTrivialEncrypt<T> for GenericSignedInteger<P>
+where
+    T: DecomposableInto<u64> + SignedInteger,
+    P: IntegerParameter,
+    P::Id: Default + WithGlobalKey<Key = IntegerServerKey>,
+{
+    #[track_caller]
+    fn encrypt_trivial(value: T) -> Self {
+        Self::try_encrypt_trivial(value).unwrap()
+    }
+}
+
+impl<P> FheMax<&Self> for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = Self;
+
+    fn max(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_max_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result, self.id)
+    }
+}
+
+impl<P> FheMin<&Self> for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = Self;
+
+    fn min(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_min_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        Self::new(inner_result, self.id)
+    }
+}
+
+impl<P> FheEq<Self> for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = FheIntegerBool;
+
+    fn eq(&self, rhs: Self) -> Self::Output {
+        <Self as FheEq<&Self>>::eq(self, &rhs)
+    }
+
+    fn ne(&self, rhs: Self) -> Self::Output {
+        <Self as FheEq<&Self>>::ne(self, &rhs)
+    }
+}
+
+impl<P> FheEq<&Self> for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = FheIntegerBool;
+
+    fn eq(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_eq_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ne(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_ne_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+impl<P> FheOrd<Self> for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = FheIntegerBool;
+
+    fn lt(&self, rhs: Self) -> Self::Output {
+        <Self as FheOrd<&Self>>::lt(self, &rhs)
+    }
+
+    fn le(&self, rhs: Self) -> Self::Output {
+        <Self as FheOrd<&Self>>::le(self, &rhs)
+    }
+
+    fn gt(&self, rhs: Self) -> Self::Output {
+        <Self as FheOrd<&Self>>::gt(self, &rhs)
+    }
+
+    fn ge(&self, rhs: Self) -> Self::Output {
+        <Self as FheOrd<&Self>>::ge(self, &rhs)
+    }
+}
+
+impl<P> FheOrd<&Self> for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = FheIntegerBool;
+
+    fn lt(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_lt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn le(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_le_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn gt(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_gt_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+
+    fn ge(&self, rhs: &Self) -> Self::Output {
+        let inner_result = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_ge_block_parallelized(&self.ciphertext, &rhs.ciphertext)
+        });
+        FheIntegerBool::new(inner_result)
+    }
+}
+
+macro_rules! generic_signed_integer_impl_operation (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+
+        impl<P, B> $rust_trait_name<B> for GenericSignedInteger<P>
+        where
+            P: IntegerParameter,
+            B: Borrow<Self>,
+            P::Id: WithGlobalKey<Key = IntegerServerKey>,
+        {
+            type Output = Self;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                <&Self as $rust_trait_name<B>>::$rust_trait_method(&self, rhs)
+            }
+
+        }
+
+        impl<P, B> $rust_trait_name<B> for &GenericSignedInteger<P>
+        where
+            P: IntegerParameter,
+            P::Id: WithGlobalKey<Key = IntegerServerKey>,
+            B: Borrow<GenericSignedInteger<P>>,
+        {
+            type Output = GenericSignedInteger<P>;
+
+            fn $rust_trait_method(self, rhs: B) -> Self::Output {
+                let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+                    let borrowed = rhs.borrow();
+                    integer_key
+                        .pbs_key()
+                        .$key_method(&self.ciphertext, &borrowed.ciphertext)
+                });
+                GenericSignedInteger::<P>::new(ciphertext, self.id)
+            }
+        }
+    }
+);
+
+macro_rules! generic_signed_integer_impl_operation_assign (
+    ($rust_trait_name:ident($rust_trait_method:ident) => $key_method:ident) => {
+        impl<P, I> $rust_trait_name<I> for GenericSignedInteger<P>
+        where
+            P: IntegerParameter,
+            P::Id: WithGlobalKey<Key = IntegerServerKey>,
+            I: Borrow<Self>,
+        {
+            fn $rust_trait_method(&mut self, rhs: I) {
+                self.id.with_unwrapped_global(|integer_key| {
+                    self.ciphertext = integer_key
+                        .pbs_key()
+                        .$key_method(&self.ciphertext, &rhs.borrow().ciphertext)
+                })
+            }
+        }
+    }
+);
+
+macro_rules! generic_signed_integer_impl_scalar_shift {
+    ($($scalar_type:ty),*) => {
+        $(
+            impl<P> Shr<$scalar_type> for GenericSignedInteger<P>
+            where
+                P: IntegerParameter,
+                P::Id: WithGlobalKey<Key = IntegerServerKey>,
+            {
+                type Output = GenericSignedInteger<P>;
+
+                fn shr(self, rhs: $scalar_type) -> Self::Output {
+                    <&Self as Shr<$scalar_type>>::shr(&self, rhs)
+                }
+            }
+
+            impl<P> Shr<$scalar_type> for &GenericSignedInteger<P>
+            where
+                P: IntegerParameter,
+                P::Id: WithGlobalKey<Key = IntegerServerKey>,
+            {
+                type Output = GenericSignedInteger<P>;
+
+                fn shr(self, rhs: $scalar_type) -> Self::Output {
+                    let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+                        let value: u64 = rhs.try_into().unwrap();
+                        integer_key
+                            .pbs_key()
+                            .signed_scalar_right_shift_parallelized(&self.ciphertext, value)
+                    });
+                    GenericSignedInteger::<P>::new(ciphertext, self.id)
+                }
+            }
+
+            impl<P> ShrAssign<$scalar_type> for GenericSignedInteger<P>
+            where
+                P: IntegerParameter,
+                P::Id: WithGlobalKey<Key = IntegerServerKey>,
+            {
+                fn shr_assign(&mut self, rhs: $scalar_type) {
+                    self.id.with_unwrapped_global(|integer_key| {
+                        let value: u64 = rhs.try_into().unwrap();
+                        integer_key
+                            .pbs_key()
+                            .signed_scalar_right_shift_assign_parallelized(&mut self.ciphertext, value);
+                    })
+                }
+            }
+        )*
+    };
+}
+
+generic_signed_integer_impl_operation!(Add(add) => signed_add_parallelized);
+generic_signed_integer_impl_operation!(Sub(sub) => signed_sub_parallelized);
+generic_signed_integer_impl_operation!(Mul(mul) => signed_mul_parallelized);
+generic_signed_integer_impl_operation!(Div(div) => signed_div_parallelized);
+generic_signed_integer_impl_operation!(Rem(rem) => signed_rem_parallelized);
+
+generic_signed_integer_impl_operation_assign!(AddAssign(add_assign) => signed_add_parallelized);
+generic_signed_integer_impl_operation_assign!(SubAssign(sub_assign) => signed_sub_parallelized);
+generic_signed_integer_impl_operation_assign!(MulAssign(mul_assign) => signed_mul_parallelized);
+generic_signed_integer_impl_operation_assign!(DivAssign(div_assign) => signed_div_parallelized);
+generic_signed_integer_impl_operation_assign!(RemAssign(rem_assign) => signed_rem_parallelized);
+
+generic_signed_integer_impl_scalar_shift!(u8, u16, u32, u64);
+
+impl<P> Neg for GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = GenericSignedInteger<P>;
+
+    fn neg(self) -> Self::Output {
+        <&Self as Neg>::neg(&self)
+    }
+}
+
+impl<P> Neg for &GenericSignedInteger<P>
+where
+    P: IntegerParameter,
+    P::Id: WithGlobalKey<Key = IntegerServerKey>,
+{
+    type Output = GenericSignedInteger<P>;
+
+    fn neg(self) -> Self::Output {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            integer_key
+                .pbs_key()
+                .signed_neg_parallelized(&self.ciphertext)
+        });
+        GenericSignedInteger::<P>::new(ciphertext, self.id)
+    }
+}
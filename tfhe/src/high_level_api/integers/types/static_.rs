@@ -1,15 +1,14 @@
 use serde::{Deserialize, Serialize};
 
 use super::base::GenericInteger;
-use crate::high_level_api::integers::parameters::{EvaluationIntegerKey, IntegerParameter};
+use super::signed::GenericSignedInteger;
+use crate::high_level_api::integers::parameters::IntegerParameter;
 use crate::high_level_api::integers::types::compact::{
     GenericCompactInteger, GenericCompactIntegerList,
 };
 use crate::high_level_api::integers::types::compressed::CompressedGenericInteger;
 use crate::high_level_api::internal_traits::{ParameterType, TypeIdentifier};
-#[cfg(feature = "internal-keycache")]
-use crate::integer::keycache::{KEY_CACHE, KEY_CACHE_WOPBS};
-use crate::integer::wopbs::WopbsKey;
+use crate::shortint::MessageModulus;
 use paste::paste;
 
 macro_rules! define_static_integer_parameters {
@@ -33,7 +32,7 @@ macro_rules! define_static_integer_parameters {
             }
 
             impl IntegerParameter for [<FheUint $num_bits Parameters>] {
-                fn num_blocks() -> usize {
+                fn num_blocks(_message_modulus: MessageModulus) -> usize {
                     $num_block
                 }
             }
@@ -45,6 +44,38 @@ macro_rules! define_static_integer_parameters {
             }
         }
     };
+    (
+        SignedRadix {
+            num_bits: $num_bits:literal,
+            num_block: $num_block:literal,
+        }
+    ) => {
+        paste! {
+            #[doc = concat!("Id for the [FheInt", stringify!($num_bits), "] data type.")]
+            #[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+            pub struct [<FheInt $num_bits Id>];
+
+            #[doc = concat!("Parameters for the [FheInt", stringify!($num_bits), "] data type.")]
+            #[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+            pub struct [<FheInt $num_bits Parameters>];
+
+            impl ParameterType for [<FheInt $num_bits Parameters>] {
+                type Id = [<FheInt $num_bits Id>];
+            }
+
+            impl IntegerParameter for [<FheInt $num_bits Parameters>] {
+                fn num_blocks(_message_modulus: MessageModulus) -> usize {
+                    $num_block
+                }
+            }
+
+            impl TypeIdentifier for [<FheInt $num_bits Id>] {
+                fn type_variant(&self) -> $crate::high_level_api::errors::Type {
+                    $crate::high_level_api::errors::Type::[<FheInt $num_bits>]
+                }
+            }
+        }
+    };
 }
 
 macro_rules! static_int_type {
@@ -76,30 +107,65 @@ macro_rules! static_int_type {
             #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
             pub type [<Compact $name List>] = GenericCompactIntegerList<[<$name Parameters>]>;
 
-            impl $crate::high_level_api::keys::RefKeyFromKeyChain for [<FheUint $num_bits Id>] {
-                type Key = crate::integer::ClientKey;
+            static_int_type!(@impl_key_traits, [<FheUint $num_bits Id>]);
+        }
+    };
 
-                fn ref_key(self, keys: &crate::high_level_api::ClientKey)
-                    -> Result<&Self::Key, $crate::high_level_api::errors::UninitializedClientKey> {
-                    keys
-                        .integer_key
-                        .key
-                        .as_ref()
-                        .ok_or($crate::high_level_api::errors::UninitializedClientKey(self.type_variant()))
-                }
+    // This rule implements the traits that tell which keys of the keychain
+    // are used by the type with the given id
+    (
+        @impl_key_traits,
+        $id:ident
+    ) => {
+        static_int_type!(@impl_key_traits, [], $id);
+    };
+    (
+        @impl_key_traits,
+        [$($generics:tt)*],
+        $id:ty
+    ) => {
+        impl<$($generics)*> $crate::high_level_api::keys::RefKeyFromKeyChain for $id {
+            type Key = crate::integer::ClientKey;
+
+            fn ref_key(self, keys: &crate::high_level_api::ClientKey)
+                -> Result<&Self::Key, $crate::high_level_api::errors::UninitializedClientKey> {
+                keys
+                    .integer_key
+                    .key
+                    .as_ref()
+                    .ok_or($crate::high_level_api::errors::UninitializedClientKey(self.type_variant()))
             }
+        }
 
-            impl $crate::high_level_api::global_state::WithGlobalKey for [<FheUint $num_bits Id>] {
-                type Key = crate::high_level_api::integers::IntegerServerKey;
+        impl<$($generics)*> $crate::high_level_api::global_state::WithGlobalKey for $id {
+            type Key = crate::high_level_api::integers::IntegerServerKey;
 
-                fn with_global<R, F>(self, func: F) -> Result<R, $crate::high_level_api::errors::UninitializedServerKey>
-                where
-                    F: FnOnce(&Self::Key) -> R {
-                    $crate::high_level_api::global_state::with_internal_keys(|keys| {
-                            Ok(func(&keys.integer_key))
-                        })
-                    }
-            }
+            fn with_global<R, F>(self, func: F) -> Result<R, $crate::high_level_api::errors::UninitializedServerKey>
+            where
+                F: FnOnce(&Self::Key) -> R {
+                $crate::high_level_api::global_state::with_internal_keys(|keys| {
+                        Ok(func(&keys.integer_key))
+                    })
+                }
+        }
+    };
+
+    // Same as `@impl_types_and_key_traits` but for signed integer types,
+    // only the plain (non compressed, non compact) type is available
+    (
+        @impl_signed_types_and_key_traits,
+        $(#[$outer:meta])*
+        $name:ident {
+            num_bits: $num_bits:literal,
+        }
+    ) => {
+         paste! {
+            #[doc = concat!("A signed integer type with ", stringify!($num_bits), " bits")]
+            $(#[$outer])*
+            #[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+            pub type $name = GenericSignedInteger<[<$name Parameters>]>;
+
+            static_int_type!(@impl_key_traits, [<FheInt $num_bits Id>]);
         }
     };
 
@@ -133,43 +199,177 @@ macro_rules! static_int_type {
             );
         }
     };
-}
 
-impl<C> EvaluationIntegerKey<C> for crate::integer::ServerKey
-where
-    C: AsRef<crate::integer::ClientKey>,
-{
-    fn new(client_key: &C) -> Self {
-        #[cfg(feature = "internal-keycache")]
+    // Defines a static signed integer type that uses
+    // the `Radix` representation
+    (
+        $(#[$outer:meta])*
         {
-            KEY_CACHE
-                .get_from_params(client_key.as_ref().parameters())
-                .1
+            num_bits: $num_bits:literal,
+            parameters: SignedRadix {
+                num_block: $num_block:literal,
+            },
         }
-        #[cfg(not(feature = "internal-keycache"))]
-        {
-            crate::integer::ServerKey::new(client_key)
+    ) => {
+        define_static_integer_parameters!(
+            SignedRadix {
+                num_bits: $num_bits,
+                num_block: $num_block,
+            }
+        );
+
+        ::paste::paste!{
+            static_int_type!(
+                @impl_signed_types_and_key_traits,
+                $(#[$outer])*
+                [<FheInt $num_bits>] {
+                    num_bits: $num_bits,
+                }
+            );
         }
+    };
+}
+
+/// Id for the [FheUint] data type.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FheUintId<const BITS: usize>;
+
+/// Parameters for the [FheUint] data type.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct FheUintParameters<const BITS: usize>;
+
+impl<const BITS: usize> ParameterType for FheUintParameters<BITS> {
+    type Id = FheUintId<BITS>;
+}
+
+impl<const BITS: usize> IntegerParameter for FheUintParameters<BITS> {
+    fn num_blocks(message_modulus: MessageModulus) -> usize {
+        assert!(
+            message_modulus.0 > 1,
+            "Blocks must have a message modulus of at least 2 to hold any bit"
+        );
+        let bits_per_block = message_modulus.0.ilog2() as usize;
+        (BITS + bits_per_block - 1) / bits_per_block
     }
+}
 
-    fn new_wopbs_key(
-        client_key: &C,
-        server_key: &Self,
-        wopbs_block_parameters: crate::shortint::WopbsParameters,
-    ) -> WopbsKey {
-        #[cfg(not(feature = "internal-keycache"))]
-        {
-            WopbsKey::new_wopbs_key(client_key.as_ref(), server_key, &wopbs_block_parameters)
-        }
-        #[cfg(feature = "internal-keycache")]
-        {
-            let _ = &server_key; // silence warning
-            KEY_CACHE_WOPBS
-                .get_from_params((client_key.as_ref().parameters(), wopbs_block_parameters))
-        }
+impl<const BITS: usize> TypeIdentifier for FheUintId<BITS> {
+    fn type_variant(&self) -> crate::high_level_api::errors::Type {
+        crate::high_level_api::errors::Type::FheUint(BITS)
+    }
+}
+
+static_int_type!(@impl_key_traits, [const BITS: usize], FheUintId<BITS>);
+
+/// An unsigned integer type with `BITS` bits
+///
+/// Contrary to the fixed size types (e.g. [FheUint8], [FheUint16]),
+/// the number of blocks is chosen from the parameters of the keys:
+/// it is the smallest number of blocks that holds `BITS` bits.
+///
+/// If `BITS` is not a multiple of the number of bits in a block,
+/// the type has slightly more bits than requested (e.g. a `FheUint<25>`
+/// with blocks of 2 bits has 26 bits), so operations wrap around
+/// at the next multiple.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::prelude::*;
+/// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint};
+///
+/// let config = ConfigBuilder::all_disabled()
+///     .enable_default_integers()
+///     .build();
+/// let (client_key, server_key) = generate_keys(config);
+/// set_server_key(server_key);
+///
+/// let a = FheUint::<24>::encrypt(10_000_000u32, &client_key);
+/// let b = FheUint::<24>::encrypt(6_000_000u32, &client_key);
+///
+/// let result = a + b;
+///
+/// let decrypted: u32 = result.decrypt(&client_key);
+/// assert_eq!(decrypted, (10_000_000 + 6_000_000) % (1 << 24));
+/// ```
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+pub type FheUint<const BITS: usize> = GenericInteger<FheUintParameters<BITS>>;
+
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+pub type CompressedFheUint<const BITS: usize> = CompressedGenericInteger<FheUintParameters<BITS>>;
+
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+pub type CompactFheUint<const BITS: usize> = GenericCompactInteger<FheUintParameters<BITS>>;
+
+#[cfg_attr(all(doc, not(doctest)), cfg(feature = "integer"))]
+pub type CompactFheUintList<const BITS: usize> = GenericCompactIntegerList<FheUintParameters<BITS>>;
+
+/// Id for the [FheUFixed](super::fixed::FheUFixed) data type.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FheUFixedId<const INT_BITS: usize, const FRAC_BITS: usize>;
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> TypeIdentifier
+    for FheUFixedId<INT_BITS, FRAC_BITS>
+{
+    fn type_variant(&self) -> crate::high_level_api::errors::Type {
+        crate::high_level_api::errors::Type::FheUFixed(INT_BITS, FRAC_BITS)
+    }
+}
+
+static_int_type!(
+    @impl_key_traits,
+    [const INT_BITS: usize, const FRAC_BITS: usize],
+    FheUFixedId<INT_BITS, FRAC_BITS>
+);
+
+/// Id for the [FheIFixed](super::fixed::FheIFixed) data type.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FheIFixedId<const INT_BITS: usize, const FRAC_BITS: usize>;
+
+impl<const INT_BITS: usize, const FRAC_BITS: usize> TypeIdentifier
+    for FheIFixedId<INT_BITS, FRAC_BITS>
+{
+    fn type_variant(&self) -> crate::high_level_api::errors::Type {
+        crate::high_level_api::errors::Type::FheIFixed(INT_BITS, FRAC_BITS)
     }
 }
 
+static_int_type!(
+    @impl_key_traits,
+    [const INT_BITS: usize, const FRAC_BITS: usize],
+    FheIFixedId<INT_BITS, FRAC_BITS>
+);
+
+/// Id for the [FheFloat](super::float::FheFloat) data type.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FheFloatId<const EXP_BITS: usize, const MANT_BITS: usize>;
+
+impl<const EXP_BITS: usize, const MANT_BITS: usize> TypeIdentifier
+    for FheFloatId<EXP_BITS, MANT_BITS>
+{
+    fn type_variant(&self) -> crate::high_level_api::errors::Type {
+        crate::high_level_api::errors::Type::FheFloat(EXP_BITS, MANT_BITS)
+    }
+}
+
+static_int_type!(
+    @impl_key_traits,
+    [const EXP_BITS: usize, const MANT_BITS: usize],
+    FheFloatId<EXP_BITS, MANT_BITS>
+);
+
+/// Id for the [FheIntegerBool](super::boolean::FheIntegerBool) data type.
+#[derive(Copy, Clone, Debug, Default, Serialize, Deserialize)]
+pub struct FheIntegerBoolId;
+
+impl TypeIdentifier for FheIntegerBoolId {
+    fn type_variant(&self) -> crate::high_level_api::errors::Type {
+        crate::high_level_api::errors::Type::FheIntegerBool
+    }
+}
+
+static_int_type!(@impl_key_traits, FheIntegerBoolId);
+
 static_int_type! {
     {
         num_bits: 8,
@@ -259,3 +459,87 @@ static_int_type! {
         },
     }
 }
+
+static_int_type! {
+    {
+        num_bits: 512,
+        keychain_member: integer_key.uint512_key,
+        parameters: Radix {
+            num_block: 256,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 1024,
+        keychain_member: integer_key.uint1024_key,
+        parameters: Radix {
+            num_block: 512,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 2048,
+        keychain_member: integer_key.uint2048_key,
+        parameters: Radix {
+            num_block: 1024,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 8,
+        parameters: SignedRadix {
+            num_block: 4,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 16,
+        parameters: SignedRadix {
+            num_block: 8,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 32,
+        parameters: SignedRadix {
+            num_block: 16,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 64,
+        parameters: SignedRadix {
+            num_block: 32,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 128,
+        parameters: SignedRadix {
+            num_block: 64,
+        },
+    }
+}
+
+static_int_type! {
+    {
+        num_bits: 256,
+        parameters: SignedRadix {
+            num_block: 128,
+        },
+    }
+}
@@ -22,7 +22,7 @@ macro_rules! expand_pub_use_fhe_type(
 
 pub use crate::core_crypto::commons::math::random::Seed;
 pub use config::{Config, ConfigBuilder};
-pub use errors::{Error, OutOfRangeError};
+pub use errors::{Error, FunctionEvaluationError, OutOfRangeError};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 pub use keys::{
     generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
@@ -38,9 +38,15 @@ pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool, FheBoolPar
 expand_pub_use_fhe_type!(
     pub use crate::high_level_api::integers{
         FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
-        FheUint256
+        FheUint256, FheUint512, FheUint1024, FheUint2048
     };
 );
+#[cfg(feature = "integer")]
+pub use crate::high_level_api::integers::{
+    AsciiPattern, CompactFheUint, CompactFheUintList, CompressedFheUint, FheAsciiString, FheFloat,
+    FheFloat16, FheFloat32, FheIFixed, FheInt128, FheInt16, FheInt256, FheInt32, FheInt64, FheInt8,
+    FheIntegerBool, FheUFixed, FheUint, LazyInteger,
+};
 #[cfg(feature = "shortint")]
 pub use crate::high_level_api::shortints::{
     CompressedFheUint2, CompressedFheUint3, CompressedFheUint4, FheUint2, FheUint2Parameters,
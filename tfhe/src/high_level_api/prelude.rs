@@ -8,6 +8,6 @@
 pub use crate::high_level_api::traits::{
     DynamicFheEncryptor, DynamicFheTrivialEncryptor, DynamicFheTryEncryptor, FheBootstrap,
     FheDecrypt, FheEncrypt, FheEq, FheMax, FheMin, FheNumberConstant, FheOrd, FheTrivialEncrypt,
-    FheTryEncrypt, FheTryTrivialEncrypt, RotateLeft, RotateLeftAssign, RotateRight,
+    FheTryEncrypt, FheTryTrivialEncrypt, IfThenElse, RotateLeft, RotateLeftAssign, RotateRight,
     RotateRightAssign,
 };
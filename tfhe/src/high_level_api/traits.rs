@@ -111,6 +111,15 @@ pub trait FheOrd<Rhs = Self> {
     fn ge(&self, other: Rhs) -> Self::Output;
 }
 
+/// Trait for fully homomorphic selection between two values.
+///
+/// The standard `if` expression can not be used as the condition is encrypted,
+/// so both values are always computed and the one to keep is selected homomorphically.
+pub trait IfThenElse<Ciphertext> {
+    /// Returns `ct_then` if `self` encrypts `true`, `ct_else` otherwise
+    fn if_then_else(&self, ct_then: &Ciphertext, ct_else: &Ciphertext) -> Ciphertext;
+}
+
 pub trait FheMin<Rhs = Self> {
     type Output;
 
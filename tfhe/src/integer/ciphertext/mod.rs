@@ -1,4 +1,6 @@
 //! This module implements the ciphertext structures.
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
 use crate::shortint::{Ciphertext, CompressedCiphertext};
 use serde::{Deserialize, Serialize};
 
@@ -70,6 +72,75 @@ impl CompactCiphertextList {
     }
 }
 
+/// Structure containing a list of ciphertexts in CRT decomposition,
+/// encrypted with a compact public key.
+///
+/// All the ciphertexts of the list use the same basis.
+#[derive(Clone, Serialize, Deserialize)]
+pub struct CompactCrtCiphertextList {
+    pub(crate) ct_list: crate::shortint::ciphertext::CompactCiphertextList,
+    pub(crate) moduli: Vec<u64>,
+    // Whether the blocks use the native CRT encoding,
+    // i.e. without padding bit nor carry space
+    pub(crate) is_native: bool,
+}
+
+impl CompactCrtCiphertextList {
+    pub fn expand_one(&self) -> CrtCiphertext {
+        let mut blocks = self.ct_list.expand();
+        blocks.truncate(self.moduli.len());
+        self.crt_ciphertext_from_blocks(blocks)
+    }
+
+    pub fn ciphertext_count(&self) -> usize {
+        self.ct_list.ct_list.lwe_ciphertext_count().0 / self.moduli.len()
+    }
+
+    pub fn expand(&self) -> Vec<CrtCiphertext> {
+        let mut all_block_iter = self.ct_list.expand().into_iter();
+        let num_ct = self.ciphertext_count();
+        let mut ciphertexts = Vec::with_capacity(num_ct);
+
+        for _ in 0..num_ct {
+            let ct_blocks = all_block_iter
+                .by_ref()
+                .take(self.moduli.len())
+                .collect::<Vec<_>>();
+            if ct_blocks.len() < self.moduli.len() {
+                break;
+            }
+            ciphertexts.push(self.crt_ciphertext_from_blocks(ct_blocks));
+        }
+
+        ciphertexts
+    }
+
+    pub fn size_elements(&self) -> usize {
+        self.ct_list.size_elements()
+    }
+
+    pub fn size_bytes(&self) -> usize {
+        self.ct_list.size_bytes()
+    }
+
+    /// The expanded blocks have the moduli of the parameters,
+    /// this sets the ones of the CRT basis
+    fn crt_ciphertext_from_blocks(&self, mut blocks: Vec<Ciphertext>) -> CrtCiphertext {
+        let total_modulus = self.ct_list.message_modulus.0 * self.ct_list.carry_modulus.0;
+        for (block, &modulus) in blocks.iter_mut().zip(self.moduli.iter()) {
+            let modulus = modulus as usize;
+            block.message_modulus = MessageModulus(modulus);
+            block.carry_modulus = if self.is_native {
+                CarryModulus(1)
+            } else {
+                CarryModulus(total_modulus / modulus)
+            };
+            block.degree = Degree(modulus - 1);
+        }
+        CrtCiphertext::from((blocks, self.moduli.clone()))
+    }
+}
+
 impl RadixCiphertext {
     pub fn block_carries_are_empty(&self) -> bool {
         self.blocks.iter().all(|block| block.carry_is_empty())
@@ -88,6 +159,57 @@ impl From<CompressedRadixCiphertext> for RadixCiphertext {
     }
 }
 
+/// Structure containing a signed ciphertext in radix decomposition.
+///
+/// The value is stored in two's complement, so the blocks themselves
+/// are laid out the same way as for a [RadixCiphertext] with the same
+/// number of blocks, the most significant bit of the last block being the sign bit.
+#[derive(Serialize, Clone, Deserialize, PartialEq, Eq, Debug)]
+pub struct BaseSignedRadixCiphertext<Block> {
+    /// The blocks are stored from LSB to MSB
+    pub(crate) blocks: Vec<Block>,
+}
+
+impl<Block> From<Vec<Block>> for BaseSignedRadixCiphertext<Block> {
+    fn from(blocks: Vec<Block>) -> Self {
+        Self { blocks }
+    }
+}
+
+// Type alias to save some typing in implementation parts
+pub type SignedRadixCiphertext = BaseSignedRadixCiphertext<Ciphertext>;
+
+/// Structure containing a **compressed** signed ciphertext in radix decomposition.
+pub type CompressedSignedRadixCiphertext = BaseSignedRadixCiphertext<CompressedCiphertext>;
+
+impl SignedRadixCiphertext {
+    pub fn block_carries_are_empty(&self) -> bool {
+        self.blocks.iter().all(|block| block.carry_is_empty())
+    }
+
+    /// Returns a copy of the blocks, reinterpreted as an unsigned radix ciphertext
+    pub(crate) fn to_unsigned(&self) -> RadixCiphertext {
+        RadixCiphertext::from(self.blocks.clone())
+    }
+
+    /// Reinterprets the blocks of an unsigned radix ciphertext as a signed one
+    pub(crate) fn from_unsigned(ct: RadixCiphertext) -> Self {
+        Self::from(ct.blocks)
+    }
+}
+
+impl From<CompressedSignedRadixCiphertext> for SignedRadixCiphertext {
+    fn from(compressed: CompressedSignedRadixCiphertext) -> Self {
+        Self::from(
+            compressed
+                .blocks
+                .into_iter()
+                .map(From::from)
+                .collect::<Vec<_>>(),
+        )
+    }
+}
+
 pub trait IntegerCiphertext: Clone {
     fn from_blocks(blocks: Vec<Ciphertext>) -> Self;
     fn blocks(&self) -> &[Ciphertext];
@@ -112,6 +234,18 @@ impl IntegerCiphertext for RadixCiphertext {
     }
 }
 
+impl IntegerCiphertext for SignedRadixCiphertext {
+    fn from_blocks(blocks: Vec<Ciphertext>) -> Self {
+        Self::from(blocks)
+    }
+    fn blocks(&self) -> &[Ciphertext] {
+        &self.blocks
+    }
+    fn blocks_mut(&mut self) -> &mut [Ciphertext] {
+        &mut self.blocks
+    }
+}
+
 impl IntegerCiphertext for CrtCiphertext {
     fn from_blocks(blocks: Vec<Ciphertext>) -> Self {
         let moduli = blocks.iter().map(|x| x.message_modulus.0 as u64).collect();
@@ -162,3 +296,9 @@ impl From<CompressedCrtCiphertext> for CrtCiphertext {
         Self::from((blocks, moduli))
     }
 }
+
+impl CrtCiphertext {
+    pub fn block_carries_are_empty(&self) -> bool {
+        self.blocks.iter().all(|block| block.carry_is_empty())
+    }
+}
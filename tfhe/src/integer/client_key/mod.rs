@@ -7,10 +7,13 @@ mod crt;
 mod radix;
 pub(crate) mod utils;
 
+use crate::core_crypto::prelude::SignedInteger;
 use crate::integer::block_decomposition::BlockRecomposer;
 use crate::integer::ciphertext::{CompressedCrtCiphertext, CrtCiphertext};
 use crate::integer::client_key::utils::i_crt;
-use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl};
+use crate::integer::encryption::{
+    encrypt_crt, encrypt_signed_radix_impl, encrypt_words_radix_impl,
+};
 use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{
     Ciphertext, ClientKey as ShortintClientKey, ShortintParameterSet as ShortintParameters,
@@ -22,7 +25,10 @@ pub use crt::CrtClientKey;
 pub use radix::RadixClientKey;
 
 use super::block_decomposition::{DecomposableInto, RecomposableFrom};
-use super::ciphertext::{CompressedRadixCiphertext, RadixCiphertext};
+use super::ciphertext::{
+    CompressedRadixCiphertext, CompressedSignedRadixCiphertext, RadixCiphertext,
+    SignedRadixCiphertext,
+};
 
 /// A structure containing the client key, which must be kept secret.
 ///
@@ -300,6 +306,112 @@ impl ClientKey {
         recomposer.value()
     }
 
+    /// Encrypts a signed integer in radix decomposition
+    ///
+    /// The value is encrypted in two's complement over `num_blocks` blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let num_block = 4;
+    ///
+    /// let msg = -67_i8;
+    ///
+    /// // 2 * 4 = 8 bits of message
+    /// let ct = cks.encrypt_signed_radix(msg, num_block);
+    ///
+    /// // Decryption
+    /// let dec: i8 = cks.decrypt_signed_radix(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn encrypt_signed_radix<T>(&self, message: T, num_blocks: usize) -> SignedRadixCiphertext
+    where
+        T: DecomposableInto<u64> + SignedInteger,
+    {
+        encrypt_signed_radix_impl(
+            &self.key,
+            message,
+            num_blocks,
+            crate::shortint::ClientKey::encrypt,
+        )
+    }
+
+    pub fn encrypt_signed_radix_compressed<T>(
+        &self,
+        message: T,
+        num_blocks: usize,
+    ) -> CompressedSignedRadixCiphertext
+    where
+        T: DecomposableInto<u64> + SignedInteger,
+    {
+        encrypt_signed_radix_impl(
+            &self.key,
+            message,
+            num_blocks,
+            crate::shortint::ClientKey::encrypt_compressed,
+        )
+    }
+
+    /// Decrypts a ciphertext encrypting a signed radix integer
+    ///
+    /// If the clear type has more bits than the ciphertext,
+    /// the decrypted value is sign extended.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::ClientKey;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let num_block = 4;
+    ///
+    /// let msg = -91_i64;
+    ///
+    /// // Encryption
+    /// let ct = cks.encrypt_signed_radix(msg, num_block);
+    ///
+    /// // Decryption, 8 bits are sign extended to 64
+    /// let dec: i64 = cks.decrypt_signed_radix(&ct);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn decrypt_signed_radix<T>(&self, ctxt: &SignedRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64> + SignedInteger,
+    {
+        if ctxt.blocks.is_empty() {
+            return T::ZERO;
+        }
+
+        let bits_in_block = self.key.parameters.message_modulus().0.ilog2();
+        let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+        for encrypted_block in &ctxt.blocks {
+            let decrypted_block = self.key.decrypt_message_and_carry(encrypted_block);
+            if !recomposer.add_unmasked(decrypted_block) {
+                // End of T::BITS reached no need to try more
+                // recomposition
+                break;
+            };
+        }
+
+        let num_bits_in_ctxt = bits_in_block as usize * ctxt.blocks.len();
+        let value = recomposer.unmasked_value();
+        if num_bits_in_ctxt >= T::BITS {
+            value
+        } else {
+            // Moving the sign bit of the ciphertext to the sign bit of T,
+            // the arithmetic shift right then sign extends
+            // and discards anything above the sign bit
+            let shift = T::BITS - num_bits_in_ctxt;
+            (value << shift) >> shift
+        }
+    }
+
     /// Encrypts an integer using crt representation
     ///
     /// # Example
@@ -1,8 +1,9 @@
 //! Definition of the client key for radix decomposition
 
 use super::ClientKey;
+use crate::core_crypto::prelude::SignedInteger;
 use crate::integer::block_decomposition::{DecomposableInto, RecomposableFrom};
-use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
 use crate::shortint::{Ciphertext as ShortintCiphertext, PBSParameters as ShortintParameters};
 
 use serde::{Deserialize, Serialize};
@@ -64,6 +65,20 @@ impl RadixClientKey {
         self.key.decrypt_radix(ciphertext)
     }
 
+    pub fn encrypt_signed<T>(&self, message: T) -> SignedRadixCiphertext
+    where
+        T: DecomposableInto<u64> + SignedInteger,
+    {
+        self.key.encrypt_signed_radix(message, self.num_blocks)
+    }
+
+    pub fn decrypt_signed<T>(&self, ciphertext: &SignedRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64> + SignedInteger,
+    {
+        self.key.decrypt_signed_radix(ciphertext)
+    }
+
     /// Returns the parameters used by the client key.
     pub fn parameters(&self) -> ShortintParameters {
         self.key.parameters()
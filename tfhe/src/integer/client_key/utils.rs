@@ -68,6 +68,18 @@ pub(crate) fn extended_euclid(f: i64, g: i64) -> (usize, Vec<i64>, Vec<i64>, Vec
     (l, r, s, t, q)
 }
 
+/// Returns the value which is 1 modulo `block_modulus` and 0 modulo the other moduli of the
+/// basis, `modulus` being the product of the basis.
+///
+/// The value encrypted by a CRT ciphertext is the sum of the value of each block multiplied
+/// by this coefficient, modulo the product of the basis.
+pub(crate) fn crt_coefficient(block_modulus: u64, modulus: u64) -> u64 {
+    let other_moduli = modulus / block_modulus;
+    let (l, _, s, _, _) = extended_euclid(other_moduli as i64, block_modulus as i64);
+    let inverse = s[l].rem_euclid(block_modulus as i64) as u64;
+    other_moduli * inverse
+}
+
 pub(crate) fn i_crt(modulus: &[u64], val: &[u64]) -> u64 {
     let big_mod = modulus.iter().product::<u64>();
     let mut c: Vec<u64> = vec![0; val.len()];
@@ -1,4 +1,5 @@
 use super::block_decomposition::{BlockDecomposer, DecomposableInto};
+use crate::core_crypto::prelude::SignedInteger;
 use crate::shortint::parameters::MessageModulus;
 
 pub(crate) trait KnowsMessageModulus {
@@ -83,6 +84,58 @@ where
         .take(num_blocks)
 }
 
+/// Encrypts a signed number under radix decomposition
+///
+/// The number is encrypted in two's complement, blocks that are above
+/// the bits of the `message` type are filled with copies of the sign bit.
+pub(crate) fn encrypt_signed_radix_impl<BlockKey, Block, RadixCiphertextType, T, F>(
+    encrypting_key: &BlockKey,
+    message: T,
+    num_blocks: usize,
+    encrypt_block: F,
+) -> RadixCiphertextType
+where
+    T: DecomposableInto<u64> + SignedInteger,
+    BlockKey: KnowsMessageModulus,
+    F: Fn(&BlockKey, u64) -> Block,
+    RadixCiphertextType: From<Vec<Block>>,
+{
+    let message_modulus = encrypting_key.message_modulus();
+    let clear_block_iterator =
+        create_clear_signed_radix_block_iterator(message, message_modulus, num_blocks);
+
+    let blocks = clear_block_iterator
+        .map(|clear_block| encrypt_block(encrypting_key, clear_block))
+        .collect::<Vec<_>>();
+
+    RadixCiphertextType::from(blocks)
+}
+
+pub(crate) fn create_clear_signed_radix_block_iterator<T>(
+    message: T,
+    message_modulus: MessageModulus,
+    num_blocks: usize,
+) -> impl Iterator<Item = u64>
+where
+    T: DecomposableInto<u64> + SignedInteger,
+{
+    let bits_in_block = message_modulus.0.ilog2();
+    let is_negative = message < T::ZERO;
+    // The padding bit is used when bits_in_block does not divide T::BITS,
+    // and the padding block is used to sign extend up to num_blocks
+    let (padding_bit, padding_block) = if is_negative {
+        (T::ONE, message_modulus.0 as u64 - 1)
+    } else {
+        (T::ZERO, 0u64)
+    };
+    let decomposer = BlockDecomposer::with_padding_bit(message, bits_in_block, padding_bit);
+
+    decomposer
+        .iter_as::<u64>()
+        .chain(std::iter::repeat(padding_block))
+        .take(num_blocks)
+}
+
 pub(crate) fn encrypt_crt<BlockKey, Block, CrtCiphertextType, F>(
     encrypting_key: &BlockKey,
     message: u64,
@@ -64,11 +64,11 @@ pub mod server_key;
 pub mod u256;
 pub mod wopbs;
 
-pub use ciphertext::{CrtCiphertext, IntegerCiphertext, RadixCiphertext};
+pub use ciphertext::{CrtCiphertext, IntegerCiphertext, RadixCiphertext, SignedRadixCiphertext};
 pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
 pub use public_key::{CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
 pub use server_key::{CheckError, CompressedServerKey, ServerKey};
-pub use u256::U256;
+pub use u256::{StaticUnsignedBigInt, U1024, U2048, U256, U512};
 
 /// Generate a couple of client and server keys with given parameters
 ///
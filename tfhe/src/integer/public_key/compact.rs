@@ -1,9 +1,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::{CompactCiphertextList, RadixCiphertext};
+use crate::integer::ciphertext::{
+    CompactCiphertextList, CompactCrtCiphertextList, RadixCiphertext,
+};
 use crate::integer::encryption::{create_clear_radix_block_iterator, encrypt_words_radix_impl};
 use crate::integer::ClientKey;
+use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{
     CompactPublicKey as ShortintCompactPublicKey,
     CompressedCompactPublicKey as ShortintCompressedCompactPublicKey,
@@ -111,6 +114,117 @@ impl CompactPublicKey {
         }
     }
 
+    /// Encrypts a message in the CRT decomposition with the given basis,
+    /// into a compact list containing one ciphertext.
+    ///
+    /// The basis elements must not be greater than the message space
+    /// of the parameters (message modulus times carry modulus).
+    /// The `PARAM_CRT_MODULI_*_COMPACT_PK` parameter sets are made for this
+    /// (see [ALL_PARAMETER_VEC_COMPACT_PK_CRT]).
+    ///
+    /// [ALL_PARAMETER_VEC_COMPACT_PK_CRT]: crate::shortint::parameters::ALL_PARAMETER_VEC_COMPACT_PK_CRT
+    pub fn encrypt_crt_compact(
+        &self,
+        message: u64,
+        base_vec: Vec<u64>,
+    ) -> CompactCrtCiphertextList {
+        self.encrypt_iter_crt_compact(std::iter::once(message), base_vec)
+    }
+
+    pub fn encrypt_slice_crt_compact(
+        &self,
+        messages: &[u64],
+        base_vec: Vec<u64>,
+    ) -> CompactCrtCiphertextList {
+        self.encrypt_iter_crt_compact(messages.iter().copied(), base_vec)
+    }
+
+    pub fn encrypt_iter_crt_compact(
+        &self,
+        message_iter: impl Iterator<Item = u64>,
+        base_vec: Vec<u64>,
+    ) -> CompactCrtCiphertextList {
+        let total_modulus =
+            self.key.parameters.message_modulus().0 * self.key.parameters.carry_modulus().0;
+        assert!(
+            base_vec
+                .iter()
+                .all(|&modulus| modulus >= 2 && modulus as usize <= total_modulus),
+            "The moduli of the basis must be in [2, {total_modulus}]"
+        );
+
+        let clear_block_iter = message_iter.flat_map(|message| {
+            base_vec
+                .iter()
+                .map(move |&modulus| (message, MessageModulus(modulus as usize)))
+        });
+        let ct_list = self.key.encrypt_iter_with_message_modulus(clear_block_iter);
+        assert!(
+            ct_list.ct_list.lwe_ciphertext_count().0 > 0,
+            "At least one message is required"
+        );
+
+        CompactCrtCiphertextList {
+            ct_list,
+            moduli: base_vec,
+            is_native: false,
+        }
+    }
+
+    /// Encrypts a message in the native CRT decomposition with the given basis,
+    /// into a compact list containing one ciphertext.
+    ///
+    /// The native CRT encoding has no padding bit nor carry space, each block
+    /// uses the whole message space, see [ClientKey::encrypt_native_crt].
+    ///
+    /// As for the standard public key, the moduli of the basis must be in `[2, 255]`.
+    pub fn encrypt_native_crt_compact(
+        &self,
+        message: u64,
+        base_vec: Vec<u64>,
+    ) -> CompactCrtCiphertextList {
+        self.encrypt_iter_native_crt_compact(std::iter::once(message), base_vec)
+    }
+
+    pub fn encrypt_slice_native_crt_compact(
+        &self,
+        messages: &[u64],
+        base_vec: Vec<u64>,
+    ) -> CompactCrtCiphertextList {
+        self.encrypt_iter_native_crt_compact(messages.iter().copied(), base_vec)
+    }
+
+    pub fn encrypt_iter_native_crt_compact(
+        &self,
+        message_iter: impl Iterator<Item = u64>,
+        base_vec: Vec<u64>,
+    ) -> CompactCrtCiphertextList {
+        assert!(
+            base_vec
+                .iter()
+                .all(|&modulus| (2..=u64::from(u8::MAX)).contains(&modulus)),
+            "The moduli of the basis must be in [2, {}]",
+            u8::MAX
+        );
+
+        let clear_block_iter = message_iter.flat_map(|message| {
+            base_vec
+                .iter()
+                .map(move |&modulus| (message, modulus as u8))
+        });
+        let ct_list = self.key.encrypt_iter_native_crt(clear_block_iter);
+        assert!(
+            ct_list.ct_list.lwe_ciphertext_count().0 > 0,
+            "At least one message is required"
+        );
+
+        CompactCrtCiphertextList {
+            ct_list,
+            moduli: base_vec,
+            is_native: true,
+        }
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
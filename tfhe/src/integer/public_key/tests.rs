@@ -27,6 +27,21 @@ create_parametrized_test!(small_radix_encrypt_decrypt_compact_128_bits_list {
     PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
 });
 
+create_parametrized_test!(crt_encrypt_decrypt_compact_list {
+    PARAM_CRT_MODULI_8_COMPACT_PK_KS_PBS,
+    PARAM_CRT_MODULI_8_COMPACT_PK_PBS_KS
+});
+
+create_parametrized_test!(crt_compact_list_arithmetic {
+    PARAM_CRT_MODULI_8_COMPACT_PK_KS_PBS,
+    PARAM_CRT_MODULI_8_COMPACT_PK_PBS_KS
+});
+
+create_parametrized_test!(native_crt_encrypt_decrypt_compact_list {
+    PARAM_CRT_MODULI_8_COMPACT_PK_KS_PBS,
+    PARAM_CRT_MODULI_8_COMPACT_PK_PBS_KS
+});
+
 /// Test that the public key can encrypt a 128 bit number
 /// in radix decomposition, and that the client key can decrypt it
 fn big_radix_encrypt_decrypt_128_bits(param: ClassicPBSParameters) {
@@ -105,3 +120,100 @@ fn radix_encrypt_decrypt_compact_128_bits_list(params: ClassicPBSParameters) {
         }
     }
 }
+
+fn crt_encrypt_decrypt_compact_list(params: ClassicPBSParameters) {
+    let (cks, _) = gen_keys(params);
+    let pk = crate::integer::public_key::CompactPublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+    let basis = vec![2, 3, 5, 7];
+    let modulus = basis.iter().product::<u64>();
+
+    let clear = rng.gen::<u64>() % modulus;
+    let ct = pk.encrypt_crt_compact(clear, basis.clone()).expand_one();
+    assert_eq!(ct.moduli, basis);
+    assert_eq!(cks.decrypt_crt(&ct), clear);
+
+    const MAX_CT: usize = 20;
+
+    for _ in 0..10 {
+        let num_ct_for_this_iter = rng.gen_range(1..=MAX_CT);
+        let clear_vec = (0..num_ct_for_this_iter)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let compact_encrypted_list = pk.encrypt_slice_crt_compact(&clear_vec, basis.clone());
+        assert_eq!(compact_encrypted_list.ciphertext_count(), clear_vec.len());
+
+        let ciphertext_vec = compact_encrypted_list.expand();
+        assert_eq!(ciphertext_vec.len(), clear_vec.len());
+
+        for (ciphertext, clear) in ciphertext_vec.iter().zip(clear_vec.iter().copied()) {
+            let decrypted = cks.decrypt_crt(ciphertext);
+            assert_eq!(decrypted, clear);
+        }
+    }
+}
+
+/// Test that CRT ciphertexts encrypted with a compact public key can be used in computations
+fn crt_compact_list_arithmetic(params: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(params);
+    let pk = crate::integer::public_key::CompactPublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+    let basis = vec![2, 3, 5, 7];
+    let modulus = basis.iter().product::<u64>();
+
+    for _ in 0..5 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ciphertexts = pk
+            .encrypt_slice_crt_compact(&[clear_0, clear_1], basis.clone())
+            .expand();
+        let mut ct_1 = ciphertexts.pop().unwrap();
+        let mut ct_0 = ciphertexts.pop().unwrap();
+
+        let mut ct_res = sks.smart_crt_mul(&mut ct_0, &mut ct_1);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!((clear_0 * clear_1) % modulus, dec_res);
+
+        let ct_res = sks.smart_crt_add(&mut ct_res, &mut ct_0);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!((clear_0 * clear_1 + clear_0) % modulus, dec_res);
+    }
+}
+
+fn native_crt_encrypt_decrypt_compact_list(params: ClassicPBSParameters) {
+    let (cks, _) = gen_keys(params);
+    let pk = crate::integer::public_key::CompactPublicKey::new(&cks);
+
+    let mut rng = rand::thread_rng();
+    let basis = vec![7, 9, 11];
+    let modulus = basis.iter().product::<u64>();
+
+    let clear = rng.gen::<u64>() % modulus;
+    let ct = pk
+        .encrypt_native_crt_compact(clear, basis.clone())
+        .expand_one();
+    assert_eq!(ct.moduli, basis);
+    assert_eq!(cks.decrypt_native_crt(&ct), clear);
+
+    const MAX_CT: usize = 20;
+
+    for _ in 0..10 {
+        let num_ct_for_this_iter = rng.gen_range(1..=MAX_CT);
+        let clear_vec = (0..num_ct_for_this_iter)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+
+        let compact_encrypted_list = pk.encrypt_slice_native_crt_compact(&clear_vec, basis.clone());
+        let ciphertext_vec = compact_encrypted_list.expand();
+        assert_eq!(ciphertext_vec.len(), clear_vec.len());
+
+        for (ciphertext, clear) in ciphertext_vec.iter().zip(clear_vec.iter().copied()) {
+            let decrypted = cks.decrypt_native_crt(ciphertext);
+            assert_eq!(decrypted, clear);
+        }
+    }
+}
@@ -706,12 +706,13 @@ where {
         self.unchecked_min_or_max_parallelized(lhs, rhs, selector)
     }
 
-    fn map_sign_result<F>(
+    /// Maps the sign block to a block encrypting 1 if `sign_result_handler_fn` returns `true`,
+    /// 0 otherwise
+    fn map_sign_result_block<F>(
         &self,
         comparison: crate::shortint::Ciphertext,
         sign_result_handler_fn: F,
-        num_blocks: usize,
-    ) -> RadixCiphertext
+    ) -> crate::shortint::Ciphertext
     where
         F: Fn(u64) -> bool,
     {
@@ -719,7 +720,19 @@ where {
             .server_key
             .key
             .generate_lookup_table(|x| u64::from(sign_result_handler_fn(x)));
-        let result_block = self.server_key.key.apply_lookup_table(&comparison, &acc);
+        self.server_key.key.apply_lookup_table(&comparison, &acc)
+    }
+
+    fn map_sign_result<F>(
+        &self,
+        comparison: crate::shortint::Ciphertext,
+        sign_result_handler_fn: F,
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        F: Fn(u64) -> bool,
+    {
+        let result_block = self.map_sign_result_block(comparison, sign_result_handler_fn);
 
         let mut blocks = Vec::with_capacity(num_blocks);
         blocks.push(result_block);
@@ -1472,6 +1485,122 @@ where {
         };
         self.unchecked_scalar_min_or_max_parallelized(lhs, rhs, MinMaxSelector::Min)
     }
+
+    //======================================
+    // Unchecked Multi-Threaded operations
+    // returning a single block
+    //======================================
+
+    fn unchecked_comparison_block_parallelized<F>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        sign_result_handler_fn: F,
+    ) -> Ciphertext
+    where
+        F: Fn(u64) -> bool,
+    {
+        let sign_block = self.unchecked_sign_parallelized(lhs, rhs);
+        self.map_sign_result_block(sign_block, sign_result_handler_fn)
+    }
+
+    fn unchecked_scalar_comparison_block_parallelized<T, F>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+        sign_result_handler_fn: F,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+        F: Fn(u64) -> bool,
+    {
+        let sign_block = self.unchecked_scalar_sign_parallelized(lhs, rhs);
+        self.map_sign_result_block(sign_block, sign_result_handler_fn)
+    }
+
+    pub(crate) fn unchecked_gt_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.unchecked_comparison_block_parallelized(lhs, rhs, |x| x == Self::IS_SUPERIOR)
+    }
+
+    pub(crate) fn unchecked_ge_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.unchecked_comparison_block_parallelized(lhs, rhs, |x| {
+            x == Self::IS_EQUAL || x == Self::IS_SUPERIOR
+        })
+    }
+
+    pub(crate) fn unchecked_lt_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.unchecked_comparison_block_parallelized(lhs, rhs, |x| x == Self::IS_INFERIOR)
+    }
+
+    pub(crate) fn unchecked_le_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.unchecked_comparison_block_parallelized(lhs, rhs, |x| {
+            x == Self::IS_EQUAL || x == Self::IS_INFERIOR
+        })
+    }
+
+    pub(crate) fn unchecked_scalar_gt_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_block_parallelized(lhs, rhs, |x| x == Self::IS_SUPERIOR)
+    }
+
+    pub(crate) fn unchecked_scalar_ge_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_block_parallelized(lhs, rhs, |x| {
+            x == Self::IS_EQUAL || x == Self::IS_SUPERIOR
+        })
+    }
+
+    pub(crate) fn unchecked_scalar_lt_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_block_parallelized(lhs, rhs, |x| x == Self::IS_INFERIOR)
+    }
+
+    pub(crate) fn unchecked_scalar_le_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.unchecked_scalar_comparison_block_parallelized(lhs, rhs, |x| {
+            x == Self::IS_EQUAL || x == Self::IS_INFERIOR
+        })
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,390 @@
+use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertext};
+use crate::integer::ServerKey;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::Ciphertext;
+
+impl ServerKey {
+    /// Returns the number of radix blocks needed to represent all the values
+    /// of a CRT ciphertext with the given basis
+    pub(crate) fn crt_radix_num_blocks(&self, moduli: &[u64]) -> usize {
+        let message_modulus = self.key.message_modulus.0 as u128;
+        let modulus = moduli
+            .iter()
+            .map(|&modulus| u128::from(modulus))
+            .product::<u128>();
+
+        let mut num_blocks = 1;
+        let mut radix_modulus = message_modulus;
+        while radix_modulus < modulus {
+            radix_modulus *= message_modulus;
+            num_blocks += 1;
+        }
+        num_blocks
+    }
+
+    /// Creates a CRT ciphertext with the given basis from a block encrypting 0 or 1
+    pub(crate) fn crt_from_boolean_block(
+        &self,
+        block: &Ciphertext,
+        moduli: &[u64],
+    ) -> CrtCiphertext {
+        let total_modulus = self.key.message_modulus.0 * self.key.carry_modulus.0;
+        // Every modulus is at least 2, so the block already
+        // encrypts the value modulo each of them
+        let blocks = moduli
+            .iter()
+            .map(|&modulus| {
+                let mut block = block.clone();
+                block.message_modulus = MessageModulus(modulus as usize);
+                block.carry_modulus = CarryModulus(total_modulus / modulus as usize);
+                block
+            })
+            .collect::<Vec<_>>();
+        CrtCiphertext::from((blocks, moduli.to_vec()))
+    }
+
+    /// Converts both ciphertexts to radix, and applies the comparison on them
+    fn unchecked_crt_compare<F>(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+        comparison: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(&RadixCiphertext, &RadixCiphertext) -> RadixCiphertext,
+    {
+        assert_eq!(
+            lhs.moduli, rhs.moduli,
+            "Ciphertexts must have the same CRT basis"
+        );
+        let num_blocks = self.crt_radix_num_blocks(&lhs.moduli);
+        let lhs_radix = self.crt_to_radix(lhs, num_blocks);
+        let rhs_radix = self.crt_to_radix(rhs, num_blocks);
+
+        let result = comparison(&lhs_radix, &rhs_radix);
+        self.crt_from_boolean_block(&result.blocks[0], &lhs.moduli)
+    }
+
+    /// Converts both ciphertexts to radix, and applies the min or max on them
+    fn unchecked_crt_min_or_max<F>(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+        min_or_max: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(&RadixCiphertext, &RadixCiphertext) -> RadixCiphertext,
+    {
+        assert_eq!(
+            lhs.moduli, rhs.moduli,
+            "Ciphertexts must have the same CRT basis"
+        );
+        let num_blocks = self.crt_radix_num_blocks(&lhs.moduli);
+        let lhs_radix = self.crt_to_radix(lhs, num_blocks);
+        let rhs_radix = self.crt_to_radix(rhs, num_blocks);
+
+        let result = min_or_max(&lhs_radix, &rhs_radix);
+        self.radix_to_crt(&result, &lhs.moduli)
+    }
+
+    /// Compares for equality two ciphertexts encrypting integer values in the CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The values are converted as is to the radix decomposition to be compared,
+    /// see [Self::crt_to_radix].
+    pub fn unchecked_crt_eq(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_compare(lhs, rhs, |lhs, rhs| self.unchecked_eq(lhs, rhs))
+    }
+
+    /// Compares for equality two ciphertexts encrypting integer values in the CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if their carries are not empty.
+    pub fn smart_crt_eq(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_eq(lhs, rhs)
+    }
+
+    /// Compares for equality two ciphertexts encrypting integer values in the CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The conversion to the radix decomposition takes the carries of the blocks into account,
+    /// so the inputs never need to be cleaned, see [Self::crt_to_radix].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = sks.crt_eq(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, u64::from(clear_1 == clear_2));
+    /// ```
+    pub fn crt_eq(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_eq(lhs, rhs)
+    }
+
+    /// Compares for inequality two ciphertexts encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq].
+    pub fn unchecked_crt_ne(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_compare(lhs, rhs, |lhs, rhs| self.unchecked_ne(lhs, rhs))
+    }
+
+    /// Compares for inequality two ciphertexts encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq].
+    pub fn smart_crt_ne(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_ne(lhs, rhs)
+    }
+
+    /// Compares for inequality two ciphertexts encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq].
+    pub fn crt_ne(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_ne(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq].
+    pub fn unchecked_crt_gt(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_compare(lhs, rhs, |lhs, rhs| self.unchecked_gt(lhs, rhs))
+    }
+
+    /// Compares if lhs is strictly greater than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq].
+    pub fn smart_crt_gt(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_gt(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 23;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = sks.crt_gt(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, u64::from(clear_1 > clear_2));
+    /// ```
+    pub fn crt_gt(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_gt(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq].
+    pub fn unchecked_crt_ge(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_compare(lhs, rhs, |lhs, rhs| self.unchecked_ge(lhs, rhs))
+    }
+
+    /// Compares if lhs is greater than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq].
+    pub fn smart_crt_ge(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_ge(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq].
+    pub fn crt_ge(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_ge(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq].
+    pub fn unchecked_crt_lt(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_compare(lhs, rhs, |lhs, rhs| self.unchecked_lt(lhs, rhs))
+    }
+
+    /// Compares if lhs is strictly lower than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq].
+    pub fn smart_crt_lt(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_lt(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq].
+    pub fn crt_lt(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_lt(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq].
+    pub fn unchecked_crt_le(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_compare(lhs, rhs, |lhs, rhs| self.unchecked_le(lhs, rhs))
+    }
+
+    /// Compares if lhs is lower than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq].
+    pub fn smart_crt_le(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_le(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq].
+    pub fn crt_le(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_le(lhs, rhs)
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::unchecked_crt_eq].
+    pub fn unchecked_crt_max(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_min_or_max(lhs, rhs, |lhs, rhs| self.unchecked_max(lhs, rhs))
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::smart_crt_eq].
+    pub fn smart_crt_max(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_max(lhs, rhs)
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::crt_eq].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 23;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = sks.crt_max(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear_1.max(clear_2));
+    /// ```
+    pub fn crt_max(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_max(lhs, rhs)
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::unchecked_crt_eq].
+    pub fn unchecked_crt_min(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_min_or_max(lhs, rhs, |lhs, rhs| self.unchecked_min(lhs, rhs))
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::smart_crt_eq].
+    pub fn smart_crt_min(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) -> CrtCiphertext {
+        self.crt_clean_carries_pair(lhs, rhs);
+        self.unchecked_crt_min(lhs, rhs)
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::crt_eq].
+    pub fn crt_min(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_min(lhs, rhs)
+    }
+}
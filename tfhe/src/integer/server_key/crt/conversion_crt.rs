@@ -0,0 +1,195 @@
+use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertext};
+use crate::integer::client_key::utils::crt_coefficient;
+use crate::integer::ServerKey;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+
+impl ServerKey {
+    /// Converts a ciphertext in the radix decomposition into a ciphertext in the
+    /// CRT decomposition with the given `basis`.
+    ///
+    /// The result encrypts the radix value modulo the product of the basis.
+    ///
+    /// Each CRT block is the sum of the radix blocks weighted by their position
+    /// reduced modulo the block modulus, this is computed with one PBS per radix block and
+    /// per CRT block, plus the PBS needed to reduce the sums.
+    ///
+    /// The elements of the basis must be pairwise coprime, and must not be greater than
+    /// the message space of the key (message modulus times carry modulus).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear = 173u64;
+    /// let basis = [2, 3, 5];
+    ///
+    /// // 4 blocks of 2 bits
+    /// let ct = cks.encrypt_radix(clear, 4);
+    ///
+    /// let ct_res = sks.radix_to_crt(&ct, &basis);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear % 30);
+    /// ```
+    pub fn radix_to_crt(&self, ct: &RadixCiphertext, basis: &[u64]) -> CrtCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let total_modulus = message_modulus * self.key.carry_modulus.0 as u64;
+        assert!(
+            basis
+                .iter()
+                .all(|&modulus| modulus >= 2 && modulus <= total_modulus),
+            "The moduli of the basis must be in [2, {total_modulus}]"
+        );
+
+        let blocks = basis
+            .iter()
+            .map(|&modulus| {
+                let reduce_lut = self.key.generate_lookup_table(|x| x % modulus);
+
+                // The weight of the radix block of index i, modulo the CRT block modulus
+                let mut weight = 1 % modulus;
+                let mut sum = self.key.create_trivial(0);
+                for block in &ct.blocks {
+                    let lut = self.key.generate_lookup_table(|x| (x * weight) % modulus);
+                    let term = self.key.apply_lookup_table(block, &lut);
+                    if (sum.degree.0 + term.degree.0) as u64 >= total_modulus {
+                        self.key.apply_lookup_table_assign(&mut sum, &reduce_lut);
+                    }
+                    self.key.unchecked_add_assign(&mut sum, &term);
+                    weight = (weight * message_modulus) % modulus;
+                }
+                self.key.apply_lookup_table_assign(&mut sum, &reduce_lut);
+
+                sum.message_modulus = MessageModulus(modulus as usize);
+                sum.carry_modulus = CarryModulus((total_modulus / modulus) as usize);
+                sum
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, basis.to_vec()))
+    }
+
+    /// Converts a ciphertext in the CRT decomposition into a ciphertext in the
+    /// radix decomposition with `num_blocks` blocks.
+    ///
+    /// Each CRT block is mapped, using one PBS per output block, to the radix decomposition of
+    /// its contribution to the value (as given by the Chinese Remainder Theorem),
+    /// these contributions are then summed modulo the product of the basis.
+    /// CRT blocks with non-empty carries are accepted as is.
+    ///
+    /// The product of the basis must fit in `num_blocks` blocks.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear = 23u64;
+    /// let basis = vec![2, 3, 5];
+    ///
+    /// let ct = cks.encrypt_crt(clear, basis);
+    ///
+    /// // 4 blocks of 2 bits
+    /// let ct_res = sks.crt_to_radix(&ct, 4);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, clear);
+    /// ```
+    pub fn crt_to_radix(&self, ct: &CrtCiphertext, num_blocks: usize) -> RadixCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let modulus = ct.moduli.iter().product::<u64>();
+        assert!(
+            message_modulus
+                .checked_pow(num_blocks as u32)
+                .map_or(true, |radix_modulus| modulus <= radix_modulus),
+            "The product of the basis ({modulus}) does not fit in {num_blocks} blocks"
+        );
+
+        // The terms are computed with one more block, so that the sum
+        // of two of them does not overflow
+        let term_num_blocks = num_blocks + 1;
+        let mut terms = ct
+            .blocks
+            .iter()
+            .zip(ct.moduli.iter())
+            .map(|(block, &block_modulus)| {
+                let coefficient = crt_coefficient(block_modulus, modulus);
+                let blocks = (0..term_num_blocks as u32)
+                    .map(|i| {
+                        let lut = self.key.generate_lookup_table(|x| {
+                            let term = (u128::from(x % block_modulus) * u128::from(coefficient)
+                                % u128::from(modulus))
+                                as u64;
+                            message_modulus
+                                .checked_pow(i)
+                                .map_or(0, |shift| (term / shift) % message_modulus)
+                        });
+                        let mut radix_block = self.key.apply_lookup_table(block, &lut);
+                        // The PBS keeps the moduli of the CRT block
+                        radix_block.message_modulus = self.key.message_modulus;
+                        radix_block.carry_modulus = self.key.carry_modulus;
+                        radix_block
+                    })
+                    .collect::<Vec<_>>();
+                RadixCiphertext::from(blocks)
+            });
+
+        let mut sum = match terms.next() {
+            Some(term) => term,
+            None => return self.create_trivial_zero_radix(num_blocks),
+        };
+
+        let modulus_ct = self.create_trivial_radix(modulus, term_num_blocks);
+        // The luts giving the blocks of the modulus if the input is 1, 0 otherwise
+        let modulus_luts = (0..term_num_blocks as u32)
+            .map(|i| {
+                let block = message_modulus
+                    .checked_pow(i)
+                    .map_or(0, |shift| (modulus / shift) % message_modulus);
+                self.key
+                    .generate_lookup_table(|x| if x == 1 { block } else { 0 })
+            })
+            .collect::<Vec<_>>();
+
+        for mut term in terms {
+            let mut tmp = self.smart_add(&mut sum, &mut term);
+            self.full_propagate(&mut tmp);
+
+            // Both terms are smaller than the modulus,
+            // so it has to be subtracted at most once
+            let is_greater_or_equal = self.unchecked_ge(&tmp, &modulus_ct);
+            let mut correction = RadixCiphertext::from(
+                modulus_luts
+                    .iter()
+                    .map(|lut| {
+                        self.key
+                            .apply_lookup_table(&is_greater_or_equal.blocks[0], lut)
+                    })
+                    .collect::<Vec<_>>(),
+            );
+            sum = self.smart_sub(&mut tmp, &mut correction);
+            self.full_propagate(&mut sum);
+        }
+
+        self.trim_radix_blocks_msb_assign(&mut sum, 1);
+        sum
+    }
+}
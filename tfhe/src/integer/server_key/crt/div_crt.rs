@@ -0,0 +1,156 @@
+use crate::integer::ciphertext::CrtCiphertext;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Computes homomorphically the quotient and remainder of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// The values are converted as is to the radix decomposition to be divided,
+    /// see [Self::crt_to_radix].
+    ///
+    /// Dividing by zero does not panic, but the quotient and remainder are then meaningless.
+    pub fn unchecked_crt_div_rem(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> (CrtCiphertext, CrtCiphertext) {
+        assert_eq!(
+            numerator.moduli, divisor.moduli,
+            "Ciphertexts must have the same CRT basis"
+        );
+        let num_blocks = self.crt_radix_num_blocks(&numerator.moduli);
+        let numerator_radix = self.crt_to_radix(numerator, num_blocks);
+        let divisor_radix = self.crt_to_radix(divisor, num_blocks);
+
+        // The conversion outputs clean ciphertexts
+        let (quotient, remainder) = self.unchecked_div_rem(&numerator_radix, &divisor_radix);
+        (
+            self.radix_to_crt(&quotient, &numerator.moduli),
+            self.radix_to_crt(&remainder, &numerator.moduli),
+        )
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// The messages of the inputs are extracted if their carries are not empty.
+    ///
+    /// Dividing by zero does not panic, but the quotient and remainder are then meaningless.
+    pub fn smart_crt_div_rem(
+        &self,
+        numerator: &mut CrtCiphertext,
+        divisor: &mut CrtCiphertext,
+    ) -> (CrtCiphertext, CrtCiphertext) {
+        self.crt_clean_carries_pair(numerator, divisor);
+        self.unchecked_crt_div_rem(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// The conversion to the radix decomposition takes the carries of the blocks into account,
+    /// so the inputs never need to be cleaned, see [Self::crt_to_radix].
+    ///
+    /// Dividing by zero does not panic, but the quotient and remainder are then meaningless.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 29;
+    /// let clear_2 = 4;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let (q, r) = sks.crt_div_rem(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let q = cks.decrypt_crt(&q);
+    /// let r = cks.decrypt_crt(&r);
+    /// assert_eq!(q, clear_1 / clear_2);
+    /// assert_eq!(r, clear_1 % clear_2);
+    /// ```
+    pub fn crt_div_rem(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> (CrtCiphertext, CrtCiphertext) {
+        self.unchecked_crt_div_rem(numerator, divisor)
+    }
+
+    /// Computes homomorphically the quotient of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// See [Self::unchecked_crt_div_rem].
+    pub fn unchecked_crt_div(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (quotient, _) = self.unchecked_crt_div_rem(numerator, divisor);
+        quotient
+    }
+
+    /// Computes homomorphically the quotient of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// See [Self::smart_crt_div_rem].
+    pub fn smart_crt_div(
+        &self,
+        numerator: &mut CrtCiphertext,
+        divisor: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (quotient, _) = self.smart_crt_div_rem(numerator, divisor);
+        quotient
+    }
+
+    /// Computes homomorphically the quotient of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// See [Self::crt_div_rem].
+    pub fn crt_div(&self, numerator: &CrtCiphertext, divisor: &CrtCiphertext) -> CrtCiphertext {
+        let (quotient, _) = self.crt_div_rem(numerator, divisor);
+        quotient
+    }
+
+    /// Computes homomorphically the remainder of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// See [Self::unchecked_crt_div_rem].
+    pub fn unchecked_crt_rem(
+        &self,
+        numerator: &CrtCiphertext,
+        divisor: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (_, remainder) = self.unchecked_crt_div_rem(numerator, divisor);
+        remainder
+    }
+
+    /// Computes homomorphically the remainder of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// See [Self::smart_crt_div_rem].
+    pub fn smart_crt_rem(
+        &self,
+        numerator: &mut CrtCiphertext,
+        divisor: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        let (_, remainder) = self.smart_crt_div_rem(numerator, divisor);
+        remainder
+    }
+
+    /// Computes homomorphically the remainder of the division of two ciphertexts
+    /// encrypting integer values in the CRT decomposition.
+    ///
+    /// See [Self::crt_div_rem].
+    pub fn crt_rem(&self, numerator: &CrtCiphertext, divisor: &CrtCiphertext) -> CrtCiphertext {
+        let (_, remainder) = self.crt_div_rem(numerator, divisor);
+        remainder
+    }
+}
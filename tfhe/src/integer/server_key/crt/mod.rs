@@ -5,6 +5,9 @@ use crate::integer::ServerKey;
 mod tests;
 
 mod add_crt;
+mod comparison_crt;
+mod conversion_crt;
+mod div_crt;
 mod mul_crt;
 mod neg_crt;
 mod scalar_add_crt;
@@ -46,6 +49,14 @@ impl ServerKey {
         }
     }
 
+    /// Extracts the messages of both ciphertexts, if the carries of one of them are not empty
+    pub(crate) fn crt_clean_carries_pair(&self, lhs: &mut CrtCiphertext, rhs: &mut CrtCiphertext) {
+        if !lhs.block_carries_are_empty() || !rhs.block_carries_are_empty() {
+            self.full_extract_message_assign(lhs);
+            self.full_extract_message_assign(rhs);
+        }
+    }
+
     /// Computes a PBS for CRT-compliant functions.
     ///
     /// # Warning
@@ -14,6 +14,38 @@ create_parametrized_test!(integer_smart_crt_scalar_mul);
 create_parametrized_test!(integer_smart_crt_scalar_sub);
 create_parametrized_test!(integer_smart_crt_sub);
 
+create_parametrized_test!(integer_radix_to_crt);
+create_parametrized_test!(integer_crt_to_radix);
+
+create_parametrized_test!(integer_crt_comparisons {
+    // Due to the use of comparison,
+    // this algorithm requires 3 bits
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+});
+create_parametrized_test!(integer_crt_min_max {
+    // Due to the use of comparison,
+    // this algorithm requires 3 bits
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+});
+create_parametrized_test!(integer_crt_div_rem {
+    // Due to the use of comparison,
+    // this algorithm requires 3 bits
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+});
+create_parametrized_test!(integer_smart_crt_comparisons_div_rem {
+    // Due to the use of comparison,
+    // this algorithm requires 3 bits
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+});
+
 /// Number of loop iteration within randomized tests
 const NB_TEST: usize = 30;
 
@@ -271,3 +303,262 @@ fn integer_smart_crt_sub(param: ClassicPBSParameters) {
         assert_eq!(clear_0, dec_res);
     }
 }
+
+fn integer_radix_to_crt(param: ClassicPBSParameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_blocks = 4;
+    let radix_modulus = (param.message_modulus.0 as u64).pow(num_blocks as u32);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % radix_modulus;
+
+        let ct = cks.encrypt_radix(clear, num_blocks);
+
+        let ct_res = sks.radix_to_crt(&ct, &basis);
+        assert_eq!(ct_res.moduli, basis);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(clear % modulus, dec_res);
+
+        let ct_res = sks.radix_to_crt_parallelized(&ct, &basis);
+        assert_eq!(ct_res.moduli, basis);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(clear % modulus, dec_res);
+
+        // The result can be used in CRT operations
+        let ct_res = sks.smart_crt_mul_parallelized(&ct_res, &mut ct_res.clone());
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!((clear * clear) % modulus, dec_res);
+    }
+}
+
+fn integer_crt_to_radix(param: ClassicPBSParameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let num_blocks = 4;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let mut ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_one = cks.encrypt_crt(clear_1, basis.clone());
+
+        // Blocks with carries are also converted
+        sks.unchecked_crt_add_assign(&mut ct_zero, &ct_one);
+
+        let ct_res = sks.crt_to_radix(&ct_zero, num_blocks);
+        assert_eq!(ct_res.blocks.len(), num_blocks);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+
+        let ct_res = sks.crt_to_radix_parallelized(&ct_zero, num_blocks);
+        assert_eq!(ct_res.blocks.len(), num_blocks);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+    }
+}
+
+fn integer_crt_comparisons(param: ClassicPBSParameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        // Make equality likely enough to be tested
+        let clear_1 = if rng.gen::<bool>() {
+            clear_0
+        } else {
+            rng.gen::<u64>() % modulus
+        };
+
+        let ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_one = cks.encrypt_crt(clear_1, basis.clone());
+
+        let expected = [
+            clear_0 == clear_1,
+            clear_0 != clear_1,
+            clear_0 > clear_1,
+            clear_0 >= clear_1,
+            clear_0 < clear_1,
+            clear_0 <= clear_1,
+        ];
+
+        let results = [
+            sks.crt_eq(&ct_zero, &ct_one),
+            sks.crt_ne(&ct_zero, &ct_one),
+            sks.crt_gt(&ct_zero, &ct_one),
+            sks.crt_ge(&ct_zero, &ct_one),
+            sks.crt_lt(&ct_zero, &ct_one),
+            sks.crt_le(&ct_zero, &ct_one),
+        ];
+        for (ct_res, expected) in results.iter().zip(expected) {
+            assert_eq!(ct_res.moduli, basis);
+            let dec_res = cks.decrypt_crt(ct_res);
+            assert_eq!(u64::from(expected), dec_res);
+        }
+
+        let results = [
+            sks.crt_eq_parallelized(&ct_zero, &ct_one),
+            sks.crt_ne_parallelized(&ct_zero, &ct_one),
+            sks.crt_gt_parallelized(&ct_zero, &ct_one),
+            sks.crt_ge_parallelized(&ct_zero, &ct_one),
+            sks.crt_lt_parallelized(&ct_zero, &ct_one),
+            sks.crt_le_parallelized(&ct_zero, &ct_one),
+        ];
+        for (ct_res, expected) in results.iter().zip(expected) {
+            assert_eq!(ct_res.moduli, basis);
+            let dec_res = cks.decrypt_crt(ct_res);
+            assert_eq!(u64::from(expected), dec_res);
+        }
+    }
+}
+
+fn integer_crt_min_max(param: ClassicPBSParameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_one = cks.encrypt_crt(clear_1, basis.clone());
+
+        let ct_res = sks.crt_max(&ct_zero, &ct_one);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(clear_0.max(clear_1), dec_res);
+
+        let ct_res = sks.crt_min(&ct_zero, &ct_one);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(clear_0.min(clear_1), dec_res);
+
+        let ct_res = sks.crt_max_parallelized(&ct_zero, &ct_one);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(clear_0.max(clear_1), dec_res);
+
+        let ct_res = sks.crt_min_parallelized(&ct_zero, &ct_one);
+        let dec_res = cks.decrypt_crt(&ct_res);
+        assert_eq!(clear_0.min(clear_1), dec_res);
+    }
+}
+
+fn integer_crt_div_rem(param: ClassicPBSParameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen_range(1..modulus);
+
+        let ct_zero = cks.encrypt_crt(clear_0, basis.clone());
+        let ct_one = cks.encrypt_crt(clear_1, basis.clone());
+
+        let (q, r) = sks.crt_div_rem(&ct_zero, &ct_one);
+        let q_res = cks.decrypt_crt(&q);
+        let r_res = cks.decrypt_crt(&r);
+        assert_eq!(clear_0 / clear_1, q_res);
+        assert_eq!(clear_0 % clear_1, r_res);
+
+        let (q, r) = sks.crt_div_rem_parallelized(&ct_zero, &ct_one);
+        let q_res = cks.decrypt_crt(&q);
+        let r_res = cks.decrypt_crt(&r);
+        assert_eq!(clear_0 / clear_1, q_res);
+        assert_eq!(clear_0 % clear_1, r_res);
+    }
+}
+
+fn integer_smart_crt_comparisons_div_rem(param: ClassicPBSParameters) {
+    // Define CRT basis, and global modulus
+    let basis = make_basis(param.message_modulus.0);
+    let modulus = basis.iter().product::<u64>();
+
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen_range(1..modulus);
+
+        // Adding encryptions of zero fills the carries without changing the values
+        let ct_zero = cks.encrypt_crt(0, basis.clone());
+        let mut ct_0 = sks.unchecked_crt_add(&cks.encrypt_crt(clear_0, basis.clone()), &ct_zero);
+        let mut ct_1 = sks.unchecked_crt_add(&cks.encrypt_crt(clear_1, basis.clone()), &ct_zero);
+        assert!(!ct_0.block_carries_are_empty());
+
+        let ct_res = sks.unchecked_crt_gt_parallelized(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 > clear_1), cks.decrypt_crt(&ct_res));
+
+        let ct_res = sks.unchecked_crt_max_parallelized(&ct_0, &ct_1);
+        assert_eq!(clear_0.max(clear_1), cks.decrypt_crt(&ct_res));
+
+        let (q, r) = sks.unchecked_crt_div_rem_parallelized(&ct_0, &ct_1);
+        assert_eq!(clear_0 / clear_1, cks.decrypt_crt(&q));
+        assert_eq!(clear_0 % clear_1, cks.decrypt_crt(&r));
+
+        let ct_res = sks.unchecked_crt_lt(&ct_0, &ct_1);
+        assert_eq!(u64::from(clear_0 < clear_1), cks.decrypt_crt(&ct_res));
+
+        let (q, r) = sks.unchecked_crt_div_rem(&ct_0, &ct_1);
+        assert_eq!(clear_0 / clear_1, cks.decrypt_crt(&q));
+        assert_eq!(clear_0 % clear_1, cks.decrypt_crt(&r));
+
+        let ct_res = sks.smart_crt_min(&mut sks.unchecked_crt_add(&ct_0, &ct_zero), &mut ct_1);
+        assert_eq!(clear_0.min(clear_1), cks.decrypt_crt(&ct_res));
+
+        let ct_res = sks.smart_crt_eq_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(u64::from(clear_0 == clear_1), cks.decrypt_crt(&ct_res));
+        assert!(ct_0.block_carries_are_empty());
+        assert!(ct_1.block_carries_are_empty());
+        assert_eq!(clear_0, cks.decrypt_crt(&ct_0));
+        assert_eq!(clear_1, cks.decrypt_crt(&ct_1));
+
+        let mut ct_0 = sks.unchecked_crt_add(&ct_0, &ct_zero);
+        let (q, r) = sks.smart_crt_div_rem_parallelized(&mut ct_0, &mut ct_1);
+        assert_eq!(clear_0 / clear_1, cks.decrypt_crt(&q));
+        assert_eq!(clear_0 % clear_1, cks.decrypt_crt(&r));
+        assert!(ct_0.block_carries_are_empty());
+
+        let mut ct_0 = sks.unchecked_crt_add(&ct_0, &ct_zero);
+        let (q, r) = sks.smart_crt_div_rem(&mut ct_0, &mut ct_1);
+        assert_eq!(clear_0 / clear_1, cks.decrypt_crt(&q));
+        assert_eq!(clear_0 % clear_1, cks.decrypt_crt(&r));
+        assert!(ct_0.block_carries_are_empty());
+    }
+}
@@ -0,0 +1,455 @@
+use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertext};
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+
+impl ServerKey {
+    /// Converts both ciphertexts to radix, and applies the comparison on them
+    fn unchecked_crt_compare_parallelized<F>(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+        comparison: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(&RadixCiphertext, &RadixCiphertext) -> Ciphertext,
+    {
+        assert_eq!(
+            lhs.moduli, rhs.moduli,
+            "Ciphertexts must have the same CRT basis"
+        );
+        let num_blocks = self.crt_radix_num_blocks(&lhs.moduli);
+        let (lhs_radix, rhs_radix) = rayon::join(
+            || self.crt_to_radix_parallelized(lhs, num_blocks),
+            || self.crt_to_radix_parallelized(rhs, num_blocks),
+        );
+
+        let result = comparison(&lhs_radix, &rhs_radix);
+        self.crt_from_boolean_block(&result, &lhs.moduli)
+    }
+
+    /// Converts both ciphertexts to radix, and applies the min or max on them
+    fn unchecked_crt_min_or_max_parallelized<F>(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+        min_or_max: F,
+    ) -> CrtCiphertext
+    where
+        F: Fn(&RadixCiphertext, &RadixCiphertext) -> RadixCiphertext,
+    {
+        assert_eq!(
+            lhs.moduli, rhs.moduli,
+            "Ciphertexts must have the same CRT basis"
+        );
+        let num_blocks = self.crt_radix_num_blocks(&lhs.moduli);
+        let (lhs_radix, rhs_radix) = rayon::join(
+            || self.crt_to_radix_parallelized(lhs, num_blocks),
+            || self.crt_to_radix_parallelized(rhs, num_blocks),
+        );
+
+        let result = min_or_max(&lhs_radix, &rhs_radix);
+        self.radix_to_crt_parallelized(&result, &lhs.moduli)
+    }
+
+    /// Compares for equality two ciphertexts encrypting integer values in the CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The values are converted as is to the radix decomposition to be compared,
+    /// see [Self::crt_to_radix_parallelized].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn unchecked_crt_eq_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_compare_parallelized(lhs, rhs, |lhs, rhs| {
+            self.unchecked_eq_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Compares for equality two ciphertexts encrypting integer values in the CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The messages of the inputs are extracted if their carries are not empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    pub fn smart_crt_eq_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_eq_parallelized(lhs, rhs)
+    }
+
+    /// Compares for equality two ciphertexts encrypting integer values in the CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs == rhs, otherwise 0.
+    ///
+    /// The conversion to the radix decomposition takes the carries of the blocks into account,
+    /// so the inputs never need to be cleaned, see [Self::crt_to_radix_parallelized].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 14;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = sks.crt_eq_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, u64::from(clear_1 == clear_2));
+    /// ```
+    pub fn crt_eq_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_eq_parallelized(lhs, rhs)
+    }
+
+    /// Compares for inequality two ciphertexts encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq_parallelized].
+    pub fn unchecked_crt_ne_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_compare_parallelized(lhs, rhs, |lhs, rhs| {
+            self.unchecked_ne_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Compares for inequality two ciphertexts encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq_parallelized].
+    pub fn smart_crt_ne_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_ne_parallelized(lhs, rhs)
+    }
+
+    /// Compares for inequality two ciphertexts encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs != rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq_parallelized].
+    pub fn crt_ne_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_ne_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq_parallelized].
+    pub fn unchecked_crt_gt_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_compare_parallelized(lhs, rhs, |lhs, rhs| {
+            Comparator::new(self).unchecked_gt_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Compares if lhs is strictly greater than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq_parallelized].
+    pub fn smart_crt_gt_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_gt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly greater than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs > rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq_parallelized].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 23;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = sks.crt_gt_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, u64::from(clear_1 > clear_2));
+    /// ```
+    pub fn crt_gt_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_gt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq_parallelized].
+    pub fn unchecked_crt_ge_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_compare_parallelized(lhs, rhs, |lhs, rhs| {
+            Comparator::new(self).unchecked_ge_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Compares if lhs is greater than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq_parallelized].
+    pub fn smart_crt_ge_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_ge_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is greater than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs >= rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq_parallelized].
+    pub fn crt_ge_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_ge_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq_parallelized].
+    pub fn unchecked_crt_lt_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_compare_parallelized(lhs, rhs, |lhs, rhs| {
+            Comparator::new(self).unchecked_lt_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Compares if lhs is strictly lower than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq_parallelized].
+    pub fn smart_crt_lt_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_lt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is strictly lower than rhs, both encrypting integer values in the CRT
+    /// decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs < rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq_parallelized].
+    pub fn crt_lt_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_lt_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// See [Self::unchecked_crt_eq_parallelized].
+    pub fn unchecked_crt_le_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_compare_parallelized(lhs, rhs, |lhs, rhs| {
+            Comparator::new(self).unchecked_le_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Compares if lhs is lower than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// See [Self::smart_crt_eq_parallelized].
+    pub fn smart_crt_le_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_le_parallelized(lhs, rhs)
+    }
+
+    /// Compares if lhs is lower than or equal to rhs, both encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// Returns a ciphertext with the same basis containing 1 if lhs <= rhs, otherwise 0.
+    ///
+    /// See [Self::crt_eq_parallelized].
+    pub fn crt_le_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_le_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::unchecked_crt_eq_parallelized].
+    pub fn unchecked_crt_max_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_min_or_max_parallelized(lhs, rhs, |lhs, rhs| {
+            self.unchecked_max_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::smart_crt_eq_parallelized].
+    pub fn smart_crt_max_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_max_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the maximum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::crt_eq_parallelized].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear_1 = 23;
+    /// let clear_2 = 14;
+    /// let basis = vec![2, 3, 5];
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt_crt(clear_1, basis.clone());
+    /// let ctxt_2 = cks.encrypt_crt(clear_2, basis);
+    ///
+    /// let ct_res = sks.crt_max_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear_1.max(clear_2));
+    /// ```
+    pub fn crt_max_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_max_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::unchecked_crt_eq_parallelized].
+    pub fn unchecked_crt_min_parallelized(
+        &self,
+        lhs: &CrtCiphertext,
+        rhs: &CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.unchecked_crt_min_or_max_parallelized(lhs, rhs, |lhs, rhs| {
+            self.unchecked_min_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::smart_crt_eq_parallelized].
+    pub fn smart_crt_min_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) -> CrtCiphertext {
+        self.crt_clean_carries_pair_parallelized(lhs, rhs);
+        self.unchecked_crt_min_parallelized(lhs, rhs)
+    }
+
+    /// Computes homomorphically the minimum of two ciphertexts encrypting integer values in the
+    /// CRT decomposition.
+    ///
+    /// See [Self::crt_eq_parallelized].
+    pub fn crt_min_parallelized(&self, lhs: &CrtCiphertext, rhs: &CrtCiphertext) -> CrtCiphertext {
+        self.unchecked_crt_min_parallelized(lhs, rhs)
+    }
+}
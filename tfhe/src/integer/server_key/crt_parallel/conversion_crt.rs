@@ -0,0 +1,185 @@
+use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertext};
+use crate::integer::client_key::utils::crt_coefficient;
+use crate::integer::ServerKey;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Converts a ciphertext in the radix decomposition into a ciphertext in the
+    /// CRT decomposition with the given `basis`.
+    ///
+    /// The result encrypts the radix value modulo the product of the basis.
+    ///
+    /// Each CRT block is the sum of the radix blocks weighted by their position
+    /// reduced modulo the block modulus, this is computed with one PBS per radix block and
+    /// per CRT block, plus the PBS needed to reduce the sums.
+    ///
+    /// The elements of the basis must be pairwise coprime, and must not be greater than
+    /// the message space of the key (message modulus times carry modulus).
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear = 173u64;
+    /// let basis = [2, 3, 5];
+    ///
+    /// // 4 blocks of 2 bits
+    /// let ct = cks.encrypt_radix(clear, 4);
+    ///
+    /// let ct_res = sks.radix_to_crt_parallelized(&ct, &basis);
+    ///
+    /// // Decrypt
+    /// let res = cks.decrypt_crt(&ct_res);
+    /// assert_eq!(res, clear % 30);
+    /// ```
+    pub fn radix_to_crt_parallelized(&self, ct: &RadixCiphertext, basis: &[u64]) -> CrtCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let total_modulus = message_modulus * self.key.carry_modulus.0 as u64;
+        assert!(
+            basis
+                .iter()
+                .all(|&modulus| modulus >= 2 && modulus <= total_modulus),
+            "The moduli of the basis must be in [2, {total_modulus}]"
+        );
+
+        let blocks = basis
+            .par_iter()
+            .map(|&modulus| {
+                // The weight of the radix block of index i, modulo the CRT block modulus
+                let weights = std::iter::successors(Some(1 % modulus), |weight| {
+                    Some((weight * message_modulus) % modulus)
+                })
+                .take(ct.blocks.len())
+                .collect::<Vec<_>>();
+
+                let terms = ct
+                    .blocks
+                    .par_iter()
+                    .zip(weights.par_iter())
+                    .map(|(block, &weight)| {
+                        let lut = self.key.generate_lookup_table(|x| (x * weight) % modulus);
+                        self.key.apply_lookup_table(block, &lut)
+                    })
+                    .collect::<Vec<_>>();
+
+                let reduce_lut = self.key.generate_lookup_table(|x| x % modulus);
+                let mut terms = terms.into_iter();
+                let mut sum = terms.next().unwrap_or_else(|| self.key.create_trivial(0));
+                for term in terms {
+                    if (sum.degree.0 + term.degree.0) as u64 >= total_modulus {
+                        self.key.apply_lookup_table_assign(&mut sum, &reduce_lut);
+                    }
+                    self.key.unchecked_add_assign(&mut sum, &term);
+                }
+                self.key.apply_lookup_table_assign(&mut sum, &reduce_lut);
+
+                sum.message_modulus = MessageModulus(modulus as usize);
+                sum.carry_modulus = CarryModulus((total_modulus / modulus) as usize);
+                sum
+            })
+            .collect::<Vec<_>>();
+
+        CrtCiphertext::from((blocks, basis.to_vec()))
+    }
+
+    /// Converts a ciphertext in the CRT decomposition into a ciphertext in the
+    /// radix decomposition with `num_blocks` blocks.
+    ///
+    /// Each CRT block is mapped, using one PBS per output block, to the radix decomposition of
+    /// its contribution to the value (as given by the Chinese Remainder Theorem),
+    /// these contributions are then summed modulo the product of the basis.
+    /// CRT blocks with non-empty carries are accepted as is.
+    ///
+    /// The product of the basis must fit in `num_blocks` blocks.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let clear = 23u64;
+    /// let basis = vec![2, 3, 5];
+    ///
+    /// let ct = cks.encrypt_crt(clear, basis);
+    ///
+    /// // 4 blocks of 2 bits
+    /// let ct_res = sks.crt_to_radix_parallelized(&ct, 4);
+    ///
+    /// // Decrypt
+    /// let res: u64 = cks.decrypt_radix(&ct_res);
+    /// assert_eq!(res, clear);
+    /// ```
+    pub fn crt_to_radix_parallelized(
+        &self,
+        ct: &CrtCiphertext,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let modulus = ct.moduli.iter().product::<u64>();
+        assert!(
+            message_modulus
+                .checked_pow(num_blocks as u32)
+                .map_or(true, |radix_modulus| modulus <= radix_modulus),
+            "The product of the basis ({modulus}) does not fit in {num_blocks} blocks"
+        );
+
+        let terms = ct
+            .blocks
+            .par_iter()
+            .zip(ct.moduli.par_iter())
+            .map(|(block, &block_modulus)| {
+                let coefficient = crt_coefficient(block_modulus, modulus);
+
+                let blocks = (0..num_blocks as u32)
+                    .into_par_iter()
+                    .map(|i| {
+                        let lut = self.key.generate_lookup_table(|x| {
+                            let term = (u128::from(x % block_modulus) * u128::from(coefficient)
+                                % u128::from(modulus))
+                                as u64;
+                            message_modulus
+                                .checked_pow(i)
+                                .map_or(0, |shift| (term / shift) % message_modulus)
+                        });
+                        let mut radix_block = self.key.apply_lookup_table(block, &lut);
+                        // The PBS keeps the moduli of the CRT block
+                        radix_block.message_modulus = self.key.message_modulus;
+                        radix_block.carry_modulus = self.key.carry_modulus;
+                        radix_block
+                    })
+                    .collect::<Vec<_>>();
+                RadixCiphertext::from(blocks)
+            })
+            .collect::<Vec<_>>();
+
+        terms
+            .into_iter()
+            .reduce(|lhs, rhs| self.unchecked_add_mod_parallelized(&lhs, &rhs, modulus))
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+}
@@ -1,4 +1,7 @@
 mod add_crt;
+mod comparison_crt;
+mod conversion_crt;
+mod div_crt;
 mod mul_crt;
 mod neg_crt;
 mod scalar_add_crt;
@@ -44,6 +47,20 @@ impl ServerKey {
         });
     }
 
+    /// Extracts the messages of both ciphertexts, if the carries of one of them are not empty
+    pub(crate) fn crt_clean_carries_pair_parallelized(
+        &self,
+        lhs: &mut CrtCiphertext,
+        rhs: &mut CrtCiphertext,
+    ) {
+        if !lhs.block_carries_are_empty() || !rhs.block_carries_are_empty() {
+            rayon::join(
+                || self.full_extract_message_assign_parallelized(lhs),
+                || self.full_extract_message_assign_parallelized(rhs),
+            );
+        }
+    }
+
     /// Computes a PBS for CRT-compliant functions.
     ///
     /// # Warning
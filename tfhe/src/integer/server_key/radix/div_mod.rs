@@ -0,0 +1,79 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Computes homomorphically the quotient and remainder of the division of two ciphertexts.
+    ///
+    /// This is the school-book long division, where one bit of the quotient is computed at each
+    /// step, see [Self::unchecked_div_rem_parallelized] for the multithreaded version.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    /// - The divisor has the same number of blocks as the numerator
+    /// - The divisor is not zero, otherwise the result is meaningless
+    pub(crate) fn unchecked_div_rem(
+        &self,
+        numerator: &RadixCiphertext,
+        divisor: &RadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let num_blocks = numerator.blocks.len();
+        let num_bits_in_block = self.key.message_modulus.0.ilog2() as usize;
+
+        let mut quotient = self.create_trivial_zero_radix(num_blocks);
+        let mut remainder = self.create_trivial_zero_radix(num_blocks);
+
+        let bit_extraction_luts = (0..num_bits_in_block)
+            .map(|pos_in_block| {
+                self.key
+                    .generate_lookup_table(move |x| (x >> pos_in_block) & 1)
+            })
+            .collect::<Vec<_>>();
+        // Keeps x if y (a boolean) is 1, otherwise returns 0
+        let zeroer_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, y| if y == 1 { x } else { 0 });
+
+        for i in (0..num_blocks * num_bits_in_block).rev() {
+            let block_of_bit = i / num_bits_in_block;
+            let pos_in_block = i % num_bits_in_block;
+
+            // R := (R << 1) | N(i)
+            //
+            // The remainder is built from the bits of the numerator, so it never overflows
+            self.unchecked_scalar_left_shift_assign(&mut remainder, 1);
+            let numerator_bit = self.key.apply_lookup_table(
+                &numerator.blocks[block_of_bit],
+                &bit_extraction_luts[pos_in_block],
+            );
+            self.key
+                .unchecked_add_assign(&mut remainder.blocks[0], &numerator_bit);
+
+            // if R >= D then R := R - D and Q(i) := 1
+            let is_remainder_ge_divisor = self.unchecked_ge(&remainder, divisor);
+            let is_remainder_ge_divisor = &is_remainder_ge_divisor.blocks[0];
+
+            let blocks_to_subtract = divisor
+                .blocks
+                .iter()
+                .map(|block| {
+                    self.key.unchecked_apply_lookup_table_bivariate(
+                        block,
+                        is_remainder_ge_divisor,
+                        &zeroer_lut,
+                    )
+                })
+                .collect::<Vec<_>>();
+            self.unchecked_sub_assign(&mut remainder, &RadixCiphertext::from(blocks_to_subtract));
+            self.full_propagate(&mut remainder);
+
+            let quotient_bit = self
+                .key
+                .unchecked_scalar_left_shift(is_remainder_ge_divisor, pos_in_block as u8);
+            self.key
+                .unchecked_add_assign(&mut quotient.blocks[block_of_bit], &quotient_bit);
+        }
+
+        (quotient, remainder)
+    }
+}
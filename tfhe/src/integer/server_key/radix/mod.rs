@@ -1,6 +1,7 @@
 mod add;
 mod bitwise_op;
 mod comparison;
+mod div_mod;
 mod mul;
 mod neg;
 mod scalar_add;
@@ -11,9 +12,10 @@ mod sub;
 
 use super::ServerKey;
 
+use crate::core_crypto::prelude::SignedInteger;
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::RadixCiphertext;
-use crate::integer::encryption::encrypt_words_radix_impl;
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::encryption::{encrypt_signed_radix_impl, encrypt_words_radix_impl};
 
 #[cfg(test)]
 mod tests;
@@ -80,6 +82,43 @@ impl ServerKey {
         )
     }
 
+    /// Create a trivial signed radix ciphertext
+    ///
+    /// Trivial means that the value is not encrypted
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::{gen_keys_radix, SignedRadixCiphertext};
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// let num_blocks = 4;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ctxt: SignedRadixCiphertext = sks.create_trivial_signed_radix(-112i8, num_blocks);
+    ///
+    /// // Decrypt:
+    /// let dec: i8 = cks.decrypt_signed(&ctxt);
+    /// assert_eq!(-112, dec);
+    /// ```
+    pub fn create_trivial_signed_radix<T>(
+        &self,
+        value: T,
+        num_blocks: usize,
+    ) -> SignedRadixCiphertext
+    where
+        T: DecomposableInto<u64> + SignedInteger,
+    {
+        encrypt_signed_radix_impl(
+            &self.key,
+            value,
+            num_blocks,
+            crate::shortint::ServerKey::create_trivial,
+        )
+    }
+
     /// Prepend trivial zero LSB blocks to an existing [`RadixCiphertext`]. This can be useful for
     /// casting operations.
     ///
@@ -1,5 +1,6 @@
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
+use crate::shortint::server_key::LookupTableOwned;
 use crate::shortint::Ciphertext;
 
 use rayon::prelude::*;
@@ -205,6 +206,131 @@ impl ServerKey {
         }
     }
 
+    /// Computes homomorphically an addition between two ciphertexts encrypting integer values,
+    /// and whether the addition overflowed.
+    ///
+    /// The returned block encrypts 1 if the sum is greater than the maximum value the
+    /// ciphertext can represent (i.e. the result wrapped around), 0 otherwise.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 214u8;
+    /// let msg2 = 97u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Compute homomorphically an addition:
+    /// let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// let (expected_result, expected_overflowed) = msg1.overflowing_add(msg2);
+    /// assert_eq!(dec_result, expected_result);
+    /// assert_eq!(dec_overflowed, u64::from(expected_overflowed));
+    /// ```
+    pub fn overflowing_add_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        let mut ct_res = ct_left.clone();
+        let mut tmp_rhs: RadixCiphertext;
+
+        let rhs = if ct_right.block_carries_are_empty() {
+            ct_right
+        } else {
+            tmp_rhs = ct_right.clone();
+            self.full_propagate_parallelized(&mut tmp_rhs);
+            &tmp_rhs
+        };
+        if !ct_res.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut ct_res);
+        }
+
+        self.unchecked_add_assign_parallelized(&mut ct_res, rhs);
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let lut_does_block_overflow = self
+            .key
+            .generate_lookup_table(|x| u64::from(x >= message_modulus));
+        let overflowed = self.propagate_single_carry_get_output_carry_parallelized(
+            &mut ct_res,
+            &lut_does_block_overflow,
+        );
+
+        (ct_res, overflowed)
+    }
+
+    /// Computes homomorphically a saturating addition between two ciphertexts encrypting
+    /// integer values.
+    ///
+    /// If the sum is greater than the maximum value the ciphertext can represent,
+    /// the result is clamped to this maximum value instead of wrapping around.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 214u8;
+    /// let msg2 = 97u8;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // Compute homomorphically an addition:
+    /// let ct_res = sks.saturating_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg1.saturating_add(msg2));
+    /// ```
+    pub fn saturating_add_parallelized(
+        &self,
+        ct_left: &RadixCiphertext,
+        ct_right: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut ct_res, overflowed) = self.overflowing_add_parallelized(ct_left, ct_right);
+
+        let max_block_value = self.key.message_modulus.0 as u64 - 1;
+        self.unchecked_set_blocks_if_assign_parallelized(&mut ct_res, &overflowed, max_block_value);
+
+        ct_res
+    }
+
     pub fn add_parallelized_work_efficient(
         &self,
         ct_left: &RadixCiphertext,
@@ -313,6 +439,58 @@ impl ServerKey {
             });
     }
 
+    /// Propagates the carries of a ciphertext for which at most one bit of carry
+    /// is consumed in each block, like
+    /// [Self::propagate_single_carry_parallelized_low_latency].
+    ///
+    /// The carry output by the last block would otherwise be lost, so `output_carry_lut`
+    /// is applied to the last block after it received its input carry, that is to a value in
+    /// `[0, 2 * message_modulus[`, and the result is returned.
+    pub(crate) fn propagate_single_carry_get_output_carry_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        output_carry_lut: &LookupTableOwned,
+    ) -> Ciphertext {
+        let num_blocks = ct.blocks.len();
+        let other_blocks_need_cleaning = if self.is_eligible_for_parallel_carryless_add() {
+            let generates_or_propagates = self.generate_init_carry_array(ct);
+            let input_carries =
+                self.compute_carry_propagation_parallelized_low_latency(generates_or_propagates);
+            ct.blocks
+                .par_iter_mut()
+                .zip(input_carries.par_iter())
+                .for_each(|(block, input_carry)| {
+                    self.key.unchecked_add_assign(block, input_carry);
+                });
+            true
+        } else {
+            for i in 0..num_blocks - 1 {
+                self.propagate_parallelized(ct, i);
+            }
+            false
+        };
+
+        let (last_block, other_blocks) = ct.blocks.split_last_mut().unwrap();
+        let ((output_carry, message), _) = rayon::join(
+            || {
+                rayon::join(
+                    || self.key.apply_lookup_table(last_block, output_carry_lut),
+                    || self.key.message_extract(last_block),
+                )
+            },
+            || {
+                if other_blocks_need_cleaning {
+                    other_blocks
+                        .par_iter_mut()
+                        .for_each(|block| self.key.message_extract_assign(block));
+                }
+            },
+        );
+        *last_block = message;
+
+        output_carry
+    }
+
     /// Backbone algorithm of parallel carry (only one bit) propagation
     ///
     /// Uses the Hillis and Steele prefix scan
@@ -555,6 +733,72 @@ impl ServerKey {
         generates_or_propagates
     }
 
+    /// Computes homomorphically the sum of all the ciphertexts
+    ///
+    /// Returns `None` if there are no ciphertexts to sum.
+    ///
+    /// Contrary to successive calls to [Self::add_parallelized], the carries are only propagated
+    /// when the degree of the blocks requires it, which is much faster when many ciphertexts
+    /// are summed.
+    ///
+    /// All the ciphertexts must have the same number of blocks, their block carries do not
+    /// need to be empty. The output's carries will be empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [14u64, 97, 3, 42, 75];
+    ///
+    /// let ctxts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// // Compute homomorphically the sum:
+    /// let ct_res = sks.sum_ciphertexts_parallelized(&ctxts).unwrap();
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, clears.iter().sum::<u64>() % 256);
+    /// ```
+    pub fn sum_ciphertexts_parallelized<'a, C>(&self, ciphertexts: C) -> Option<RadixCiphertext>
+    where
+        C: IntoIterator<Item = &'a RadixCiphertext>,
+    {
+        let ciphertexts = ciphertexts.into_iter().cloned().collect::<Vec<_>>();
+        self.sum_ciphertexts_vec_parallelized(ciphertexts)
+    }
+
+    /// Computes homomorphically the sum of all the ciphertexts
+    ///
+    /// Same as [Self::sum_ciphertexts_parallelized], but takes ownership of the ciphertexts
+    /// instead of copying them.
+    pub fn sum_ciphertexts_vec_parallelized(
+        &self,
+        ciphertexts: Vec<RadixCiphertext>,
+    ) -> Option<RadixCiphertext> {
+        let num_blocks = ciphertexts.first()?.blocks.len();
+        assert!(
+            ciphertexts.iter().all(|ct| ct.blocks.len() == num_blocks),
+            "All ciphertexts must have the same number of blocks"
+        );
+
+        let mut result = self.create_trivial_zero_radix(num_blocks);
+        self.sum_multiplication_terms_into(&mut result, ciphertexts);
+        Some(result)
+    }
+
     /// op must be associative and commutative
     pub fn smart_binary_op_seq_parallelized<'this, 'item>(
         &'this self,
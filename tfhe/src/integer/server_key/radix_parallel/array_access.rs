@@ -0,0 +1,355 @@
+use super::bit_extractor::BitExtractor;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+use std::borrow::{Borrow, BorrowMut, Cow};
+
+impl ServerKey {
+    /// Returns a ciphertext that encrypts the same value as `ct` if `condition` encrypts 1,
+    /// and encrypts 0 if `condition` encrypts 0.
+    ///
+    /// This is the multiplication of `ct` by a boolean,
+    /// done with one bivariate PBS per block.
+    fn unchecked_zero_out_if_false_parallelized(
+        &self,
+        condition: &RadixCiphertext,
+        ct: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let condition_block = &condition.blocks[0];
+        let lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, condition| if condition == 1 { x } else { 0 });
+        let mut result = ct.clone();
+        result.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition_block, &lut);
+        });
+        result
+    }
+
+    /// Returns the bits of `index` that are needed to select among `len` elements,
+    /// each bit being a single block ciphertext encrypting 0 or 1,
+    /// ordered from the least significant to the most significant.
+    fn unchecked_index_bits_parallelized(
+        &self,
+        index: &RadixCiphertext,
+        len: usize,
+    ) -> Vec<RadixCiphertext> {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_bits = (usize::BITS - (len.max(2) - 1).leading_zeros()) as usize;
+        assert!(
+            num_bits <= index.blocks.len() * bits_per_block,
+            "The index does not have enough blocks to address {len} elements"
+        );
+
+        BitExtractor::new(self, bits_per_block)
+            .extract_n_bits(&index.blocks, num_bits)
+            .into_iter()
+            .map(|bit| RadixCiphertext::from(vec![bit]))
+            .collect()
+    }
+
+    /// Returns the ciphertext of `cts` at the encrypted position `index`.
+    ///
+    /// If `index` is out of bounds, the result encrypts 0.
+    ///
+    /// Each element is compared to the index (one scalar equality per element),
+    /// and multiplied by the result of the comparison, the products are then summed.
+    /// The cost is linear in the number of elements,
+    /// see [Self::unchecked_select_at_index_tree_parallelized] for large arrays.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - `cts` must not be empty, and all its ciphertexts must have the same number of blocks
+    pub fn unchecked_select_at_index_parallelized<T>(
+        &self,
+        cts: &[T],
+        index: &RadixCiphertext,
+    ) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        assert!(!cts.is_empty(), "Cannot select in an empty array");
+
+        let selected = cts
+            .par_iter()
+            .enumerate()
+            .map(|(i, ct)| {
+                let is_selected = self.unchecked_scalar_eq_parallelized(index, i as u64);
+                self.unchecked_zero_out_if_false_parallelized(&is_selected, ct.borrow())
+            })
+            .collect::<Vec<_>>();
+
+        // Only one of the terms is not zero, but the sum still
+        // has to take care of the degrees of the blocks
+        self.sum_ciphertexts_vec_parallelized(selected).unwrap()
+    }
+
+    /// Returns the ciphertext of `cts` at the encrypted position `index`.
+    ///
+    /// If `index` is out of bounds, the result encrypts 0.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [14u64, 97, 3, 42, 75];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(3u64);
+    ///
+    /// let ct_res = sks.select_at_index_parallelized(&cts, &index);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, clears[3]);
+    /// ```
+    pub fn select_at_index_parallelized<T>(
+        &self,
+        cts: &[T],
+        index: &RadixCiphertext,
+    ) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let (cts, index) = self.propagated_array_and_index(cts, index);
+        self.unchecked_select_at_index_parallelized(&cts, &index)
+    }
+
+    /// Returns the ciphertext of `cts` at the encrypted position `index`.
+    ///
+    /// If `index` is out of bounds, the result encrypts 0.
+    ///
+    /// The elements are selected with a tree of encrypted conditional selections
+    /// (see [Self::if_then_else_parallelized]) driven by the bits of the index:
+    /// this needs `len - 1` selections but only `log2(len)` of them are sequential,
+    /// and does not need one comparison per element,
+    /// which makes it better suited to large arrays than
+    /// [Self::unchecked_select_at_index_parallelized].
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - `cts` must not be empty, and all its ciphertexts must have the same number of blocks
+    pub fn unchecked_select_at_index_tree_parallelized<T>(
+        &self,
+        cts: &[T],
+        index: &RadixCiphertext,
+    ) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        assert!(!cts.is_empty(), "Cannot select in an empty array");
+
+        let (index_bits, is_in_bounds) = rayon::join(
+            || self.unchecked_index_bits_parallelized(index, cts.len()),
+            || self.unchecked_scalar_lt_parallelized(index, cts.len() as u64),
+        );
+
+        // Each level halves the number of candidates using one bit of the index,
+        // the last candidate of an odd level is paired with nothing and kept as is:
+        // it can only be wrongly selected if the index is out of bounds,
+        // which is handled at the end
+        let mut candidates = cts
+            .iter()
+            .map(|ct| Cow::Borrowed(ct.borrow()))
+            .collect::<Vec<_>>();
+        for bit in &index_bits {
+            candidates = candidates
+                .par_chunks(2)
+                .map(|pair| match pair {
+                    [if_zero, if_one] => {
+                        Cow::Owned(self.unchecked_if_then_else_parallelized(bit, if_one, if_zero))
+                    }
+                    [last] => last.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
+        }
+
+        let selected = candidates.pop().unwrap();
+        self.unchecked_zero_out_if_false_parallelized(&is_in_bounds, &selected)
+    }
+
+    /// Returns the ciphertext of `cts` at the encrypted position `index`,
+    /// using a tree of conditional selections.
+    ///
+    /// If `index` is out of bounds, the result encrypts 0.
+    ///
+    /// See [Self::unchecked_select_at_index_tree_parallelized].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [14u64, 97, 3, 42, 75];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(4u64);
+    ///
+    /// let ct_res = sks.select_at_index_tree_parallelized(&cts, &index);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, clears[4]);
+    /// ```
+    pub fn select_at_index_tree_parallelized<T>(
+        &self,
+        cts: &[T],
+        index: &RadixCiphertext,
+    ) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let (cts, index) = self.propagated_array_and_index(cts, index);
+        self.unchecked_select_at_index_tree_parallelized(&cts, &index)
+    }
+
+    /// Replaces the ciphertext of `cts` at the encrypted position `index` by `value`,
+    /// without revealing which element changed.
+    ///
+    /// If `index` is out of bounds, no element is modified.
+    ///
+    /// Every element is compared to the index, and replaced by an encrypted conditional
+    /// selection between itself and `value`, so all the ciphertexts are refreshed.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - `value` and the ciphertexts of `cts` must have the same number of blocks
+    pub fn unchecked_write_at_index_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &RadixCiphertext,
+        value: &RadixCiphertext,
+    ) where
+        T: BorrowMut<RadixCiphertext> + Send,
+    {
+        cts.par_iter_mut().enumerate().for_each(|(i, ct)| {
+            let ct = ct.borrow_mut();
+            let is_selected = self.unchecked_scalar_eq_parallelized(index, i as u64);
+            *ct = self.unchecked_if_then_else_parallelized(&is_selected, value, ct);
+        });
+    }
+
+    /// Replaces the ciphertext of `cts` at the encrypted position `index` by `value`,
+    /// without revealing which element changed.
+    ///
+    /// If `index` is out of bounds, no element is modified.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [14u64, 97, 3];
+    /// let mut cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    /// let index = cks.encrypt(1u64);
+    /// let value = cks.encrypt(200u64);
+    ///
+    /// sks.write_at_index_parallelized(&mut cts, &index, &value);
+    ///
+    /// // Decrypt:
+    /// let dec_result = cts.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(dec_result, vec![14, 200, 3]);
+    /// ```
+    pub fn write_at_index_parallelized<T>(
+        &self,
+        cts: &mut [T],
+        index: &RadixCiphertext,
+        value: &RadixCiphertext,
+    ) where
+        T: BorrowMut<RadixCiphertext> + Send,
+    {
+        cts.par_iter_mut()
+            .map(|ct| ct.borrow_mut())
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        let mut tmp_index;
+        let index = if index.block_carries_are_empty() {
+            index
+        } else {
+            tmp_index = index.clone();
+            self.full_propagate_parallelized(&mut tmp_index);
+            &tmp_index
+        };
+        let mut tmp_value;
+        let value = if value.block_carries_are_empty() {
+            value
+        } else {
+            tmp_value = value.clone();
+            self.full_propagate_parallelized(&mut tmp_value);
+            &tmp_value
+        };
+
+        self.unchecked_write_at_index_parallelized(cts, index, value);
+    }
+
+    /// Returns the array and the index with empty carries,
+    /// only the ciphertexts whose carries are not empty are copied to be propagated
+    fn propagated_array_and_index<'a, T>(
+        &self,
+        cts: &'a [T],
+        index: &'a RadixCiphertext,
+    ) -> (Vec<Cow<'a, RadixCiphertext>>, Cow<'a, RadixCiphertext>)
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        rayon::join(
+            || {
+                cts.par_iter()
+                    .map(|ct| self.propagated_radix(ct.borrow()))
+                    .collect()
+            },
+            || self.propagated_radix(index),
+        )
+    }
+
+    pub(super) fn propagated_radix<'a>(&self, ct: &'a RadixCiphertext) -> Cow<'a, RadixCiphertext> {
+        if ct.block_carries_are_empty() {
+            Cow::Borrowed(ct)
+        } else {
+            let mut ct = ct.clone();
+            self.full_propagate_parallelized(&mut ct);
+            Cow::Owned(ct)
+        }
+    }
+}
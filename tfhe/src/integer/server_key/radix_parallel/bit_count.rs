@@ -0,0 +1,402 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+#[derive(Copy, Clone)]
+enum Direction {
+    Trailing,
+    Leading,
+}
+
+impl ServerKey {
+    /// Sums the per-block counts into a radix ciphertext of `num_blocks` blocks
+    ///
+    /// Each count must be smaller than the message modulus.
+    fn sum_block_counts_parallelized(
+        &self,
+        counts: Vec<Ciphertext>,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let counts = counts
+            .into_par_iter()
+            .map(|count| {
+                let mut count = RadixCiphertext::from(vec![count]);
+                self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut count, num_blocks - 1);
+                count
+            })
+            .collect::<Vec<_>>();
+
+        self.sum_ciphertexts_vec_parallelized(counts)
+            .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks))
+    }
+
+    fn unchecked_count_bits_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        count_bits_in_block: impl Fn(u64) -> u64,
+    ) -> RadixCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let lut = self
+            .key
+            .generate_lookup_table(|x| count_bits_in_block(x % message_modulus));
+        let counts = ct
+            .blocks
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &lut))
+            .collect::<Vec<_>>();
+
+        self.sum_block_counts_parallelized(counts, ct.blocks.len())
+    }
+
+    fn unchecked_count_consecutive_zeros_parallelized(
+        &self,
+        ct: &RadixCiphertext,
+        direction: Direction,
+    ) -> RadixCiphertext {
+        let num_blocks = ct.blocks.len();
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let bits_per_block = message_modulus.ilog2();
+
+        // Blocks are ordered so that the counting starts from the first one
+        let blocks = match direction {
+            Direction::Trailing => ct.blocks.iter().collect::<Vec<_>>(),
+            Direction::Leading => ct.blocks.iter().rev().collect::<Vec<_>>(),
+        };
+
+        let is_zero_lut = self.key.generate_lookup_table(|x| u64::from(x == 0));
+        let mut all_zeros = blocks
+            .par_iter()
+            .map(|block| self.key.apply_lookup_table(block, &is_zero_lut))
+            .collect::<Vec<_>>();
+
+        // Hillis and Steele prefix scan, after which all_zeros[i] encrypts 1
+        // if and only if the blocks 0..=i are all zeros
+        let mut step = 1;
+        while step < num_blocks {
+            all_zeros = (0..num_blocks)
+                .into_par_iter()
+                .map(|i| {
+                    if i >= step {
+                        self.key.bitand(&all_zeros[i], &all_zeros[i - step])
+                    } else {
+                        all_zeros[i].clone()
+                    }
+                })
+                .collect();
+            step *= 2;
+        }
+
+        // The zeros of a block are only counted if all the previous blocks are zeros
+        let mut previous_are_zeros = all_zeros;
+        previous_are_zeros.pop();
+        previous_are_zeros.insert(0, self.key.create_trivial(1));
+
+        let count_lut = self
+            .key
+            .generate_lookup_table_bivariate(|x, previous_are_zeros| {
+                if previous_are_zeros == 0 {
+                    return 0;
+                }
+                let x = x % message_modulus;
+                let count = match direction {
+                    Direction::Trailing => x.trailing_zeros(),
+                    Direction::Leading => x.leading_zeros() - (u64::BITS - bits_per_block),
+                };
+                u64::from(count.min(bits_per_block))
+            });
+        let counts = blocks
+            .par_iter()
+            .zip(previous_are_zeros.par_iter())
+            .map(|(block, previous_are_zeros)| {
+                self.key.unchecked_apply_lookup_table_bivariate(
+                    block,
+                    previous_are_zeros,
+                    &count_lut,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        self.sum_block_counts_parallelized(counts, num_blocks)
+    }
+
+    /// Computes homomorphically the number of bits set to 1 in the ciphertext
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `ct` must be empty
+    pub fn unchecked_count_ones_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_bits_parallelized(ct, |x| u64::from(x.count_ones()))
+    }
+
+    /// Computes homomorphically the number of bits set to 0 in the ciphertext
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `ct` must be empty
+    pub fn unchecked_count_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let bits_per_block = u64::from(self.key.message_modulus.0.ilog2());
+        self.unchecked_count_bits_parallelized(ct, |x| bits_per_block - u64::from(x.count_ones()))
+    }
+
+    /// Computes homomorphically the number of consecutive bits set to 0,
+    /// starting from the least significant bit
+    ///
+    /// If the ciphertext encrypts 0, the result is the number of bits of the ciphertext.
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `ct` must be empty
+    pub fn unchecked_trailing_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_consecutive_zeros_parallelized(ct, Direction::Trailing)
+    }
+
+    /// Computes homomorphically the number of consecutive bits set to 0,
+    /// starting from the most significant bit
+    ///
+    /// If the ciphertext encrypts 0, the result is the number of bits of the ciphertext.
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `ct` must be empty
+    pub fn unchecked_leading_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        self.unchecked_count_consecutive_zeros_parallelized(ct, Direction::Leading)
+    }
+
+    /// Computes homomorphically the base 2 logarithm of the ciphertext, rounded down
+    ///
+    /// The logarithm of 0 is not defined, in that case the result
+    /// is the maximum value the ciphertext can represent.
+    /// The result has the same number of blocks as the input.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `ct` must be empty
+    pub fn unchecked_ilog2_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as u64;
+        let num_bits = bits_per_block * ct.blocks.len() as u64;
+
+        // ilog2(x) = num_bits - 1 - leading_zeros(x)
+        let leading_zeros = self.unchecked_leading_zeros_parallelized(ct);
+        let mut result = self.neg_parallelized(&leading_zeros);
+        self.scalar_add_assign_parallelized(&mut result, num_bits - 1);
+        result
+    }
+
+    /// Computes homomorphically the number of bits set to 1 in the ciphertext
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b0110_1101u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.count_ones_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.count_ones());
+    /// ```
+    pub fn count_ones_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_count_ones_parallelized(ct)
+    }
+
+    /// Computes homomorphically the number of bits set to 0 in the ciphertext
+    ///
+    /// The result has the same number of blocks as the input.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b0110_1101u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.count_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.count_zeros());
+    /// ```
+    pub fn count_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_count_zeros_parallelized(ct)
+    }
+
+    /// Computes homomorphically the number of consecutive bits set to 0,
+    /// starting from the least significant bit
+    ///
+    /// If the ciphertext encrypts 0, the result is the number of bits of the ciphertext.
+    /// The result has the same number of blocks as the input.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b0110_1000u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.trailing_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.trailing_zeros());
+    /// ```
+    pub fn trailing_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_trailing_zeros_parallelized(ct)
+    }
+
+    /// Computes homomorphically the number of consecutive bits set to 0,
+    /// starting from the most significant bit
+    ///
+    /// If the ciphertext encrypts 0, the result is the number of bits of the ciphertext.
+    /// The result has the same number of blocks as the input.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b0001_0110u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.leading_zeros_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.leading_zeros());
+    /// ```
+    pub fn leading_zeros_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_leading_zeros_parallelized(ct)
+    }
+
+    /// Computes homomorphically the base 2 logarithm of the ciphertext, rounded down
+    ///
+    /// The logarithm of 0 is not defined, in that case the result
+    /// is the maximum value the ciphertext can represent.
+    /// The result has the same number of blocks as the input.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 97u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.ilog2_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u32 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.ilog2());
+    /// ```
+    pub fn ilog2_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_ilog2_parallelized(ct)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::integer::ServerKey;
+use crate::integer::{RadixCiphertext, ServerKey};
 use crate::shortint::server_key::LookupTableOwned;
 use crate::shortint::Ciphertext;
 use itertools::iproduct;
@@ -46,3 +46,139 @@ impl<'a> BitExtractor<'a> {
         bits
     }
 }
+
+impl ServerKey {
+    /// Extracts the bits of the ciphertext
+    ///
+    /// Each bit is returned as a single block encrypting 0 or 1,
+    /// ordered from the least significant bit to the most significant one.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `ct` must be empty
+    pub fn unchecked_extract_bits_parallelized(&self, ct: &RadixCiphertext) -> Vec<Ciphertext> {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        BitExtractor::new(self, bits_per_block).extract_all_bits(&ct.blocks)
+    }
+
+    /// Extracts the bits of the ciphertext
+    ///
+    /// Each bit is returned as a single block encrypting 0 or 1,
+    /// ordered from the least significant bit to the most significant one.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1001_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let bits = sks.extract_bits_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_bits = bits
+    ///     .iter()
+    ///     .map(|bit| cks.decrypt_one_block(bit))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(dec_bits, vec![0, 1, 1, 0, 1, 0, 0, 1]);
+    /// ```
+    pub fn extract_bits_parallelized(&self, ct: &RadixCiphertext) -> Vec<Ciphertext> {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_extract_bits_parallelized(ct)
+    }
+
+    /// Packs encrypted bits into a radix ciphertext
+    ///
+    /// The bits are ordered from the least significant bit to the most significant one,
+    /// the result has as many blocks as needed to hold all of them.
+    ///
+    /// # Requirements
+    ///
+    /// - each of the `bits` must encrypt either 0 or 1 and have a degree of at most 1
+    pub fn unchecked_pack_bits_parallelized(&self, bits: &[Ciphertext]) -> RadixCiphertext {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+
+        let blocks = bits
+            .par_chunks(bits_per_block)
+            .map(|block_bits| {
+                let mut block = block_bits[0].clone();
+                for (i, bit) in block_bits.iter().enumerate().skip(1) {
+                    let shifted_bit = self.key.unchecked_scalar_mul(bit, 1 << i);
+                    self.key.unchecked_add_assign(&mut block, &shifted_bit);
+                }
+                // Cleans the noise accumulated by the weighted sum
+                self.key.message_extract_assign(&mut block);
+                block
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Packs encrypted bits into a radix ciphertext
+    ///
+    /// The bits are ordered from the least significant bit to the most significant one,
+    /// the result has as many blocks as needed to hold all of them.
+    ///
+    /// This is the inverse of [Self::extract_bits_parallelized].
+    ///
+    /// # Panics
+    ///
+    /// Panics if one of the `bits` has a degree greater than 1.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 0b1001_0110u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// let mut bits = sks.extract_bits_parallelized(&ct);
+    /// bits.reverse();
+    ///
+    /// let ct_res = sks.pack_bits_parallelized(&bits);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 0b0110_1001);
+    /// ```
+    pub fn pack_bits_parallelized(&self, bits: &[Ciphertext]) -> RadixCiphertext {
+        assert!(
+            bits.iter().all(|bit| bit.degree.0 <= 1),
+            "The ciphertexts to pack must be encrypted bits"
+        );
+        self.unchecked_pack_bits_parallelized(bits)
+    }
+}
@@ -0,0 +1,216 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::Ciphertext;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Encrypted conditional selection between two radix ciphertexts.
+    ///
+    /// Returns `true_ct` if `condition` encrypts 1, `false_ct` if it encrypts 0.
+    ///
+    /// # Requirements
+    ///
+    /// - `condition` must encrypt either 0 or 1, only its first block is used
+    /// - the carries of `condition`, `true_ct` and `false_ct` must be empty
+    /// - `true_ct` and `false_ct` must have the same number of blocks
+    ///
+    /// # Output
+    ///
+    /// - The output's carries will be empty
+    pub fn unchecked_if_then_else_parallelized(
+        &self,
+        condition: &RadixCiphertext,
+        true_ct: &RadixCiphertext,
+        false_ct: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        assert_eq!(
+            true_ct.blocks.len(),
+            false_ct.blocks.len(),
+            "Both possible results must have the same number of blocks"
+        );
+        let condition_block = &condition.blocks[0];
+
+        // Each block is kept if the condition selects its ciphertext and zeroed otherwise,
+        // so that adding the two resulting ciphertexts does not create any carry
+        let zero_out_if = |ct: &RadixCiphertext, selecting_value: u64| {
+            let lut = self.key.generate_lookup_table_bivariate(|x, condition| {
+                if condition == selecting_value {
+                    x
+                } else {
+                    0
+                }
+            });
+            let mut result = ct.clone();
+            result.blocks.par_iter_mut().for_each(|block| {
+                self.key.unchecked_apply_lookup_table_bivariate_assign(
+                    block,
+                    condition_block,
+                    &lut,
+                );
+            });
+            result
+        };
+
+        let (mut result, false_ct) =
+            rayon::join(|| zero_out_if(true_ct, 1), || zero_out_if(false_ct, 0));
+
+        for (result_block, false_block) in result.blocks.iter_mut().zip(false_ct.blocks.iter()) {
+            // One of the two blocks is a zero, so the degree is the maximum,
+            // not the sum of the two degrees
+            let degree = Degree(result_block.degree.0.max(false_block.degree.0));
+            self.key.unchecked_add_assign(result_block, false_block);
+            result_block.degree = degree;
+        }
+
+        result
+    }
+
+    /// Encrypted conditional selection between two radix ciphertexts.
+    ///
+    /// Returns `true_ct` if `condition` encrypts 1, `false_ct` if it encrypts 0.
+    /// `condition` must encrypt either 0 or 1.
+    ///
+    /// The carries of the inputs are propagated in place if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    ///
+    /// let mut ct1 = cks.encrypt(msg1);
+    /// let mut ct2 = cks.encrypt(msg2);
+    /// let mut condition = sks.gt_parallelized(&ct1, &ct2);
+    ///
+    /// let ct_res = sks.smart_if_then_else_parallelized(&mut condition, &mut ct1, &mut ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, if msg1 > msg2 { msg1 } else { msg2 });
+    /// ```
+    pub fn smart_if_then_else_parallelized(
+        &self,
+        condition: &mut RadixCiphertext,
+        true_ct: &mut RadixCiphertext,
+        false_ct: &mut RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut ciphertexts = [condition, true_ct, false_ct];
+        ciphertexts
+            .par_iter_mut()
+            .filter(|ct| !ct.block_carries_are_empty())
+            .for_each(|ct| self.full_propagate_parallelized(ct));
+
+        let [condition, true_ct, false_ct] = ciphertexts;
+        self.unchecked_if_then_else_parallelized(condition, true_ct, false_ct)
+    }
+
+    /// Encrypted conditional selection between two radix ciphertexts.
+    ///
+    /// Returns `true_ct` if `condition` encrypts 1, `false_ct` if it encrypts 0.
+    /// `condition` must encrypt either 0 or 1, as the results of comparisons do.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 14u64;
+    /// let msg2 = 97u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    /// let condition = sks.lt_parallelized(&ct1, &ct2);
+    ///
+    /// let ct_res = sks.if_then_else_parallelized(&condition, &ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, if msg1 < msg2 { msg1 } else { msg2 });
+    /// ```
+    pub fn if_then_else_parallelized(
+        &self,
+        condition: &RadixCiphertext,
+        true_ct: &RadixCiphertext,
+        false_ct: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut tmp_condition;
+        let mut tmp_true_ct;
+        let mut tmp_false_ct;
+
+        let condition = if condition.block_carries_are_empty() {
+            condition
+        } else {
+            tmp_condition = condition.clone();
+            self.full_propagate_parallelized(&mut tmp_condition);
+            &tmp_condition
+        };
+
+        let true_ct = if true_ct.block_carries_are_empty() {
+            true_ct
+        } else {
+            tmp_true_ct = true_ct.clone();
+            self.full_propagate_parallelized(&mut tmp_true_ct);
+            &tmp_true_ct
+        };
+
+        let false_ct = if false_ct.block_carries_are_empty() {
+            false_ct
+        } else {
+            tmp_false_ct = false_ct.clone();
+            self.full_propagate_parallelized(&mut tmp_false_ct);
+            &tmp_false_ct
+        };
+
+        self.unchecked_if_then_else_parallelized(condition, true_ct, false_ct)
+    }
+
+    /// Replaces every block of `ct` by a trivial `block_value` if `condition` encrypts 1,
+    /// leaves `ct` unchanged if it encrypts 0.
+    ///
+    /// This is used to clamp the result of an operation which overflowed,
+    /// `condition` being the overflow flag of the operation.
+    ///
+    /// # Requirements
+    ///
+    /// - `condition` must encrypt either 0 or 1
+    /// - the carries of `ct` must be empty
+    pub(crate) fn unchecked_set_blocks_if_assign_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        condition: &Ciphertext,
+        block_value: u64,
+    ) {
+        let lut =
+            self.key.generate_lookup_table_bivariate(
+                |x, condition| {
+                    if condition == 1 {
+                        block_value
+                    } else {
+                        x
+                    }
+                },
+            );
+        ct.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition, &lut);
+        });
+    }
+}
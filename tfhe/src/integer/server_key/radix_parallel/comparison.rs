@@ -2,6 +2,7 @@ use super::ServerKey;
 
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::server_key::comparator::Comparator;
+use crate::shortint::Ciphertext;
 
 use rayon::prelude::*;
 
@@ -11,6 +12,21 @@ impl ServerKey {
         lhs: &RadixCiphertext,
         rhs: &RadixCiphertext,
     ) -> RadixCiphertext {
+        let is_equal_result = self.unchecked_eq_block_parallelized(lhs, rhs);
+
+        let mut blocks = Vec::with_capacity(lhs.blocks.len());
+        blocks.push(is_equal_result);
+        blocks.resize_with(lhs.blocks.len(), || self.key.create_trivial(0));
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Same as [Self::unchecked_eq_parallelized], but returns the result as a single block
+    pub(crate) fn unchecked_eq_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
         // Even though the corresponding function
         // may already exist in self.key
         // we generate our own lut to do less allocations
@@ -27,20 +43,29 @@ impl ServerKey {
                     .unchecked_apply_lookup_table_bivariate_assign(lhs_block, rhs_block, &lut);
             });
 
-        let is_equal_result = self.are_all_comparisons_block_true(block_comparisons);
+        self.are_all_comparisons_block_true(block_comparisons)
+    }
+
+    pub fn unchecked_ne_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let is_not_equal_result = self.unchecked_ne_block_parallelized(lhs, rhs);
 
         let mut blocks = Vec::with_capacity(lhs.blocks.len());
-        blocks.push(is_equal_result);
+        blocks.push(is_not_equal_result);
         blocks.resize_with(lhs.blocks.len(), || self.key.create_trivial(0));
 
         RadixCiphertext { blocks }
     }
 
-    pub fn unchecked_ne_parallelized(
+    /// Same as [Self::unchecked_ne_parallelized], but returns the result as a single block
+    pub(crate) fn unchecked_ne_block_parallelized(
         &self,
         lhs: &RadixCiphertext,
         rhs: &RadixCiphertext,
-    ) -> RadixCiphertext {
+    ) -> Ciphertext {
         // Even though the corresponding function
         // may already exist in self.key
         // we generate our own lut to do less allocations
@@ -79,11 +104,7 @@ impl ServerKey {
             std::mem::swap(&mut block_comparisons_2, &mut block_comparisons);
         }
 
-        block_comparisons.resize_with(lhs.blocks.len(), || self.key.create_trivial(0));
-
-        RadixCiphertext {
-            blocks: block_comparisons,
-        }
+        block_comparisons.pop().unwrap()
     }
 
     pub fn unchecked_gt_parallelized(
@@ -223,35 +244,55 @@ impl ServerKey {
     }
 
     pub fn eq_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
-        let mut tmp_lhs: RadixCiphertext;
-        let mut tmp_rhs: RadixCiphertext;
-        let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
-            (true, true) => (lhs, rhs),
-            (true, false) => {
-                tmp_rhs = rhs.clone();
-                self.full_propagate_parallelized(&mut tmp_rhs);
-                (lhs, &tmp_rhs)
-            }
-            (false, true) => {
-                tmp_lhs = lhs.clone();
-                self.full_propagate_parallelized(&mut tmp_lhs);
-                (&tmp_lhs, rhs)
-            }
-            (false, false) => {
-                tmp_lhs = lhs.clone();
-                tmp_rhs = rhs.clone();
-                rayon::join(
-                    || self.full_propagate_parallelized(&mut tmp_lhs),
-                    || self.full_propagate_parallelized(&mut tmp_rhs),
-                );
-                (&tmp_lhs, &tmp_rhs)
-            }
-        };
-
-        self.unchecked_eq_parallelized(lhs, rhs)
+        self.with_propagated_carries_parallelized(lhs, rhs, Self::unchecked_eq_parallelized)
     }
 
     pub fn ne_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        self.with_propagated_carries_parallelized(lhs, rhs, Self::unchecked_ne_parallelized)
+    }
+
+    pub fn gt_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        Comparator::new(self).gt_parallelized(lhs, rhs)
+    }
+
+    pub fn ge_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        Comparator::new(self).ge_parallelized(lhs, rhs)
+    }
+
+    pub fn lt_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        Comparator::new(self).lt_parallelized(lhs, rhs)
+    }
+
+    pub fn le_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
+        Comparator::new(self).le_parallelized(lhs, rhs)
+    }
+
+    pub fn max_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).max_parallelized(lhs, rhs)
+    }
+
+    pub fn min_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        Comparator::new(self).min_parallelized(lhs, rhs)
+    }
+
+    /// Calls `f` on `lhs` and `rhs`, after propagating their carries if they are not empty
+    fn with_propagated_carries_parallelized<R, F>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        f: F,
+    ) -> R
+    where
+        F: FnOnce(&Self, &RadixCiphertext, &RadixCiphertext) -> R,
+    {
         let mut tmp_lhs: RadixCiphertext;
         let mut tmp_rhs: RadixCiphertext;
         let (lhs, rhs) = match (lhs.block_carries_are_empty(), rhs.block_carries_are_empty()) {
@@ -277,38 +318,72 @@ impl ServerKey {
             }
         };
 
-        self.unchecked_ne_parallelized(lhs, rhs)
+        f(self, lhs, rhs)
     }
 
-    pub fn gt_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
-        Comparator::new(self).gt_parallelized(lhs, rhs)
+    //===========================================================
+    // Default ==, !=, <, >, <=, >= returning a single block
+    //===========================================================
+
+    /// Same as [Self::eq_parallelized], but returns the result as a single block
+    pub(crate) fn eq_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.with_propagated_carries_parallelized(lhs, rhs, Self::unchecked_eq_block_parallelized)
     }
 
-    pub fn ge_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
-        Comparator::new(self).ge_parallelized(lhs, rhs)
+    /// Same as [Self::ne_parallelized], but returns the result as a single block
+    pub(crate) fn ne_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.with_propagated_carries_parallelized(lhs, rhs, Self::unchecked_ne_block_parallelized)
     }
 
-    pub fn lt_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
-        Comparator::new(self).lt_parallelized(lhs, rhs)
+    /// Same as [Self::gt_parallelized], but returns the result as a single block
+    pub(crate) fn gt_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.with_propagated_carries_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_gt_block_parallelized(lhs, rhs)
+        })
     }
 
-    pub fn le_parallelized(&self, lhs: &RadixCiphertext, rhs: &RadixCiphertext) -> RadixCiphertext {
-        Comparator::new(self).le_parallelized(lhs, rhs)
+    /// Same as [Self::ge_parallelized], but returns the result as a single block
+    pub(crate) fn ge_block_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+    ) -> Ciphertext {
+        self.with_propagated_carries_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_ge_block_parallelized(lhs, rhs)
+        })
     }
 
-    pub fn max_parallelized(
+    /// Same as [Self::lt_parallelized], but returns the result as a single block
+    pub(crate) fn lt_block_parallelized(
         &self,
         lhs: &RadixCiphertext,
         rhs: &RadixCiphertext,
-    ) -> RadixCiphertext {
-        Comparator::new(self).max_parallelized(lhs, rhs)
+    ) -> Ciphertext {
+        self.with_propagated_carries_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_lt_block_parallelized(lhs, rhs)
+        })
     }
 
-    pub fn min_parallelized(
+    /// Same as [Self::le_parallelized], but returns the result as a single block
+    pub(crate) fn le_block_parallelized(
         &self,
         lhs: &RadixCiphertext,
         rhs: &RadixCiphertext,
-    ) -> RadixCiphertext {
-        Comparator::new(self).min_parallelized(lhs, rhs)
+    ) -> Ciphertext {
+        self.with_propagated_carries_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_le_block_parallelized(lhs, rhs)
+        })
     }
 }
@@ -0,0 +1,113 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Computes homomorphically the dot product between encrypted values and clear weights,
+    /// that is the sum of each ciphertext multiplied by its weight.
+    ///
+    /// The products are not computed one after the other: the terms of all the
+    /// scalar multiplications are summed at once, so that carries are only propagated
+    /// when needed.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - `cts` and `weights` must have the same length, which must not be zero
+    /// - all the ciphertexts must have the same number of blocks
+    pub fn unchecked_dot_product_with_clear_parallelized(
+        &self,
+        cts: &[RadixCiphertext],
+        weights: &[u64],
+    ) -> RadixCiphertext {
+        assert_eq!(
+            cts.len(),
+            weights.len(),
+            "There must be as many weights as there are ciphertexts"
+        );
+        assert!(
+            !cts.is_empty(),
+            "The dot product requires at least one ciphertext"
+        );
+        let num_blocks = cts[0].blocks.len();
+        assert!(
+            cts.iter().all(|ct| ct.blocks.len() == num_blocks),
+            "All ciphertexts must have the same number of blocks"
+        );
+
+        let terms = cts
+            .par_iter()
+            .zip(weights.par_iter())
+            .flat_map_iter(|(ct, &weight)| self.unchecked_scalar_mul_terms_parallelized(ct, weight))
+            .collect::<Vec<_>>();
+
+        let mut result = self.create_trivial_zero_radix(num_blocks);
+        self.sum_multiplication_terms_into(&mut result, terms);
+        result
+    }
+
+    /// Computes homomorphically the dot product between encrypted values and clear weights,
+    /// that is the sum of each ciphertext multiplied by its weight.
+    ///
+    /// The products are not computed one after the other: the terms of all the
+    /// scalar multiplications are summed at once, so that carries are only propagated
+    /// when needed.
+    ///
+    /// `cts` and `weights` must have the same length, which must not be zero,
+    /// and all the ciphertexts must have the same number of blocks.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [14u64, 9, 3];
+    /// let weights = [3u64, 0, 7];
+    ///
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.dot_product_with_clear_parallelized(&cts, &weights);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 14 * 3 + 3 * 7);
+    /// ```
+    pub fn dot_product_with_clear_parallelized(
+        &self,
+        cts: &[RadixCiphertext],
+        weights: &[u64],
+    ) -> RadixCiphertext {
+        if cts.iter().all(RadixCiphertext::block_carries_are_empty) {
+            return self.unchecked_dot_product_with_clear_parallelized(cts, weights);
+        }
+
+        let cts = cts
+            .par_iter()
+            .map(|ct| {
+                let mut ct = ct.clone();
+                if !ct.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct);
+                }
+                ct
+            })
+            .collect::<Vec<_>>();
+        self.unchecked_dot_product_with_clear_parallelized(&cts, weights)
+    }
+}
@@ -1,10 +1,17 @@
 mod add;
+mod array_access;
+mod bit_count;
 mod bit_extractor;
 mod bitwise_op;
+mod cmux;
 mod comparison;
 mod div_mod;
+mod dot_product;
+mod modular;
 mod mul;
 mod neg;
+mod oprf;
+mod pow;
 mod rotate;
 mod scalar_add;
 mod scalar_bitwise_op;
@@ -15,6 +22,10 @@ mod scalar_rotate;
 mod scalar_shift;
 mod scalar_sub;
 mod shift;
+mod signed_comparison;
+mod signed_ops;
+mod sort;
+mod sqrt;
 mod sub;
 
 #[cfg(test)]
@@ -0,0 +1,484 @@
+//! Modular arithmetic over a clear modulus
+//!
+//! The operations of this module compute over the integers modulo a clear `modulus`,
+//! values are represented by radix ciphertexts encrypting an integer in `[0, modulus)`.
+use super::bit_extractor::BitExtractor;
+use crate::core_crypto::prelude::CastFrom;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::{ServerKey, U256};
+
+impl ServerKey {
+    fn assert_modulus_is_valid(&self, modulus: U256, num_blocks: usize) {
+        assert_ne!(modulus, U256::ZERO, "The modulus must not be zero");
+        let num_bits = self.key.message_modulus.0.ilog2() * num_blocks as u32;
+        assert!(
+            U256::BITS - (modulus - U256::ONE).leading_zeros() <= num_bits,
+            "The values modulo {modulus:?} do not fit in a ciphertext of {num_bits} bits"
+        );
+    }
+
+    /// Reduces `ct` modulo `modulus`
+    ///
+    /// `ct` must have empty carries.
+    fn reduce_mod_parallelized(&self, ct: &RadixCiphertext, modulus: U256) -> RadixCiphertext {
+        let num_bits = self.key.message_modulus.0.ilog2() * ct.blocks.len() as u32;
+
+        if modulus.is_power_of_two() {
+            self.scalar_bitand_parallelized(ct, modulus - U256::ONE)
+        } else if num_bits <= u64::BITS && modulus <= U256::from(u64::MAX) {
+            self.unchecked_scalar_rem_parallelized(ct, u64::cast_from(modulus))
+        } else {
+            // The scalar division only handles ciphertexts up to 64 bits
+            let divisor = self.create_trivial_radix(modulus, ct.blocks.len());
+            let (_, remainder) = self.div_rem_parallelized(ct, &divisor);
+            remainder
+        }
+    }
+
+    fn reduce_inputs_mod_parallelized(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: U256,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        let reduce = |ct: &RadixCiphertext| {
+            let mut ct = ct.clone();
+            if !ct.block_carries_are_empty() {
+                self.full_propagate_parallelized(&mut ct);
+            }
+            self.reduce_mod_parallelized(&ct, modulus)
+        };
+        rayon::join(|| reduce(lhs), || reduce(rhs))
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus`
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `lhs` and `rhs` must be empty
+    /// - `lhs` and `rhs` must be smaller than `modulus`
+    pub fn unchecked_add_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        self.assert_modulus_is_valid(modulus, lhs.blocks.len());
+
+        // lhs + rhs < 2 * modulus, so at most one subtraction of modulus is needed.
+        // The sum may not fit in the ciphertext, in which case it is greater than modulus
+        let (sum, overflowed) = self.overflowing_add_parallelized(lhs, rhs);
+        let (is_greater_or_equal, reduced_sum) = rayon::join(
+            || self.scalar_ge_parallelized(&sum, modulus),
+            // Subtracting modulus is adding its two's complement
+            || self.scalar_add_parallelized(&sum, (!modulus) + U256::ONE),
+        );
+
+        let condition = self.key.bitor(&overflowed, &is_greater_or_equal.blocks[0]);
+        let condition = RadixCiphertext::from(vec![condition]);
+        self.if_then_else_parallelized(&condition, &reduced_sum, &sum)
+    }
+
+    /// Computes homomorphically `(lhs + rhs) % modulus`
+    ///
+    /// The inputs are reduced modulo `modulus` beforehand, if they are known to already
+    /// be reduced, [Self::unchecked_add_mod_parallelized] is faster.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if values modulo `modulus` do not fit in `lhs`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    /// let msg1 = 200u64;
+    /// let msg2 = 97u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.add_mod_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, (msg1 + msg2) % modulus);
+    /// ```
+    pub fn add_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        self.assert_modulus_is_valid(modulus, lhs.blocks.len());
+        let (lhs, rhs) = self.reduce_inputs_mod_parallelized(lhs, rhs, modulus);
+        self.unchecked_add_mod_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Computes homomorphically `(lhs - rhs) % modulus`, the result being in `[0, modulus)`
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `lhs` and `rhs` must be empty
+    /// - `lhs` and `rhs` must be smaller than `modulus`
+    pub fn unchecked_sub_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        self.assert_modulus_is_valid(modulus, lhs.blocks.len());
+
+        // If the subtraction underflowed, adding modulus (wrapping)
+        // brings the difference back in the range
+        let (difference, underflowed) = self.overflowing_sub_parallelized(lhs, rhs);
+        let corrected_difference = self.scalar_add_parallelized(&difference, modulus);
+
+        let condition = RadixCiphertext::from(vec![underflowed]);
+        self.if_then_else_parallelized(&condition, &corrected_difference, &difference)
+    }
+
+    /// Computes homomorphically `(lhs - rhs) % modulus`, the result being in `[0, modulus)`
+    ///
+    /// The inputs are reduced modulo `modulus` beforehand, if they are known to already
+    /// be reduced, [Self::unchecked_sub_mod_parallelized] is faster.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if values modulo `modulus` do not fit in `lhs`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    /// let msg1 = 97u64;
+    /// let msg2 = 200u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.sub_mod_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, (msg1 + modulus - msg2) % modulus);
+    /// ```
+    pub fn sub_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        self.assert_modulus_is_valid(modulus, lhs.blocks.len());
+        let (lhs, rhs) = self.reduce_inputs_mod_parallelized(lhs, rhs, modulus);
+        self.unchecked_sub_mod_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus`
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `lhs` and `rhs` must be empty
+    /// - `lhs` and `rhs` must be smaller than `modulus`
+    pub fn unchecked_mul_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        let num_blocks = lhs.blocks.len();
+        self.assert_modulus_is_valid(modulus, num_blocks);
+
+        if modulus.is_power_of_two() {
+            let product = self.mul_parallelized(lhs, rhs);
+            return self.scalar_bitand_parallelized(&product, modulus - U256::ONE);
+        }
+
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let value_bits = U256::BITS - (modulus - U256::ONE).leading_zeros();
+        let value_blocks = ((value_bits + bits_per_block - 1) / bits_per_block) as usize;
+
+        if 2 * value_blocks as u32 * bits_per_block <= u64::BITS {
+            // The full product fits in 64 bits, so it can be reduced
+            // with a scalar division, which is much cheaper than an encrypted one.
+            let to_product_size = |ct: &RadixCiphertext| {
+                let mut ct = ct.clone();
+                self.trim_radix_blocks_msb_assign(&mut ct, num_blocks - value_blocks);
+                self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut ct, value_blocks);
+                ct
+            };
+            let product = self.mul_parallelized(&to_product_size(lhs), &to_product_size(rhs));
+            let mut result =
+                self.unchecked_scalar_rem_parallelized(&product, u64::cast_from(modulus));
+            self.trim_radix_blocks_msb_assign(&mut result, value_blocks);
+            self.extend_radix_with_trivial_zero_blocks_msb_assign(
+                &mut result,
+                num_blocks - value_blocks,
+            );
+            return result;
+        }
+
+        // Interleaved multiplication: the bits of rhs are processed from the most significant,
+        // the result is doubled and lhs added to it if the bit is set, everything modulo modulus.
+        let rhs_bits = BitExtractor::new(self, bits_per_block as usize)
+            .extract_n_bits(&rhs.blocks, value_bits as usize);
+        let zero = self.create_trivial_zero_radix(num_blocks);
+        let mut result: Option<RadixCiphertext> = None;
+        for rhs_bit in rhs_bits.into_iter().rev() {
+            let condition = RadixCiphertext::from(vec![rhs_bit]);
+            let (doubled, term) = rayon::join(
+                || {
+                    result
+                        .as_ref()
+                        .map(|result| self.unchecked_add_mod_parallelized(result, result, modulus))
+                },
+                || self.if_then_else_parallelized(&condition, lhs, &zero),
+            );
+            result = Some(match doubled {
+                Some(doubled) => self.unchecked_add_mod_parallelized(&doubled, &term, modulus),
+                None => term,
+            });
+        }
+
+        result.unwrap_or(zero)
+    }
+
+    /// Computes homomorphically `(lhs * rhs) % modulus`
+    ///
+    /// The inputs are reduced modulo `modulus` beforehand, if they are known to already
+    /// be reduced, [Self::unchecked_mul_mod_parallelized] is faster.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if values modulo `modulus` do not fit in `lhs`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    /// let msg1 = 200u64;
+    /// let msg2 = 97u64;
+    ///
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// let ct_res = sks.mul_mod_parallelized(&ct1, &ct2, modulus);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, (msg1 * msg2) % modulus);
+    /// ```
+    pub fn mul_mod_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        self.assert_modulus_is_valid(modulus, lhs.blocks.len());
+        let (lhs, rhs) = self.reduce_inputs_mod_parallelized(lhs, rhs, modulus);
+        self.unchecked_mul_mod_parallelized(&lhs, &rhs, modulus)
+    }
+
+    /// Computes homomorphically `base.pow(exponent) % modulus`
+    ///
+    /// `exponent` is not reduced and may have a different number of blocks than `base`.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `base` and `exponent` must be empty
+    /// - `base` must be smaller than `modulus`
+    pub fn unchecked_pow_mod_parallelized<T>(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        let num_blocks = base.blocks.len();
+        self.assert_modulus_is_valid(modulus, num_blocks);
+
+        if modulus == U256::ONE {
+            return self.create_trivial_zero_radix(num_blocks);
+        }
+
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let exponent_bits =
+            BitExtractor::new(self, bits_per_block).extract_all_bits(&exponent.blocks);
+
+        let one = self.create_trivial_radix(1u64, num_blocks);
+        let mut base = base.clone();
+        let mut result: Option<RadixCiphertext> = None;
+        for (i, exponent_bit) in exponent_bits.iter().enumerate() {
+            // Same square-and-multiply as pow_parallelized
+            let condition = RadixCiphertext::from(vec![exponent_bit.clone()]);
+            let factor = self.if_then_else_parallelized(&condition, &base, &one);
+
+            let is_last_bit = i == exponent_bits.len() - 1;
+            let (new_result, squared_base) = rayon::join(
+                || match &result {
+                    Some(result) => self.unchecked_mul_mod_parallelized(result, &factor, modulus),
+                    None => factor.clone(),
+                },
+                || {
+                    (!is_last_bit)
+                        .then(|| self.unchecked_mul_mod_parallelized(&base, &base, modulus))
+                },
+            );
+            result = Some(new_result);
+            if let Some(squared_base) = squared_base {
+                base = squared_base;
+            }
+        }
+
+        result.unwrap_or(one)
+    }
+
+    /// Computes homomorphically `base.pow(exponent) % modulus`
+    ///
+    /// `base` is reduced modulo `modulus` beforehand. `exponent` is not reduced and
+    /// may have a different number of blocks than `base`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus is zero, or if values modulo `modulus` do not fit in `base`.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let modulus = 251u64;
+    /// let msg = 200u64;
+    /// let exponent = 11u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    /// // A 2 blocks exponent, its value must be smaller than 16
+    /// let ct_exponent = cks.as_ref().encrypt_radix(exponent, 2);
+    ///
+    /// let ct_res = sks.pow_mod_parallelized(&ct, &ct_exponent, modulus);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// let expected = (0..exponent).fold(1, |acc, _| (acc * msg) % modulus);
+    /// assert_eq!(dec_result, expected);
+    /// ```
+    pub fn pow_mod_parallelized<T>(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+        modulus: T,
+    ) -> RadixCiphertext
+    where
+        T: Into<U256>,
+    {
+        let modulus = modulus.into();
+        self.assert_modulus_is_valid(modulus, base.blocks.len());
+
+        let (base, exponent) = rayon::join(
+            || {
+                let mut base = base.clone();
+                if !base.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut base);
+                }
+                self.reduce_mod_parallelized(&base, modulus)
+            },
+            || {
+                let mut exponent = exponent.clone();
+                if !exponent.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut exponent);
+                }
+                exponent
+            },
+        );
+        self.unchecked_pow_mod_parallelized(&base, &exponent, modulus)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
 use rayon::prelude::*;
 
 impl ServerKey {
@@ -277,7 +278,8 @@ impl ServerKey {
     /// This sums all of the terms in `terms` and overwrites
     /// `lhs` with the result.
     ///
-    /// Each of the input term is expected to have _at most_ one carry consumed
+    /// Terms are added without propagating carries as long as the degree of their
+    /// blocks allows it, the carries are then propagated and the process repeats.
     pub(crate) fn sum_multiplication_terms_into(
         &self,
         lhs: &mut RadixCiphertext,
@@ -292,28 +294,18 @@ impl ServerKey {
 
         let num_blocks = lhs.blocks.len();
 
-        let num_bits_in_carry = self.key.carry_modulus.0.ilog2() as u64;
-        // Among those bits of carry, we know one is already consumed by
-        // the last step of the unchecked_block_mul_parallelized
-        // for each of the term.
-        //
-        // It means we can only do num_bits_in_carry - 1 additions
-        // Which then means we can iter on chunks of num_bits_in_carry
-        // to add them up as it will result in num_bits_in_carry - 1 additions
-        //
-        // If we have only one bit of carry, it is consumed
-        // and thus the faster algorithm is not possible
-        // so we use another one that still works
-        if num_bits_in_carry == 1 {
-            *lhs = self
-                .smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
-                .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks));
-
-            self.full_propagate_parallelized(lhs);
-            return;
-        }
-
-        let chunk_size = num_bits_in_carry as usize;
+        let max_sum_degree = self.key.message_modulus.0 * self.key.carry_modulus.0 - 1;
+        // The number of terms that can be added together without propagating carries,
+        // given the degree of their blocks
+        let max_terms_in_chunk = |terms: &[RadixCiphertext]| {
+            let max_block_degree = terms
+                .iter()
+                .flat_map(|term| term.blocks.iter())
+                .map(|block| block.degree.0)
+                .max()
+                .unwrap_or(0);
+            max_sum_degree / max_block_degree.max(1)
+        };
 
         // For the last chunk, we want to finish it off
         // using an addition that does not leave the resulting ciphertext with
@@ -331,7 +323,22 @@ impl ServerKey {
         //
         // As we want to handle the last chunk separately
         // only reduce until we have one last chunk
-        while terms.len() > chunk_size {
+        let chunk_size = loop {
+            let chunk_size = max_terms_in_chunk(&terms);
+            // If not even two terms can be added together, the faster algorithm
+            // is not possible, so we use another one that still works
+            if chunk_size < 2 {
+                *lhs = self
+                    .smart_binary_op_seq_parallelized(&mut terms, ServerKey::smart_add_parallelized)
+                    .unwrap_or_else(|| self.create_trivial_zero_radix(num_blocks));
+
+                self.full_propagate_parallelized(lhs);
+                return;
+            }
+            if terms.len() <= chunk_size {
+                break chunk_size;
+            }
+
             terms.par_chunks_exact_mut(chunk_size).for_each(|chunk| {
                 let (s, rest) = chunk.split_first_mut().unwrap();
                 let mut first_block_where_addition_happenned = num_blocks - 1;
@@ -413,7 +420,7 @@ impl ServerKey {
                 terms.swap(from, to);
             }
             terms.truncate((terms.len() / chunk_size) + rest);
-        }
+        };
         assert!(terms.len() <= chunk_size);
 
         // Now we will add the last chunk of terms
@@ -680,4 +687,110 @@ impl ServerKey {
 
         self.unchecked_mul_assign_parallelized(lhs, rhs);
     }
+
+    /// Computes homomorphically a multiplication between two ciphertexts encrypting integer values,
+    /// and whether the multiplication overflowed.
+    ///
+    /// The returned block encrypts 1 if the product is greater than the maximum value the
+    /// ciphertext can represent (i.e. the result wrapped around), 0 otherwise.
+    ///
+    /// To detect the overflow, the product is computed on twice as many blocks
+    /// as the inputs have, which makes this operation more expensive than
+    /// [Self::mul_parallelized].
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear_1 = 170u8;
+    /// let clear_2 = 6u8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let (ct_res, overflowed) = sks.overflowing_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// let (expected_res, expected_overflowed) = clear_1.overflowing_mul(clear_2);
+    /// assert_eq!(res, expected_res);
+    /// assert_eq!(dec_overflowed, u64::from(expected_overflowed));
+    /// ```
+    pub fn overflowing_mul_parallelized(
+        &self,
+        ct1: &RadixCiphertext,
+        ct2: &RadixCiphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        let num_blocks = ct1.blocks.len();
+
+        let (mut lhs, rhs) = rayon::join(
+            || self.extend_radix_with_trivial_zero_blocks_msb(ct1, num_blocks),
+            || self.extend_radix_with_trivial_zero_blocks_msb(ct2, num_blocks),
+        );
+        self.mul_assign_parallelized(&mut lhs, &rhs);
+
+        // The product overflowed if any of its upper blocks is not zero
+        let upper_blocks = RadixCiphertext::from(lhs.blocks.split_off(num_blocks));
+        let mut overflowed = self.scalar_ne_parallelized(&upper_blocks, 0u64);
+
+        (lhs, overflowed.blocks.swap_remove(0))
+    }
+
+    /// Computes homomorphically a saturating multiplication between two ciphertexts encrypting
+    /// integer values.
+    ///
+    /// If the product is greater than the maximum value the ciphertext can represent,
+    /// the result is clamped to this maximum value instead of wrapping around.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clear_1 = 170u8;
+    /// let clear_2 = 6u8;
+    ///
+    /// // Encrypt two messages
+    /// let ctxt_1 = cks.encrypt(clear_1);
+    /// let ctxt_2 = cks.encrypt(clear_2);
+    ///
+    /// // Compute homomorphically a multiplication
+    /// let ct_res = sks.saturating_mul_parallelized(&ctxt_1, &ctxt_2);
+    /// // Decrypt
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, clear_1.saturating_mul(clear_2));
+    /// ```
+    pub fn saturating_mul_parallelized(
+        &self,
+        ct1: &RadixCiphertext,
+        ct2: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut ct_res, overflowed) = self.overflowing_mul_parallelized(ct1, ct2);
+
+        let max_block_value = self.key.message_modulus.0 as u64 - 1;
+        self.unchecked_set_blocks_if_assign_parallelized(&mut ct_res, &overflowed, max_block_value);
+
+        ct_res
+    }
 }
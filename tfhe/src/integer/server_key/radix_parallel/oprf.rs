@@ -0,0 +1,191 @@
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed, Seeder};
+use crate::core_crypto::prelude::UnsignedInteger;
+use crate::integer::block_decomposition::DecomposableInto;
+use crate::integer::{RadixCiphertext, ServerKey};
+use rayon::prelude::*;
+
+/// Number of random bits generated in addition to the bits of the bound,
+/// when generating a random value in a range that is not a power of two
+const BOUNDED_EXTRA_RANDOM_BITS: u64 = 64;
+
+impl ServerKey {
+    /// Generates an encrypted pseudo-random integer, uniform over all the values
+    /// representable with `num_blocks` blocks, without any knowledge of the client key.
+    ///
+    /// The value is fully determined by the `seed` and the secret key, but as the secret key is
+    /// unknown to the server, nobody knows the value without decrypting it.
+    /// Calling this function twice with the same seed gives encryptions of the same value,
+    /// so a new seed must be used to get a new value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct_res = sks.generate_oblivious_pseudo_random_parallelized(Seed(0), num_blocks);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < 256);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_parallelized(
+        &self,
+        seed: Seed,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as u64;
+        self.generate_oblivious_pseudo_random_bits_parallelized(
+            seed,
+            bits_per_block * num_blocks as u64,
+            num_blocks,
+        )
+    }
+
+    /// Generates an encrypted pseudo-random integer, uniform in `[0, 2^random_bits_count[`,
+    /// without any knowledge of the client key.
+    ///
+    /// See [Self::generate_oblivious_pseudo_random_parallelized] for more details, and
+    /// [Self::generate_oblivious_pseudo_random_bounded_parallelized] for bounds that are not
+    /// powers of two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_blocks` blocks cannot hold `random_bits_count` bits.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let ct_res = sks.generate_oblivious_pseudo_random_bits_parallelized(Seed(0), 5, num_blocks);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < 32);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_bits_parallelized(
+        &self,
+        seed: Seed,
+        random_bits_count: u64,
+        num_blocks: usize,
+    ) -> RadixCiphertext {
+        let bits_per_block = self.key.message_modulus.0.ilog2() as u64;
+        assert!(
+            random_bits_count <= bits_per_block * num_blocks as u64,
+            "{num_blocks} blocks of {bits_per_block} bits cannot hold {random_bits_count} bits"
+        );
+
+        // Each block gets its own seed, derived from the input one
+        let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
+        let block_seeds = (0..num_blocks).map(|_| seeder.seed()).collect::<Vec<_>>();
+
+        let blocks = block_seeds
+            .into_par_iter()
+            .enumerate()
+            .map(|(i, block_seed)| {
+                let block_bits = random_bits_count
+                    .saturating_sub(i as u64 * bits_per_block)
+                    .min(bits_per_block);
+                if block_bits == 0 {
+                    self.key.create_trivial(0)
+                } else {
+                    self.key
+                        .generate_oblivious_pseudo_random(block_seed, block_bits)
+                }
+            })
+            .collect::<Vec<_>>();
+
+        RadixCiphertext::from(blocks)
+    }
+
+    /// Generates an encrypted pseudo-random integer in `[0, bound[`,
+    /// without any knowledge of the client key.
+    ///
+    /// A random value `r` of `k` bits is generated, with `k` the number of bits of `bound` plus
+    /// 64, and the result is `(r * bound) >> k`. This maps `r` to `[0, bound[` with a
+    /// statistical distance to the uniform distribution lower than `2^-64`.
+    ///
+    /// When `bound` is a power of two, the result is exactly uniform and is computed with
+    /// [Self::generate_oblivious_pseudo_random_bits_parallelized], which is cheaper.
+    ///
+    /// See [Self::generate_oblivious_pseudo_random_parallelized] for more details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is 0, or if `num_blocks` blocks cannot hold `bound - 1`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// // A dice roll, in [0, 6[
+    /// let ct_res =
+    ///     sks.generate_oblivious_pseudo_random_bounded_parallelized(Seed(0), 6u64, num_blocks);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert!(dec_result < 6);
+    /// ```
+    pub fn generate_oblivious_pseudo_random_bounded_parallelized<T>(
+        &self,
+        seed: Seed,
+        bound: T,
+        num_blocks: usize,
+    ) -> RadixCiphertext
+    where
+        T: UnsignedInteger + DecomposableInto<u8>,
+    {
+        assert!(bound > T::ZERO, "The bound must not be 0");
+
+        let bits_per_block = self.key.message_modulus.0.ilog2() as u64;
+        // Number of bits of bound - 1
+        let bound_bits = u64::from(bound.ceil_ilog2());
+        assert!(
+            bound_bits <= bits_per_block * num_blocks as u64,
+            "{num_blocks} blocks of {bits_per_block} bits cannot hold values up to {:?}",
+            bound - T::ONE
+        );
+
+        if bound.is_power_of_two() {
+            return self
+                .generate_oblivious_pseudo_random_bits_parallelized(seed, bound_bits, num_blocks);
+        }
+
+        // The random value has a whole number of blocks, so that the shift is free
+        let random_num_blocks = ((bound_bits + BOUNDED_EXTRA_RANDOM_BITS + bits_per_block - 1)
+            / bits_per_block) as usize;
+        let bound_num_blocks = ((bound_bits + bits_per_block - 1) / bits_per_block) as usize;
+
+        let mut random =
+            self.generate_oblivious_pseudo_random_parallelized(seed, random_num_blocks);
+        // r * bound < 2^k * bound, it fits in the blocks of r and the ones of the bound
+        self.extend_radix_with_trivial_zero_blocks_msb_assign(&mut random, bound_num_blocks);
+        self.scalar_mul_assign_parallelized(&mut random, bound);
+
+        let mut blocks = random.blocks.split_off(random_num_blocks);
+        // The result is lower than bound, so the removed blocks are zeros
+        blocks.resize_with(num_blocks, || self.key.create_trivial(0));
+
+        RadixCiphertext::from(blocks)
+    }
+}
@@ -0,0 +1,160 @@
+use super::bit_extractor::BitExtractor;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Computes homomorphically `ct` raised to the power of a clear exponent
+    ///
+    /// The result wraps around if it does not fit in the ciphertext, as with
+    /// [u64::wrapping_pow]. The exponentiation is done by squaring,
+    /// so it costs about `2 * log2(exponent)` multiplications.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 3u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    ///
+    /// let ct_res = sks.scalar_pow_parallelized(&ct, 5);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.wrapping_pow(5));
+    /// ```
+    pub fn scalar_pow_parallelized(&self, ct: &RadixCiphertext, exponent: u64) -> RadixCiphertext {
+        let mut base = ct.clone();
+        if !base.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut base);
+        }
+
+        let mut result: Option<RadixCiphertext> = None;
+        let mut exponent = exponent;
+        while exponent != 0 {
+            let multiply_result = exponent & 1 == 1;
+            exponent >>= 1;
+
+            let (new_result, squared_base) = rayon::join(
+                || match (&result, multiply_result) {
+                    (Some(result), true) => Some(self.mul_parallelized(result, &base)),
+                    (None, true) => Some(base.clone()),
+                    (_, false) => None,
+                },
+                || (exponent != 0).then(|| self.mul_parallelized(&base, &base)),
+            );
+            if new_result.is_some() {
+                result = new_result;
+            }
+            if let Some(squared_base) = squared_base {
+                base = squared_base;
+            }
+        }
+
+        result.unwrap_or_else(|| self.create_trivial_radix(1u64, ct.blocks.len()))
+    }
+
+    /// Computes homomorphically `base` raised to the power of an encrypted `exponent`
+    ///
+    /// The result wraps around if it does not fit in the ciphertext, as with
+    /// [u64::wrapping_pow]. The result has the same number of blocks as `base`,
+    /// `exponent` may have a different number of blocks.
+    ///
+    /// As the exponent is not known, each of its bits has to be processed, so this costs
+    /// about `2 * num_bits` multiplications, where `num_bits` is the number of bits of `exponent`.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 3u8;
+    /// let exponent = 5u8;
+    ///
+    /// let ct = cks.encrypt(msg as u64);
+    /// // A 2 blocks exponent, its value must be smaller than 16
+    /// let ct_exponent = cks.as_ref().encrypt_radix(exponent as u64, 2);
+    ///
+    /// let ct_res = sks.pow_parallelized(&ct, &ct_exponent);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, msg.wrapping_pow(exponent as u32));
+    /// ```
+    pub fn pow_parallelized(
+        &self,
+        base: &RadixCiphertext,
+        exponent: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let mut tmp_exponent;
+        let exponent = if exponent.block_carries_are_empty() {
+            exponent
+        } else {
+            tmp_exponent = exponent.clone();
+            self.full_propagate_parallelized(&mut tmp_exponent);
+            &tmp_exponent
+        };
+
+        let mut base = base.clone();
+        if !base.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut base);
+        }
+
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let exponent_bits =
+            BitExtractor::new(self, bits_per_block).extract_all_bits(&exponent.blocks);
+
+        let one = self.create_trivial_radix(1u64, base.blocks.len());
+        let mut result: Option<RadixCiphertext> = None;
+        for (i, exponent_bit) in exponent_bits.iter().enumerate() {
+            // base holds the initial base raised to the power of 2^i,
+            // it is a factor of the result only if the i-th bit of the exponent is set
+            let condition = RadixCiphertext::from(vec![exponent_bit.clone()]);
+            let factor = self.if_then_else_parallelized(&condition, &base, &one);
+
+            let is_last_bit = i == exponent_bits.len() - 1;
+            let (new_result, squared_base) = rayon::join(
+                || match &result {
+                    Some(result) => self.mul_parallelized(result, &factor),
+                    None => factor.clone(),
+                },
+                || (!is_last_bit).then(|| self.mul_parallelized(&base, &base)),
+            );
+            result = Some(new_result);
+            if let Some(squared_base) = squared_base {
+                base = squared_base;
+            }
+        }
+
+        result.unwrap_or(one)
+    }
+}
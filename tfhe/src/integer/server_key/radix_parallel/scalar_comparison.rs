@@ -285,6 +285,25 @@ impl ServerKey {
         lhs: &RadixCiphertext,
         rhs: T,
     ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        let result = self.unchecked_scalar_eq_block_parallelized(lhs, rhs);
+
+        let mut blocks = Vec::with_capacity(lhs.blocks.len());
+        blocks.push(result);
+        blocks.resize_with(lhs.blocks.len(), || self.key.create_trivial(0));
+
+        RadixCiphertext { blocks }
+    }
+
+    /// Same as [Self::unchecked_scalar_eq_parallelized], but returns the result as a single
+    /// block
+    pub(crate) fn unchecked_scalar_eq_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
     where
         T: DecomposableInto<u8>,
     {
@@ -314,7 +333,7 @@ impl ServerKey {
             .map(|sub_slice| sub_slice.iter().any(|&scalar_block| scalar_block != 0))
             .unwrap_or(false);
         if is_scalar_obviously_bigger {
-            return self.create_trivial_zero_radix(num_blocks);
+            return self.key.create_trivial(0);
         }
         // If we are sill here, that means scalar_blocks above
         // num_blocks_halved are 0s, we can remove them
@@ -353,20 +372,33 @@ impl ServerKey {
             || self.compare_blocks_with_zero(most_significant_blocks, ZeroComparisonType::Equality),
         );
         cmp_1.append(&mut cmp_2);
-        let is_equal_result = self.are_all_comparisons_block_true(cmp_1);
+        self.are_all_comparisons_block_true(cmp_1)
+    }
+
+    pub fn unchecked_scalar_ne_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> RadixCiphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        let result = self.unchecked_scalar_ne_block_parallelized(lhs, rhs);
 
         let mut blocks = Vec::with_capacity(lhs.blocks.len());
-        blocks.push(is_equal_result);
+        blocks.push(result);
         blocks.resize_with(lhs.blocks.len(), || self.key.create_trivial(0));
 
         RadixCiphertext { blocks }
     }
 
-    pub fn unchecked_scalar_ne_parallelized<T>(
+    /// Same as [Self::unchecked_scalar_ne_parallelized], but returns the result as a single
+    /// block
+    pub(crate) fn unchecked_scalar_ne_block_parallelized<T>(
         &self,
         lhs: &RadixCiphertext,
         rhs: T,
-    ) -> RadixCiphertext
+    ) -> Ciphertext
     where
         T: DecomposableInto<u8>,
     {
@@ -396,7 +428,7 @@ impl ServerKey {
             .map(|sub_slice| sub_slice.iter().any(|&scalar_block| scalar_block != 0))
             .unwrap_or(false);
         if is_scalar_obviously_bigger {
-            return self.create_trivial_radix(1, num_blocks);
+            return self.key.create_trivial(1);
         }
         // If we are sill here, that means scalar_blocks above
         // num_blocks_halved are 0s, we can remove them
@@ -440,13 +472,7 @@ impl ServerKey {
             },
         );
         cmp_1.append(&mut cmp_2);
-        let is_equal_result = self.is_at_least_one_comparisons_block_true(cmp_1);
-
-        let mut blocks = Vec::with_capacity(lhs.blocks.len());
-        blocks.push(is_equal_result);
-        blocks.resize_with(lhs.blocks.len(), || self.key.create_trivial(0));
-
-        RadixCiphertext { blocks }
+        self.is_at_least_one_comparisons_block_true(cmp_1)
     }
 
     pub fn smart_scalar_eq_parallelized<T>(
@@ -692,4 +718,117 @@ impl ServerKey {
     {
         Comparator::new(self).scalar_min_parallelized(lhs, rhs)
     }
+
+    /// Calls `f` on `lhs` and `rhs`, after propagating the carries of `lhs` if they are not empty
+    fn with_propagated_carries_scalar_parallelized<T, R, F>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+        f: F,
+    ) -> R
+    where
+        F: FnOnce(&Self, &RadixCiphertext, T) -> R,
+    {
+        let mut tmp_lhs: RadixCiphertext;
+        let lhs = if lhs.block_carries_are_empty() {
+            lhs
+        } else {
+            tmp_lhs = lhs.clone();
+            self.full_propagate_parallelized(&mut tmp_lhs);
+            &tmp_lhs
+        };
+        f(self, lhs, rhs)
+    }
+
+    //===========================================================
+    // Default ==, !=, <, >, <=, >= returning a single block
+    //===========================================================
+
+    /// Same as [Self::scalar_eq_parallelized], but returns the result as a single block
+    pub(crate) fn scalar_eq_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.with_propagated_carries_scalar_parallelized(
+            lhs,
+            rhs,
+            Self::unchecked_scalar_eq_block_parallelized,
+        )
+    }
+
+    /// Same as [Self::scalar_ne_parallelized], but returns the result as a single block
+    pub(crate) fn scalar_ne_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.with_propagated_carries_scalar_parallelized(
+            lhs,
+            rhs,
+            Self::unchecked_scalar_ne_block_parallelized,
+        )
+    }
+
+    /// Same as [Self::scalar_gt_parallelized], but returns the result as a single block
+    pub(crate) fn scalar_gt_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.with_propagated_carries_scalar_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_scalar_gt_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Same as [Self::scalar_ge_parallelized], but returns the result as a single block
+    pub(crate) fn scalar_ge_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.with_propagated_carries_scalar_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_scalar_ge_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Same as [Self::scalar_lt_parallelized], but returns the result as a single block
+    pub(crate) fn scalar_lt_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.with_propagated_carries_scalar_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_scalar_lt_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Same as [Self::scalar_le_parallelized], but returns the result as a single block
+    pub(crate) fn scalar_le_block_parallelized<T>(
+        &self,
+        lhs: &RadixCiphertext,
+        rhs: T,
+    ) -> Ciphertext
+    where
+        T: DecomposableInto<u8>,
+    {
+        self.with_propagated_carries_scalar_parallelized(lhs, rhs, |sks, lhs, rhs| {
+            Comparator::new(sks).unchecked_scalar_le_block_parallelized(lhs, rhs)
+        })
+    }
 }
@@ -356,8 +356,27 @@ impl ServerKey {
             return;
         }
 
+        let terms = self.unchecked_scalar_mul_terms_parallelized(lhs, scalar);
+        self.sum_multiplication_terms_into(lhs, terms);
+    }
+
+    /// Computes the terms which sum is the product of `ct` by `scalar`
+    ///
+    /// The scalar is decomposed in blocks, each term being `ct` multiplied by one of
+    /// these blocks and shifted accordingly. The terms may have one carry consumed and
+    /// are meant to be summed using [Self::sum_multiplication_terms_into].
+    ///
+    /// `ct` must have empty carries.
+    pub(crate) fn unchecked_scalar_mul_terms_parallelized<T>(
+        &self,
+        ct: &RadixCiphertext,
+        scalar: T,
+    ) -> Vec<RadixCiphertext>
+    where
+        T: UnsignedInteger + DecomposableInto<u8>,
+    {
         let message_modulus = self.key.message_modulus.0 as u64;
-        let num_blocks = lhs.blocks.len();
+        let num_blocks = ct.blocks.len();
 
         // key is the small scalar we multiply by
         // value is the vector of blockshifts
@@ -372,15 +391,8 @@ impl ServerKey {
             }
         }
 
-        // This can happen if scalar % (nb_blocks * message_modulus) == 0
-        if task_map.iter().all(Vec::is_empty) {
-            for block in &mut lhs.blocks {
-                self.key.create_trivial_assign(block, 0);
-            }
-            return;
-        }
-
-        let terms = task_map[1..] // Ignore multiplications by zero
+        // The terms vec is empty if scalar % (nb_blocks * message_modulus) == 0
+        task_map[1..] // Ignore multiplications by zero
             .into_par_iter()
             .enumerate()
             .filter(|(_, block_indices)| !block_indices.is_empty())
@@ -389,7 +401,7 @@ impl ServerKey {
 
                 let min_index = block_indices.iter().min().unwrap();
 
-                let mut tmp = lhs.clone();
+                let mut tmp = ct.clone();
                 if scalar != 1 {
                     tmp.blocks[0..num_blocks - min_index]
                         .par_iter_mut()
@@ -430,9 +442,7 @@ impl ServerKey {
                     .collect::<Vec<_>>()
             })
             .flatten()
-            .collect::<Vec<_>>();
-
-        self.sum_multiplication_terms_into(lhs, terms);
+            .collect::<Vec<_>>()
     }
 
     /// Computes homomorphically a multiplication between a scalar and a ciphertext.
@@ -9,6 +9,7 @@ pub(super) enum BarrelShifterOperation {
     LeftShift,
     RightShift,
     RightRotate,
+    ArithmeticRightShift,
 }
 
 impl ServerKey {
@@ -383,9 +384,9 @@ impl ServerKey {
 
         let offset = match operation {
             BarrelShifterOperation::LeftShift | BarrelShifterOperation::LeftRotate => 0,
-            BarrelShifterOperation::RightShift | BarrelShifterOperation::RightRotate => {
-                total_nb_bits
-            }
+            BarrelShifterOperation::RightShift
+            | BarrelShifterOperation::RightRotate
+            | BarrelShifterOperation::ArithmeticRightShift => total_nb_bits,
         };
 
         use std::cell::UnsafeCell;
@@ -435,6 +436,16 @@ impl ServerKey {
                         self.key.create_trivial_assign(bit_that_wrapped, 0);
                     }
                 }
+                BarrelShifterOperation::ArithmeticRightShift => {
+                    // The sign bit is never changed by an arithmetic shift,
+                    // so the last bit of input_bits_a is always the sign bit
+                    input_bits_b.rotate_left(1 << d);
+                    let sign_bit = input_bits_a[total_nb_bits as usize - 1].clone();
+                    let bits_that_wrapped = &mut input_bits_b[total_nb_bits as usize - (1 << d)..];
+                    for bit_that_wrapped in bits_that_wrapped {
+                        bit_that_wrapped.clone_from(&sign_bit);
+                    }
+                }
                 BarrelShifterOperation::LeftRotate => {
                     input_bits_b.rotate_right(1 << d);
                 }
@@ -0,0 +1,766 @@
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::server_key::comparator::Comparator;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+
+impl ServerKey {
+    /// Returns a copy of `ct`, viewed as unsigned, where the sign bit is flipped.
+    ///
+    /// Flipping the sign bit maps the signed range [-2^(n-1), 2^(n-1)[
+    /// to the unsigned range [0, 2^n[ while preserving the order,
+    /// so signed values can then be compared using the unsigned algorithms.
+    ///
+    /// Expects the carry buffers to be empty
+    fn unchecked_flip_sign_bit(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let lut = self
+            .key
+            .generate_lookup_table(|x| (x + (message_modulus / 2)) % message_modulus);
+
+        let mut result = ct.clone();
+        let last_block = result.blocks.last_mut().unwrap();
+        self.key.apply_lookup_table_assign(last_block, &lut);
+        result
+    }
+
+    fn unchecked_signed_comparison_impl<F>(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+        unsigned_comparison_fn: F,
+    ) -> SignedRadixCiphertext
+    where
+        F: Fn(&Self, &RadixCiphertext, &RadixCiphertext) -> RadixCiphertext,
+    {
+        let (lhs, rhs) = rayon::join(
+            || self.unchecked_flip_sign_bit(&lhs.to_unsigned()),
+            || self.unchecked_flip_sign_bit(&rhs.to_unsigned()),
+        );
+        let result = unsigned_comparison_fn(self, &lhs, &rhs);
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    fn signed_comparison_impl<F>(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+        unsigned_comparison_fn: F,
+    ) -> SignedRadixCiphertext
+    where
+        F: Fn(&Self, &RadixCiphertext, &RadixCiphertext) -> RadixCiphertext,
+    {
+        let (lhs, rhs) = rayon::join(
+            || self.unchecked_flip_sign_bit(&self.signed_to_clean_unsigned(lhs)),
+            || self.unchecked_flip_sign_bit(&self.signed_to_clean_unsigned(rhs)),
+        );
+        let result = unsigned_comparison_fn(self, &lhs, &rhs);
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    //===========================================================
+    // Unchecked <, >, <=, >=, min, max, ==, != multi-threaded
+    //===========================================================
+
+    /// Compares for equality two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_eq_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 == msg2));
+    /// ```
+    pub fn unchecked_signed_eq_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        // Equality does not depend on how the bits are interpreted
+        let result = self.unchecked_eq_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Compares for difference two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_ne_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 != msg2));
+    /// ```
+    pub fn unchecked_signed_ne_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result = self.unchecked_ne_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Compares for greater than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_gt_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 > msg2));
+    /// ```
+    pub fn unchecked_signed_gt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.unchecked_signed_comparison_impl(lhs, rhs, Self::unchecked_gt_parallelized)
+    }
+
+    /// Compares for greater or equal than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_ge_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 >= msg2));
+    /// ```
+    pub fn unchecked_signed_ge_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.unchecked_signed_comparison_impl(lhs, rhs, Self::unchecked_ge_parallelized)
+    }
+
+    /// Compares for less than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_lt_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 < msg2));
+    /// ```
+    pub fn unchecked_signed_lt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.unchecked_signed_comparison_impl(lhs, rhs, Self::unchecked_lt_parallelized)
+    }
+
+    /// Compares for less or equal than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_le_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 <= msg2));
+    /// ```
+    pub fn unchecked_signed_le_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.unchecked_signed_comparison_impl(lhs, rhs, Self::unchecked_le_parallelized)
+    }
+
+    /// Computes homomorphically the maximum of two signed ciphertexts
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_max_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.max(msg2));
+    /// ```
+    pub fn unchecked_signed_max_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result =
+            self.unchecked_signed_comparison_impl(lhs, rhs, Self::unchecked_max_parallelized);
+        // The selected value still has its sign bit flipped
+        let result = self.unchecked_flip_sign_bit(&result.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Computes homomorphically the minimum of two signed ciphertexts
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_min_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.min(msg2));
+    /// ```
+    pub fn unchecked_signed_min_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result =
+            self.unchecked_signed_comparison_impl(lhs, rhs, Self::unchecked_min_parallelized);
+        // The selected value still has its sign bit flipped
+        let result = self.unchecked_flip_sign_bit(&result.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    //===========================================================
+    // Default <, >, <=, >=, min, max, ==, != multi-threaded
+    //===========================================================
+
+    /// Compares for equality two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs == rhs, otherwise 0
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_eq_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 == msg2));
+    /// ```
+    pub fn signed_eq_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result = self.eq_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Compares for difference two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs != rhs, otherwise 0
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_ne_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 != msg2));
+    /// ```
+    pub fn signed_ne_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result = self.ne_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Compares for greater than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs > rhs, otherwise 0
+    ///
+    /// Requires carry bits to be empty
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_gt_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 > msg2));
+    /// ```
+    pub fn signed_gt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.signed_comparison_impl(lhs, rhs, Self::unchecked_gt_parallelized)
+    }
+
+    /// Compares for greater or equal than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs >= rhs, otherwise 0
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_ge_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 >= msg2));
+    /// ```
+    pub fn signed_ge_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.signed_comparison_impl(lhs, rhs, Self::unchecked_ge_parallelized)
+    }
+
+    /// Compares for less than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs < rhs, otherwise 0
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_lt_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 < msg2));
+    /// ```
+    pub fn signed_lt_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.signed_comparison_impl(lhs, rhs, Self::unchecked_lt_parallelized)
+    }
+
+    /// Compares for less or equal than two signed ciphertexts
+    ///
+    /// Returns a ciphertext containing 1 if lhs <= rhs, otherwise 0
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_le_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, i8::from(msg1 <= msg2));
+    /// ```
+    pub fn signed_le_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        self.signed_comparison_impl(lhs, rhs, Self::unchecked_le_parallelized)
+    }
+
+    /// Computes homomorphically the maximum of two signed ciphertexts
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_max_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.max(msg2));
+    /// ```
+    pub fn signed_max_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result = self.signed_comparison_impl(lhs, rhs, Self::unchecked_max_parallelized);
+        let result = self.unchecked_flip_sign_bit(&result.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Computes homomorphically the minimum of two signed ciphertexts
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -24i8;
+    /// let msg2 = 10i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_min_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.min(msg2));
+    /// ```
+    pub fn signed_min_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result = self.signed_comparison_impl(lhs, rhs, Self::unchecked_min_parallelized);
+        let result = self.unchecked_flip_sign_bit(&result.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    //===========================================================
+    // Default ==, !=, <, >, <=, >= returning a single block
+    //===========================================================
+
+    fn signed_comparison_block_impl<F>(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+        unsigned_comparison_fn: F,
+    ) -> Ciphertext
+    where
+        F: Fn(&Comparator, &RadixCiphertext, &RadixCiphertext) -> Ciphertext,
+    {
+        let (lhs, rhs) = rayon::join(
+            || self.unchecked_flip_sign_bit(&self.signed_to_clean_unsigned(lhs)),
+            || self.unchecked_flip_sign_bit(&self.signed_to_clean_unsigned(rhs)),
+        );
+        unsigned_comparison_fn(&Comparator::new(self), &lhs, &rhs)
+    }
+
+    /// Same as [Self::signed_eq_parallelized], but returns the result as a single block
+    pub(crate) fn signed_eq_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> Ciphertext {
+        self.eq_block_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned())
+    }
+
+    /// Same as [Self::signed_ne_parallelized], but returns the result as a single block
+    pub(crate) fn signed_ne_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> Ciphertext {
+        self.ne_block_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned())
+    }
+
+    /// Same as [Self::signed_gt_parallelized], but returns the result as a single block
+    pub(crate) fn signed_gt_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> Ciphertext {
+        self.signed_comparison_block_impl(lhs, rhs, |comparator, lhs, rhs| {
+            comparator.unchecked_gt_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Same as [Self::signed_ge_parallelized], but returns the result as a single block
+    pub(crate) fn signed_ge_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> Ciphertext {
+        self.signed_comparison_block_impl(lhs, rhs, |comparator, lhs, rhs| {
+            comparator.unchecked_ge_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Same as [Self::signed_lt_parallelized], but returns the result as a single block
+    pub(crate) fn signed_lt_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> Ciphertext {
+        self.signed_comparison_block_impl(lhs, rhs, |comparator, lhs, rhs| {
+            comparator.unchecked_lt_block_parallelized(lhs, rhs)
+        })
+    }
+
+    /// Same as [Self::signed_le_parallelized], but returns the result as a single block
+    pub(crate) fn signed_le_block_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> Ciphertext {
+        self.signed_comparison_block_impl(lhs, rhs, |comparator, lhs, rhs| {
+            comparator.unchecked_le_block_parallelized(lhs, rhs)
+        })
+    }
+}
@@ -0,0 +1,1131 @@
+use crate::integer::ciphertext::{RadixCiphertext, SignedRadixCiphertext};
+use crate::integer::server_key::radix_parallel::shift::BarrelShifterOperation;
+use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
+
+use rayon::prelude::*;
+
+impl ServerKey {
+    /// Returns a copy of `ct` viewed as unsigned, with its carries propagated if needed
+    pub(super) fn signed_to_clean_unsigned(&self, ct: &SignedRadixCiphertext) -> RadixCiphertext {
+        let mut result = ct.to_unsigned();
+        if !result.block_carries_are_empty() {
+            self.full_propagate_parallelized(&mut result);
+        }
+        result
+    }
+
+    fn signed_to_clean_unsigned_pair(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> (RadixCiphertext, RadixCiphertext) {
+        rayon::join(
+            || self.signed_to_clean_unsigned(lhs),
+            || self.signed_to_clean_unsigned(rhs),
+        )
+    }
+
+    /// Returns a block that encrypts 1 if the two's complement number
+    /// held by the blocks is negative, 0 otherwise.
+    ///
+    /// Expects the carry buffers to be empty
+    pub(crate) fn unchecked_sign_bit_block(&self, ct: &RadixCiphertext) -> Ciphertext {
+        let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+        let lut = self.key.generate_lookup_table(|x| (x >> sign_bit_pos) & 1);
+        self.key
+            .apply_lookup_table(&ct.blocks[ct.blocks.len() - 1], &lut)
+    }
+
+    /// Returns a block whose bits are all copies of the sign bit held by
+    /// `most_significant_block`, i.e. the block that sign extends a two's complement number.
+    ///
+    /// Expects the carry buffer to be empty
+    fn unchecked_sign_padding_block(&self, most_significant_block: &Ciphertext) -> Ciphertext {
+        let sign_bit_pos = self.key.message_modulus.0.ilog2() - 1;
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let lut = self.key.generate_lookup_table(|x| {
+            if (x >> sign_bit_pos) & 1 == 1 {
+                message_modulus - 1
+            } else {
+                0
+            }
+        });
+        self.key.apply_lookup_table(most_significant_block, &lut)
+    }
+
+    /// Appends `num_blocks` blocks on the most significant side of `ct`,
+    /// so that the signed value stays the same (sign extension).
+    ///
+    /// The output carries will be empty
+    pub(crate) fn extend_signed_radix_with_sign_msb(
+        &self,
+        ct: &SignedRadixCiphertext,
+        num_blocks: usize,
+    ) -> SignedRadixCiphertext {
+        let mut result = self.signed_to_clean_unsigned(ct);
+        if num_blocks > 0 {
+            let padding_block =
+                self.unchecked_sign_padding_block(&result.blocks[ct.blocks.len() - 1]);
+            result
+                .blocks
+                .resize(ct.blocks.len() + num_blocks, padding_block);
+        }
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Negates (two's complement) `ct` if `condition` encrypts 1, leaves it unchanged
+    /// if `condition` encrypts 0.
+    ///
+    /// - `ct` must have empty carries
+    /// - `condition` must encrypt either 0 or 1
+    ///
+    /// The output carries will be empty
+    pub(crate) fn unchecked_conditional_negate_assign_parallelized(
+        &self,
+        ct: &mut RadixCiphertext,
+        condition: &Ciphertext,
+    ) {
+        // -x = !x + 1, so we first conditionally flip all the bits
+        // then add the condition to the least significant block
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let lut = self.key.generate_lookup_table_bivariate(|x, condition| {
+            if condition == 1 {
+                (message_modulus - 1) - x
+            } else {
+                x
+            }
+        });
+        ct.blocks.par_iter_mut().for_each(|block| {
+            self.key
+                .unchecked_apply_lookup_table_bivariate_assign(block, condition, &lut);
+        });
+
+        self.key.unchecked_add_assign(&mut ct.blocks[0], condition);
+        if self.is_eligible_for_parallel_carryless_add() {
+            self.propagate_single_carry_parallelized_low_latency(ct);
+        } else {
+            self.full_propagate_parallelized(ct);
+        }
+    }
+
+    //======================================================================
+    //                Add / Sub / Mul / Neg
+    //======================================================================
+    //
+    // In two's complement, the blocks resulting from these operations are
+    // the same whether the inputs are interpreted as signed or unsigned,
+    // so the unsigned algorithms are used.
+
+    /// Computes homomorphically the addition of two signed ciphertexts.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_add].
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext, the carries of the output are not propagated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 53i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.wrapping_add(msg2));
+    /// ```
+    pub fn unchecked_signed_add_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result = self.unchecked_add_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Computes homomorphically the addition of two signed ciphertexts.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_add].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 53i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_add_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1.wrapping_add(msg2));
+    /// ```
+    pub fn signed_add_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut result = lhs.clone();
+        self.signed_add_assign_parallelized(&mut result, rhs);
+        result
+    }
+
+    /// Computes homomorphically the addition of two signed ciphertexts, the result is assigned to
+    /// `lhs`.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_add].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. The block carries of `lhs` are always empty
+    /// after the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 53i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// sks.signed_add_assign_parallelized(&mut ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct1);
+    /// assert_eq!(dec_result, msg1.wrapping_add(msg2));
+    /// ```
+    pub fn signed_add_assign_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) {
+        let (mut unsigned_lhs, unsigned_rhs) = self.signed_to_clean_unsigned_pair(lhs, rhs);
+        self.add_assign_parallelized(&mut unsigned_lhs, &unsigned_rhs);
+        *lhs = SignedRadixCiphertext::from_unsigned(unsigned_lhs);
+    }
+
+    /// Computes homomorphically the subtraction of two signed ciphertexts.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_sub].
+    ///
+    /// This function computes the operation without checking if it exceeds the capacity of the
+    /// ciphertext, the carries of the output are not propagated.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 12i8;
+    /// let msg2 = 73i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_sub_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 - msg2);
+    /// ```
+    pub fn unchecked_signed_sub_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let negated_rhs = self.unchecked_neg(&rhs.to_unsigned());
+        let result = self.unchecked_add_parallelized(&lhs.to_unsigned(), &negated_rhs);
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Computes homomorphically the subtraction of two signed ciphertexts.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_sub].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 12i8;
+    /// let msg2 = 73i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_sub_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 - msg2);
+    /// ```
+    pub fn signed_sub_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut result = lhs.clone();
+        self.signed_sub_assign_parallelized(&mut result, rhs);
+        result
+    }
+
+    /// Computes homomorphically the subtraction of two signed ciphertexts, the result is assigned
+    /// to `lhs`.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_sub].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. The block carries of `lhs` are always empty
+    /// after the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = 12i8;
+    /// let msg2 = 73i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// sks.signed_sub_assign_parallelized(&mut ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct1);
+    /// assert_eq!(dec_result, msg1 - msg2);
+    /// ```
+    pub fn signed_sub_assign_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) {
+        let (mut unsigned_lhs, unsigned_rhs) = self.signed_to_clean_unsigned_pair(lhs, rhs);
+        self.sub_assign_parallelized(&mut unsigned_lhs, &unsigned_rhs);
+        *lhs = SignedRadixCiphertext::from_unsigned(unsigned_lhs);
+    }
+
+    /// Computes homomorphically the multiplication of two signed ciphertexts.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_mul].
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -7i8;
+    /// let msg2 = 13i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 * msg2);
+    /// ```
+    pub fn unchecked_signed_mul_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let result = self.unchecked_mul_parallelized(&lhs.to_unsigned(), &rhs.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Computes homomorphically the multiplication of two signed ciphertexts.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_mul].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -7i8;
+    /// let msg2 = 13i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_mul_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 * msg2);
+    /// ```
+    pub fn signed_mul_parallelized(
+        &self,
+        lhs: &SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut result = lhs.clone();
+        self.signed_mul_assign_parallelized(&mut result, rhs);
+        result
+    }
+
+    /// Computes homomorphically the multiplication of two signed ciphertexts, the result is
+    /// assigned to `lhs`.
+    ///
+    /// The result wraps around on overflow, like [i64::wrapping_mul].
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. The block carries of `lhs` are always empty
+    /// after the operation.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -7i8;
+    /// let msg2 = 13i8;
+    ///
+    /// let mut ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// sks.signed_mul_assign_parallelized(&mut ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct1);
+    /// assert_eq!(dec_result, msg1 * msg2);
+    /// ```
+    pub fn signed_mul_assign_parallelized(
+        &self,
+        lhs: &mut SignedRadixCiphertext,
+        rhs: &SignedRadixCiphertext,
+    ) {
+        let (mut unsigned_lhs, unsigned_rhs) = self.signed_to_clean_unsigned_pair(lhs, rhs);
+        self.mul_assign_parallelized(&mut unsigned_lhs, &unsigned_rhs);
+        *lhs = SignedRadixCiphertext::from_unsigned(unsigned_lhs);
+    }
+
+    /// Computes homomorphically the negation of a signed ciphertext.
+    ///
+    /// Negating the minimum value of the type gives back the minimum value,
+    /// like [i64::wrapping_neg].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -103i8;
+    ///
+    /// let ct = cks.encrypt_signed(msg);
+    ///
+    /// let ct_res = sks.signed_neg_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, -msg);
+    /// ```
+    pub fn signed_neg_parallelized(&self, ct: &SignedRadixCiphertext) -> SignedRadixCiphertext {
+        let result = self.neg_parallelized(&ct.to_unsigned());
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    //======================================================================
+    //                Abs
+    //======================================================================
+
+    /// Computes homomorphically the absolute value of a signed ciphertext.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertext carry buffer is empty / clean
+    ///
+    /// # Output
+    ///
+    /// - The output's carries will be clean
+    pub fn unchecked_signed_abs_parallelized(
+        &self,
+        ct: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut result = ct.to_unsigned();
+        let is_negative = self.unchecked_sign_bit_block(&result);
+        self.unchecked_conditional_negate_assign_parallelized(&mut result, &is_negative);
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Computes homomorphically the absolute value of a signed ciphertext.
+    ///
+    /// The absolute value of the minimum value of the type is the minimum value itself,
+    /// like [i64::wrapping_abs].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -43i8;
+    ///
+    /// let ct = cks.encrypt_signed(msg);
+    ///
+    /// let ct_res = sks.signed_abs_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg.wrapping_abs());
+    /// ```
+    pub fn signed_abs_parallelized(&self, ct: &SignedRadixCiphertext) -> SignedRadixCiphertext {
+        let mut result = self.signed_to_clean_unsigned(ct);
+        let is_negative = self.unchecked_sign_bit_block(&result);
+        self.unchecked_conditional_negate_assign_parallelized(&mut result, &is_negative);
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    //======================================================================
+    //                Div Rem
+    //======================================================================
+
+    /// Computes homomorphically the quotient and remainder of the division
+    /// of two signed ciphertexts.
+    ///
+    /// The quotient is rounded toward zero and the remainder
+    /// has the sign of the numerator, like the `/` and `%` operators on rust signed integers.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    /// - The divisor is not zero, otherwise the result is meaningless
+    pub fn unchecked_signed_div_rem_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, SignedRadixCiphertext) {
+        // Compute the division on the absolute values,
+        // then fix the signs of the results:
+        // - the quotient is negative if exactly one of the inputs is negative
+        // - the remainder has the sign of the numerator
+        //
+        // The absolute value of the minimum value does not fit in the signed type,
+        // however interpreted as unsigned it is correct
+        let abs_with_sign = |ct: &SignedRadixCiphertext| {
+            let mut ct = ct.to_unsigned();
+            let is_negative = self.unchecked_sign_bit_block(&ct);
+            self.unchecked_conditional_negate_assign_parallelized(&mut ct, &is_negative);
+            (ct, is_negative)
+        };
+        let ((abs_numerator, numerator_is_negative), (abs_divisor, divisor_is_negative)) =
+            rayon::join(|| abs_with_sign(numerator), || abs_with_sign(divisor));
+
+        let ((mut quotient, mut remainder), quotient_is_negative) = rayon::join(
+            || self.unchecked_div_rem_parallelized(&abs_numerator, &abs_divisor),
+            || {
+                let lut = self.key.generate_lookup_table(|x| x & 1);
+                let mut sign = self
+                    .key
+                    .unchecked_add(&numerator_is_negative, &divisor_is_negative);
+                self.key.apply_lookup_table_assign(&mut sign, &lut);
+                sign
+            },
+        );
+
+        rayon::join(
+            || {
+                self.unchecked_conditional_negate_assign_parallelized(
+                    &mut quotient,
+                    &quotient_is_negative,
+                )
+            },
+            || {
+                self.unchecked_conditional_negate_assign_parallelized(
+                    &mut remainder,
+                    &numerator_is_negative,
+                )
+            },
+        );
+
+        (
+            SignedRadixCiphertext::from_unsigned(quotient),
+            SignedRadixCiphertext::from_unsigned(remainder),
+        )
+    }
+
+    /// Computes homomorphically the quotient and remainder of the division
+    /// of two signed ciphertexts.
+    ///
+    /// The quotient is rounded toward zero and the remainder
+    /// has the sign of the numerator, like the `/` and `%` operators on rust signed integers.
+    /// Dividing the minimum value by -1 wraps around, like [i64::wrapping_div].
+    ///
+    /// If the divisor is zero, the result is meaningless.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 14i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let (q, r) = sks.signed_div_rem_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let q: i8 = cks.decrypt_signed(&q);
+    /// let r: i8 = cks.decrypt_signed(&r);
+    /// assert_eq!(q, msg1 / msg2);
+    /// assert_eq!(r, msg1 % msg2);
+    /// ```
+    pub fn signed_div_rem_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> (SignedRadixCiphertext, SignedRadixCiphertext) {
+        let (numerator, divisor) = self.signed_to_clean_unsigned_pair(numerator, divisor);
+        self.unchecked_signed_div_rem_parallelized(
+            &SignedRadixCiphertext::from_unsigned(numerator),
+            &SignedRadixCiphertext::from_unsigned(divisor),
+        )
+    }
+
+    /// Computes homomorphically the quotient of the division of two signed ciphertexts.
+    ///
+    /// The quotient is rounded toward zero, like the `/` operator on rust signed integers.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    /// - The divisor is not zero, otherwise the result is meaningless
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 14i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_div_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 / msg2);
+    /// ```
+    pub fn unchecked_signed_div_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (q, _) = self.unchecked_signed_div_rem_parallelized(numerator, divisor);
+        q
+    }
+
+    /// Computes homomorphically the quotient of the division of two signed ciphertexts.
+    ///
+    /// The quotient is rounded toward zero, like the `/` operator on rust signed integers.
+    /// Dividing the minimum value by -1 wraps around, like [i64::wrapping_div].
+    ///
+    /// If the divisor is zero, the result is meaningless.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 14i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_div_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 / msg2);
+    /// ```
+    pub fn signed_div_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (q, _) = self.signed_div_rem_parallelized(numerator, divisor);
+        q
+    }
+
+    /// Computes homomorphically the remainder of the division of two signed ciphertexts.
+    ///
+    /// The remainder has the sign of the numerator, like the `%` operator on rust signed integers.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    /// - The divisor is not zero, otherwise the result is meaningless
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 14i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.unchecked_signed_rem_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 % msg2);
+    /// ```
+    pub fn unchecked_signed_rem_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (_, r) = self.unchecked_signed_div_rem_parallelized(numerator, divisor);
+        r
+    }
+
+    /// Computes homomorphically the remainder of the division of two signed ciphertexts.
+    ///
+    /// The remainder has the sign of the numerator, like the `%` operator on rust signed integers.
+    ///
+    /// If the divisor is zero, the result is meaningless.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg1 = -97i8;
+    /// let msg2 = 14i8;
+    ///
+    /// let ct1 = cks.encrypt_signed(msg1);
+    /// let ct2 = cks.encrypt_signed(msg2);
+    ///
+    /// let ct_res = sks.signed_rem_parallelized(&ct1, &ct2);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg1 % msg2);
+    /// ```
+    pub fn signed_rem_parallelized(
+        &self,
+        numerator: &SignedRadixCiphertext,
+        divisor: &SignedRadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let (_, r) = self.signed_div_rem_parallelized(numerator, divisor);
+        r
+    }
+
+    //======================================================================
+    //                Arithmetic Shift Right
+    //======================================================================
+
+    /// Computes homomorphically an arithmetic right shift by a clear amount.
+    ///
+    /// The bits shifted in from the most significant side are copies of the sign bit.
+    /// The shift amount is taken modulo the number of bits in the ciphertext,
+    /// like [i64::wrapping_shr].
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertext carry buffer is empty / clean
+    ///
+    /// # Output
+    ///
+    /// - The output's carries will be clean
+    pub fn unchecked_signed_scalar_right_shift_parallelized(
+        &self,
+        ct: &SignedRadixCiphertext,
+        shift: u64,
+    ) -> SignedRadixCiphertext {
+        let mut result = ct.clone();
+        self.unchecked_signed_scalar_right_shift_assign_parallelized(&mut result, shift);
+        result
+    }
+
+    /// Computes homomorphically an arithmetic right shift by a clear amount, the result is
+    /// assigned to `ct`.
+    ///
+    /// The bits shifted in from the most significant side are copies of the sign bit.
+    /// The shift amount is taken modulo the number of bits in the ciphertext,
+    /// like [i64::wrapping_shr].
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertext carry buffer is empty / clean
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -117i8;
+    /// let shift = 3;
+    ///
+    /// let mut ct = cks.encrypt_signed(msg);
+    ///
+    /// sks.unchecked_signed_scalar_right_shift_assign_parallelized(&mut ct, shift);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct);
+    /// assert_eq!(dec_result, msg >> shift);
+    /// ```
+    pub fn unchecked_signed_scalar_right_shift_assign_parallelized(
+        &self,
+        ct: &mut SignedRadixCiphertext,
+        shift: u64,
+    ) {
+        // Same idea as the logical shift:
+        // 1) shift blocks, the blocks entering from the MSB side are copies of a block made of the
+        //    sign bit
+        // 2) shift within each block, using the next block (or the sign block for the last one) to
+        //    get the bits that enter the block
+        debug_assert!(ct.block_carries_are_empty());
+
+        let num_blocks = ct.blocks.len();
+        let num_bits_in_block = self.key.message_modulus.0.ilog2() as u64;
+        let total_num_bits = num_bits_in_block * num_blocks as u64;
+        let shift = shift % total_num_bits;
+        if shift == 0 {
+            return;
+        }
+
+        let rotations = (shift / num_bits_in_block) as usize;
+        let shift_within_block = shift % num_bits_in_block;
+        let message_modulus = self.key.message_modulus.0 as u64;
+
+        let padding_block = self.unchecked_sign_padding_block(&ct.blocks[num_blocks - 1]);
+
+        // rotate left as the blocks are from LSB to MSB
+        ct.blocks.rotate_left(rotations);
+        for block in &mut ct.blocks[num_blocks - rotations..] {
+            block.clone_from(&padding_block);
+        }
+
+        if shift_within_block == 0 {
+            return;
+        }
+
+        let lut = self
+            .key
+            .generate_lookup_table_bivariate(|current_block, mut next_block| {
+                // left shift so as not to lose bits when shifting right afterwards
+                next_block <<= num_bits_in_block;
+                next_block >>= shift_within_block;
+
+                let message_of_current_block = current_block >> shift_within_block;
+                let carry_of_previous_block = next_block % message_modulus;
+
+                message_of_current_block + carry_of_previous_block
+            });
+
+        // Blocks that are copies of the padding block stay the same,
+        // so only the other ones go through a PBS
+        let num_blocks_to_shift = num_blocks - rotations;
+        let shifted_blocks = (0..num_blocks_to_shift)
+            .into_par_iter()
+            .map(|i| {
+                let next_block = if i + 1 < num_blocks_to_shift {
+                    &ct.blocks[i + 1]
+                } else {
+                    &padding_block
+                };
+                self.key
+                    .unchecked_apply_lookup_table_bivariate(&ct.blocks[i], next_block, &lut)
+            })
+            .collect::<Vec<_>>();
+
+        for (block, shifted_block) in ct.blocks.iter_mut().zip(shifted_blocks) {
+            *block = shifted_block;
+        }
+    }
+
+    /// Computes homomorphically an arithmetic right shift by a clear amount.
+    ///
+    /// The bits shifted in from the most significant side are copies of the sign bit.
+    /// The shift amount is taken modulo the number of bits in the ciphertext,
+    /// like [i64::wrapping_shr].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -117i8;
+    /// let shift = 3;
+    ///
+    /// let ct = cks.encrypt_signed(msg);
+    ///
+    /// let ct_res = sks.signed_scalar_right_shift_parallelized(&ct, shift);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg >> shift);
+    /// ```
+    pub fn signed_scalar_right_shift_parallelized(
+        &self,
+        ct: &SignedRadixCiphertext,
+        shift: u64,
+    ) -> SignedRadixCiphertext {
+        let mut result = ct.clone();
+        self.signed_scalar_right_shift_assign_parallelized(&mut result, shift);
+        result
+    }
+
+    /// Computes homomorphically an arithmetic right shift by a clear amount, the result is
+    /// assigned to `ct`.
+    ///
+    /// The bits shifted in from the most significant side are copies of the sign bit.
+    /// The shift amount is taken modulo the number of bits in the ciphertext,
+    /// like [i64::wrapping_shr].
+    ///
+    /// The carries of `ct` are cleared before the shift if needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -117i8;
+    /// let shift = 3;
+    ///
+    /// let mut ct = cks.encrypt_signed(msg);
+    ///
+    /// sks.signed_scalar_right_shift_assign_parallelized(&mut ct, shift);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct);
+    /// assert_eq!(dec_result, msg >> shift);
+    /// ```
+    pub fn signed_scalar_right_shift_assign_parallelized(
+        &self,
+        ct: &mut SignedRadixCiphertext,
+        shift: u64,
+    ) {
+        if !ct.block_carries_are_empty() {
+            *ct = SignedRadixCiphertext::from_unsigned(self.signed_to_clean_unsigned(ct));
+        }
+        self.unchecked_signed_scalar_right_shift_assign_parallelized(ct, shift);
+    }
+
+    /// Computes homomorphically an arithmetic right shift by an encrypted amount.
+    ///
+    /// `shift` must have the same number of blocks as `ct`.
+    ///
+    /// # Requirements
+    ///
+    /// - The input ciphertexts carry buffers are empty / clean
+    /// - The blocks have at least 3 bits (message + carry)
+    pub fn unchecked_signed_right_shift_parallelized(
+        &self,
+        ct: &SignedRadixCiphertext,
+        shift: &RadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut result = ct.to_unsigned();
+        self.barrel_shifter(
+            &mut result,
+            shift,
+            BarrelShifterOperation::ArithmeticRightShift,
+        );
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+
+    /// Computes homomorphically an arithmetic right shift by an encrypted amount.
+    ///
+    /// `shift` must have the same number of blocks as `ct`.
+    /// As for the logical shift, only the low-order bits of `shift` needed
+    /// to index a bit of `ct` are used, higher-order bits are ignored.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = -117i8;
+    /// let shift = 5u8;
+    ///
+    /// let ct = cks.encrypt_signed(msg);
+    /// let shift_ct = cks.encrypt(shift);
+    ///
+    /// let ct_res = sks.signed_right_shift_parallelized(&ct, &shift_ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: i8 = cks.decrypt_signed(&ct_res);
+    /// assert_eq!(dec_result, msg >> shift);
+    /// ```
+    pub fn signed_right_shift_parallelized(
+        &self,
+        ct: &SignedRadixCiphertext,
+        shift: &RadixCiphertext,
+    ) -> SignedRadixCiphertext {
+        let mut tmp_shift: RadixCiphertext;
+        let shift = if shift.block_carries_are_empty() {
+            shift
+        } else {
+            tmp_shift = shift.clone();
+            self.full_propagate_parallelized(&mut tmp_shift);
+            &tmp_shift
+        };
+        let mut result = self.signed_to_clean_unsigned(ct);
+        self.barrel_shifter(
+            &mut result,
+            shift,
+            BarrelShifterOperation::ArithmeticRightShift,
+        );
+        SignedRadixCiphertext::from_unsigned(result)
+    }
+}
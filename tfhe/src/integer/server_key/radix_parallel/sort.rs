@@ -0,0 +1,452 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+use rayon::prelude::*;
+use std::borrow::{Borrow, Cow};
+
+/// Returns the comparators of Batcher's odd-even merge sort network for `len` elements,
+/// grouped in layers.
+///
+/// Within a layer, the comparators touch disjoint pairs of indices and can thus be
+/// computed in parallel. The pairs `(i, j)` always have `i < j`.
+///
+/// When `len` is not a power of two, the network of the next power of two is used
+/// and the comparators involving the missing elements are dropped, which is equivalent to
+/// padding the input with elements greater than all the others.
+fn odd_even_merge_sort_layers(len: usize) -> Vec<Vec<(usize, usize)>> {
+    let mut layers = Vec::new();
+
+    let mut p = 1;
+    while p < len {
+        let mut k = p;
+        while k >= 1 {
+            let mut layer = Vec::new();
+            let mut j = k % p;
+            while j + k < len {
+                for i in 0..k.min(len - j - k) {
+                    if (i + j) / (2 * p) == (i + j + k) / (2 * p) {
+                        layer.push((i + j, i + j + k));
+                    }
+                }
+                j += 2 * k;
+            }
+            layers.push(layer);
+            k /= 2;
+        }
+        p *= 2;
+    }
+
+    layers
+}
+
+/// Comparator of a sorting network, whose outputs may not all be needed
+#[derive(Copy, Clone)]
+struct Comparator {
+    i: usize,
+    j: usize,
+    /// Whether the min, stored at `i`, is needed
+    needs_min: bool,
+    /// Whether the max, stored at `j`, is needed
+    needs_max: bool,
+}
+
+/// Returns the layers of [odd_even_merge_sort_layers] restricted to the comparators
+/// that contribute to the `k` last (i.e. greatest) outputs.
+fn pruned_sort_layers(len: usize, k: usize) -> Vec<Vec<Comparator>> {
+    let mut is_needed = vec![false; len];
+    for needed in is_needed.iter_mut().skip(len.saturating_sub(k)) {
+        *needed = true;
+    }
+
+    let mut layers = odd_even_merge_sort_layers(len)
+        .into_iter()
+        .rev()
+        .map(|layer| {
+            let layer = layer
+                .into_iter()
+                .filter_map(|(i, j)| {
+                    let (needs_min, needs_max) = (is_needed[i], is_needed[j]);
+                    (needs_min || needs_max).then_some(Comparator {
+                        i,
+                        j,
+                        needs_min,
+                        needs_max,
+                    })
+                })
+                .collect::<Vec<_>>();
+            // Both inputs of a comparator are needed to compute any of its outputs
+            for comparator in &layer {
+                is_needed[comparator.i] = true;
+                is_needed[comparator.j] = true;
+            }
+            layer
+        })
+        .filter(|layer| !layer.is_empty())
+        .collect::<Vec<_>>();
+    layers.reverse();
+
+    layers
+}
+
+impl ServerKey {
+    /// Sorts the ciphertexts in ascending order.
+    ///
+    /// The sort uses Batcher's odd-even merge sort network, each comparator being a
+    /// [Self::unchecked_min_parallelized] and a [Self::unchecked_max_parallelized].
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - all the ciphertexts must have the same number of blocks
+    pub fn unchecked_sort_parallelized<T>(&self, cts: &[T]) -> Vec<RadixCiphertext>
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        self.unchecked_partial_sort_parallelized(cts, cts.len())
+            .into_iter()
+            .map(Cow::into_owned)
+            .collect()
+    }
+
+    /// Sorts the ciphertexts in ascending order.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [97u64, 14, 42, 3];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let sorted = sks.sort_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let dec_result = sorted
+    ///     .iter()
+    ///     .map(|ct| cks.decrypt(ct))
+    ///     .collect::<Vec<u64>>();
+    /// assert_eq!(dec_result, vec![3, 14, 42, 97]);
+    /// ```
+    pub fn sort_parallelized<T>(&self, cts: &[T]) -> Vec<RadixCiphertext>
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let cts = self.propagated_ciphertexts(cts);
+        self.unchecked_sort_parallelized(&cts)
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order.
+    ///
+    /// If `k` is greater than the number of ciphertexts, all of them are returned.
+    ///
+    /// This uses the sorting network of [Self::unchecked_sort_parallelized], from which only
+    /// the comparators contributing to the `k` greatest values are computed.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - all the ciphertexts must have the same number of blocks
+    pub fn unchecked_top_k_parallelized<T>(&self, cts: &[T], k: usize) -> Vec<RadixCiphertext>
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let sorted = self.unchecked_partial_sort_parallelized(cts, k);
+        sorted
+            .into_iter()
+            .rev()
+            .take(k)
+            .map(Cow::into_owned)
+            .collect()
+    }
+
+    /// Returns the `k` greatest ciphertexts, in descending order.
+    ///
+    /// If `k` is greater than the number of ciphertexts, all of them are returned.
+    ///
+    /// Only the part of the sort of the ciphertexts (see [Self::sort_parallelized]) that
+    /// computes the `k` greatest values is done.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [97u64, 14, 42, 3, 75];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let top = sks.top_k_parallelized(&cts, 2);
+    ///
+    /// // Decrypt:
+    /// let dec_result = top.iter().map(|ct| cks.decrypt(ct)).collect::<Vec<u64>>();
+    /// assert_eq!(dec_result, vec![97, 75]);
+    /// ```
+    pub fn top_k_parallelized<T>(&self, cts: &[T], k: usize) -> Vec<RadixCiphertext>
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let cts = self.propagated_ciphertexts(cts);
+        self.unchecked_top_k_parallelized(&cts, k)
+    }
+
+    /// Returns the encrypted position of the greatest ciphertext.
+    ///
+    /// If the greatest value appears several times, the position of its first occurrence
+    /// is returned. The position is encrypted with the same number of blocks as the inputs.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - `cts` must not be empty, and all its ciphertexts must have the same number of blocks
+    /// - the number of blocks must be enough to encrypt `cts.len() - 1`
+    pub fn unchecked_argmax_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        self.unchecked_arg_reduce_parallelized(cts, |lhs, rhs| {
+            self.unchecked_gt_parallelized(rhs, lhs)
+        })
+    }
+
+    /// Returns the encrypted position of the greatest ciphertext.
+    ///
+    /// If the greatest value appears several times, the position of its first occurrence
+    /// is returned. The position is encrypted with the same number of blocks as the inputs.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [14u64, 97, 42, 97, 3];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.argmax_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 1);
+    /// ```
+    pub fn argmax_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let cts = self.propagated_ciphertexts(cts);
+        self.unchecked_argmax_parallelized(&cts)
+    }
+
+    /// Returns the encrypted position of the smallest ciphertext.
+    ///
+    /// If the smallest value appears several times, the position of its first occurrence
+    /// is returned. The position is encrypted with the same number of blocks as the inputs.
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of all the ciphertexts must be empty
+    /// - `cts` must not be empty, and all its ciphertexts must have the same number of blocks
+    /// - the number of blocks must be enough to encrypt `cts.len() - 1`
+    pub fn unchecked_argmin_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        self.unchecked_arg_reduce_parallelized(cts, |lhs, rhs| {
+            self.unchecked_lt_parallelized(rhs, lhs)
+        })
+    }
+
+    /// Returns the encrypted position of the smallest ciphertext.
+    ///
+    /// If the smallest value appears several times, the position of its first occurrence
+    /// is returned. The position is encrypted with the same number of blocks as the inputs.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let clears = [14u64, 97, 3, 42, 3];
+    /// let cts = clears
+    ///     .iter()
+    ///     .map(|&clear| cks.encrypt(clear))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let ct_res = sks.argmin_parallelized(&cts);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 2);
+    /// ```
+    pub fn argmin_parallelized<T>(&self, cts: &[T]) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let cts = self.propagated_ciphertexts(cts);
+        self.unchecked_argmin_parallelized(&cts)
+    }
+
+    /// Reduces the ciphertexts pairwise, in a tree, keeping track of the position of the
+    /// selected value.
+    ///
+    /// `replace_with_rhs(lhs, rhs)` must return an encryption of 1 if `rhs` must be kept instead
+    /// of `lhs`, `lhs` always being at a lower position than `rhs`.
+    fn unchecked_arg_reduce_parallelized<T, F>(
+        &self,
+        cts: &[T],
+        replace_with_rhs: F,
+    ) -> RadixCiphertext
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+        F: Fn(&RadixCiphertext, &RadixCiphertext) -> RadixCiphertext + Sync,
+    {
+        assert!(
+            !cts.is_empty(),
+            "Cannot compute the position in an empty array"
+        );
+        let num_blocks = cts[0].borrow().blocks.len();
+        let bits_per_block = self.key.message_modulus.0.ilog2();
+        let max_index = cts.len() as u64 - 1;
+        assert!(
+            num_blocks as u32 * bits_per_block >= u64::BITS
+                || max_index >> (num_blocks as u32 * bits_per_block) == 0,
+            "The ciphertexts do not have enough blocks to encrypt the position {max_index}"
+        );
+
+        let mut candidates = cts
+            .iter()
+            .enumerate()
+            .map(|(i, ct)| {
+                let index = self.create_trivial_radix(i as u64, num_blocks);
+                (Cow::Borrowed(ct.borrow()), index)
+            })
+            .collect::<Vec<_>>();
+
+        while candidates.len() > 1 {
+            candidates = candidates
+                .into_par_iter()
+                .chunks(2)
+                .map(|mut pair| {
+                    if pair.len() == 1 {
+                        return pair.pop().unwrap();
+                    }
+                    let (rhs, rhs_index) = pair.pop().unwrap();
+                    let (lhs, lhs_index) = pair.pop().unwrap();
+
+                    let condition = replace_with_rhs(&lhs, &rhs);
+                    let (selected, selected_index) = rayon::join(
+                        || self.unchecked_if_then_else_parallelized(&condition, &rhs, &lhs),
+                        || {
+                            self.unchecked_if_then_else_parallelized(
+                                &condition, &rhs_index, &lhs_index,
+                            )
+                        },
+                    );
+                    (Cow::Owned(selected), selected_index)
+                })
+                .collect();
+        }
+
+        candidates.pop().unwrap().1
+    }
+
+    /// Sorts the ciphertexts in ascending order, but only computes the `k` last (i.e. greatest)
+    /// values, the other positions of the result are meaningless.
+    fn unchecked_partial_sort_parallelized<'a, T>(
+        &self,
+        cts: &'a [T],
+        k: usize,
+    ) -> Vec<Cow<'a, RadixCiphertext>>
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        let mut sorted = cts
+            .iter()
+            .map(|ct| Cow::Borrowed(ct.borrow()))
+            .collect::<Vec<_>>();
+
+        for layer in pruned_sort_layers(sorted.len(), k) {
+            let swapped = layer
+                .par_iter()
+                .map(|comparator| {
+                    let (lhs, rhs) = (sorted[comparator.i].as_ref(), sorted[comparator.j].as_ref());
+                    rayon::join(
+                        || {
+                            comparator
+                                .needs_min
+                                .then(|| self.unchecked_min_parallelized(lhs, rhs))
+                        },
+                        || {
+                            comparator
+                                .needs_max
+                                .then(|| self.unchecked_max_parallelized(lhs, rhs))
+                        },
+                    )
+                })
+                .collect::<Vec<_>>();
+
+            for (comparator, (min, max)) in layer.iter().zip(swapped) {
+                if let Some(min) = min {
+                    sorted[comparator.i] = Cow::Owned(min);
+                }
+                if let Some(max) = max {
+                    sorted[comparator.j] = Cow::Owned(max);
+                }
+            }
+        }
+
+        sorted
+    }
+
+    /// Returns the ciphertexts with empty carries,
+    /// only the ciphertexts whose carries are not empty are copied to be propagated
+    fn propagated_ciphertexts<'a, T>(&self, cts: &'a [T]) -> Vec<Cow<'a, RadixCiphertext>>
+    where
+        T: Borrow<RadixCiphertext> + Sync,
+    {
+        cts.par_iter()
+            .map(|ct| self.propagated_radix(ct.borrow()))
+            .collect()
+    }
+}
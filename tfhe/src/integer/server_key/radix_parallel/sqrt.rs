@@ -0,0 +1,108 @@
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::ServerKey;
+
+impl ServerKey {
+    /// Computes homomorphically the integer square root of the ciphertext,
+    /// that is the greatest value whose square is smaller than or equal to the ciphertext
+    ///
+    /// # Requirements
+    ///
+    /// - the carries of `ct` must be empty
+    pub fn unchecked_isqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let num_blocks = ct.blocks.len();
+        let bits_per_block = self.key.message_modulus.0.ilog2() as usize;
+        let num_bits = num_blocks * bits_per_block;
+
+        let trivial_power_of_two = |exponent: usize| {
+            let mut ct = self.create_trivial_zero_radix(num_blocks);
+            ct.blocks[exponent / bits_per_block] =
+                self.key.create_trivial(1 << (exponent % bits_per_block));
+            ct
+        };
+
+        // The root is computed bit by bit, starting from the most significant one.
+        //
+        // At each step, `remainder` holds what is left of the input once the square of
+        // the root bits found so far is removed, and `result` holds these root bits
+        // shifted so that the next candidate bit can be added with a power of four.
+        let mut remainder = ct.clone();
+        let mut result = self.create_trivial_zero_radix(num_blocks);
+        // Start from the highest power of four the ciphertext can hold
+        let mut exponent = (num_bits - 1) & !1;
+        loop {
+            let power_of_four = trivial_power_of_two(exponent);
+
+            let ((new_remainder, borrow), (shifted_result, new_result)) = rayon::join(
+                || {
+                    let candidate = self.add_parallelized(&result, &power_of_four);
+                    self.overflowing_sub_parallelized(&remainder, &candidate)
+                },
+                || {
+                    let shifted_result = self.scalar_right_shift_parallelized(&result, 1);
+                    let new_result = self.add_parallelized(&shifted_result, &power_of_four);
+                    (shifted_result, new_result)
+                },
+            );
+
+            // The candidate bit belongs to the root if the subtraction did not overflow
+            let borrow = RadixCiphertext::from(vec![borrow]);
+            let (next_remainder, next_result) = rayon::join(
+                || self.if_then_else_parallelized(&borrow, &remainder, &new_remainder),
+                || self.if_then_else_parallelized(&borrow, &shifted_result, &new_result),
+            );
+            remainder = next_remainder;
+            result = next_result;
+
+            if exponent < 2 {
+                break;
+            }
+            exponent -= 2;
+        }
+
+        result
+    }
+
+    /// Computes homomorphically the integer square root of the ciphertext,
+    /// that is the greatest value whose square is smaller than or equal to the ciphertext
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertext block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Warning
+    ///
+    /// - Multithreaded
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let msg = 200u64;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let ct_res = sks.isqrt_parallelized(&ct);
+    ///
+    /// // Decrypt:
+    /// let dec_result: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec_result, 14);
+    /// ```
+    pub fn isqrt_parallelized(&self, ct: &RadixCiphertext) -> RadixCiphertext {
+        let mut tmp_ct;
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+        self.unchecked_isqrt_parallelized(ct)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::integer::ciphertext::RadixCiphertext;
 use crate::integer::ServerKey;
+use crate::shortint::Ciphertext;
 
 impl ServerKey {
     /// Computes homomorphically the subtraction between ct_left and ct_right.
@@ -219,6 +220,124 @@ impl ServerKey {
         }
     }
 
+    /// Computes homomorphically the subtraction between ct_left and ct_right,
+    /// and whether the subtraction overflowed.
+    ///
+    /// The returned block encrypts 1 if ct_right is greater than ct_left (i.e. the result
+    /// wrapped around), 0 otherwise.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg_1 = 120u8;
+    /// let msg_2 = 181u8;
+    ///
+    /// // Encrypt two messages:
+    /// let ctxt_1 = cks.encrypt(msg_1 as u64);
+    /// let ctxt_2 = cks.encrypt(msg_2 as u64);
+    ///
+    /// // Compute homomorphically a subtraction
+    /// let (ct_res, overflowed) = sks.overflowing_sub_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// let dec_overflowed = cks.decrypt_one_block(&overflowed);
+    /// let (expected_res, expected_overflowed) = msg_1.overflowing_sub(msg_2);
+    /// assert_eq!(res, expected_res);
+    /// assert_eq!(dec_overflowed, u64::from(expected_overflowed));
+    /// ```
+    pub fn overflowing_sub_parallelized(
+        &self,
+        ctxt_left: &RadixCiphertext,
+        ctxt_right: &RadixCiphertext,
+    ) -> (RadixCiphertext, Ciphertext) {
+        let mut ct_res = ctxt_left.clone();
+
+        // lhs - rhs is computed as lhs + !rhs + 1, so that the carry output
+        // by the addition is 1 if and only if the subtraction does not overflow
+        let ((), not_rhs) = rayon::join(
+            || {
+                if !ct_res.block_carries_are_empty() {
+                    self.full_propagate_parallelized(&mut ct_res);
+                }
+            },
+            || self.bitnot_parallelized(ctxt_right),
+        );
+
+        self.unchecked_add_assign_parallelized(&mut ct_res, &not_rhs);
+        self.key
+            .unchecked_scalar_add_assign(&mut ct_res.blocks[0], 1);
+
+        let message_modulus = self.key.message_modulus.0 as u64;
+        let lut_does_block_not_output_carry = self
+            .key
+            .generate_lookup_table(|x| u64::from(x < message_modulus));
+        let overflowed = self.propagate_single_carry_get_output_carry_parallelized(
+            &mut ct_res,
+            &lut_does_block_not_output_carry,
+        );
+
+        (ct_res, overflowed)
+    }
+
+    /// Computes homomorphically a saturating subtraction between ct_left and ct_right.
+    ///
+    /// If ct_right is greater than ct_left, the result is clamped to 0
+    /// instead of wrapping around.
+    ///
+    /// This function, like all "default" operations (i.e. not smart, checked or unchecked), will
+    /// check that the input ciphertexts block carries are empty and clears them if it's not the
+    /// case and the operation requires it. It outputs a ciphertext whose block carries are always
+    /// empty.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let size = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, size);
+    ///
+    /// let msg_1 = 120u8;
+    /// let msg_2 = 181u8;
+    ///
+    /// // Encrypt two messages:
+    /// let ctxt_1 = cks.encrypt(msg_1 as u64);
+    /// let ctxt_2 = cks.encrypt(msg_2 as u64);
+    ///
+    /// // Compute homomorphically a subtraction
+    /// let ct_res = sks.saturating_sub_parallelized(&ctxt_1, &ctxt_2);
+    ///
+    /// // Decrypt:
+    /// let res: u8 = cks.decrypt(&ct_res);
+    /// assert_eq!(res, msg_1.saturating_sub(msg_2));
+    /// ```
+    pub fn saturating_sub_parallelized(
+        &self,
+        ctxt_left: &RadixCiphertext,
+        ctxt_right: &RadixCiphertext,
+    ) -> RadixCiphertext {
+        let (mut ct_res, overflowed) = self.overflowing_sub_parallelized(ctxt_left, ctxt_right);
+
+        self.unchecked_set_blocks_if_assign_parallelized(&mut ct_res, &overflowed, 0);
+
+        ct_res
+    }
+
     pub fn sub_parallelized_work_efficient(
         &self,
         ctxt_left: &RadixCiphertext,
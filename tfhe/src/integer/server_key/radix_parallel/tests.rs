@@ -1,5 +1,6 @@
+use crate::core_crypto::commons::math::random::Seed;
 use crate::integer::keycache::KEY_CACHE;
-use crate::integer::{RadixClientKey, ServerKey};
+use crate::integer::{RadixClientKey, ServerKey, U256};
 use crate::shortint::parameters::*;
 use paste::paste;
 use rand::Rng;
@@ -182,6 +183,71 @@ create_parametrized_test!(integer_smart_scalar_sub);
 create_parametrized_test!(integer_default_scalar_sub);
 create_parametrized_test!(integer_smart_scalar_add);
 create_parametrized_test!(integer_default_scalar_add);
+create_parametrized_test!(integer_smart_if_then_else);
+create_parametrized_test!(integer_default_if_then_else);
+create_parametrized_test!(integer_default_overflowing_add);
+create_parametrized_test!(integer_default_overflowing_sub);
+create_parametrized_test!(integer_default_overflowing_mul);
+create_parametrized_test!(integer_default_saturating_add);
+create_parametrized_test!(integer_default_saturating_sub);
+create_parametrized_test!(integer_default_saturating_mul);
+create_parametrized_test!(integer_default_bit_counting);
+create_parametrized_test!(integer_default_sum_ciphertexts);
+create_parametrized_test!(integer_default_dot_product_with_clear);
+create_parametrized_test!(integer_default_isqrt);
+create_parametrized_test!(integer_default_scalar_pow);
+create_parametrized_test!(integer_default_pow);
+create_parametrized_test!(integer_default_modular_arithmetic);
+create_parametrized_test!(integer_default_pow_mod);
+create_parametrized_test!(integer_default_modular_arithmetic_wide_modulus {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS
+});
+create_parametrized_test!(integer_default_select_at_index);
+create_parametrized_test!(integer_default_write_at_index);
+create_parametrized_test!(integer_default_sort);
+create_parametrized_test!(integer_default_argmax_argmin);
+create_parametrized_test!(integer_default_extract_and_pack_bits);
+create_parametrized_test!(integer_default_oblivious_pseudo_random);
+// signed integers
+create_parametrized_test!(integer_signed_default_add);
+create_parametrized_test!(integer_signed_default_sub);
+create_parametrized_test!(integer_signed_default_mul);
+create_parametrized_test!(integer_signed_default_neg);
+create_parametrized_test!(integer_signed_default_abs);
+create_parametrized_test!(integer_signed_default_scalar_right_shift);
+create_parametrized_test!(integer_signed_default_right_shift {
+    // This algorithm requires 3 bits
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_signed_default_div_rem {
+    // Due to the use of comparison,
+    // this algorithm requires 3 bits
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
+create_parametrized_test!(integer_signed_default_comparisons {
+    // Due to the use of comparison,
+    // this algorithm requires 3 bits
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_3_CARRY_3_KS_PBS,
+    PARAM_MESSAGE_4_CARRY_4_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_3_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_2_KS_PBS,
+    PARAM_MULTI_BIT_MESSAGE_3_CARRY_3_GROUP_3_KS_PBS
+});
 
 fn integer_smart_add<P>(param: P)
 where
@@ -2767,3 +2833,1353 @@ where
         }
     }
 }
+
+fn integer_smart_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_range(0u64..2);
+
+        let mut ctxt_0 = cks.encrypt(clear_0);
+        let mut ctxt_1 = cks.encrypt(clear_1);
+        let mut ctxt_condition = cks.encrypt(clear_condition);
+
+        // add to fill the carries
+        sks.unchecked_add_assign(&mut ctxt_0, &ctxt_1);
+        let clear_0 = (clear_0 + clear_1) % modulus;
+
+        let ct_res =
+            sks.smart_if_then_else_parallelized(&mut ctxt_condition, &mut ctxt_0, &mut ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = if clear_condition == 1 {
+            clear_0
+        } else {
+            clear_1
+        };
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_default_if_then_else<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_condition = rng.gen_range(0u64..2);
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        let ctxt_condition = cks.encrypt(clear_condition);
+
+        let ct_res = sks.if_then_else_parallelized(&ctxt_condition, &ctxt_0, &ctxt_1);
+        let tmp = sks.if_then_else_parallelized(&ctxt_condition, &ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = if clear_condition == 1 {
+            clear_0
+        } else {
+            clear_1
+        };
+        assert_eq!(dec_res, expected);
+    }
+}
+fn integer_default_overflowing_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.overflowing_add_parallelized(&ctxt_0, &ctxt_1);
+        let (tmp, tmp_overflowed) = sks.overflowing_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+        assert_eq!(overflowed, tmp_overflowed);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        let expected_result = clear_0 + clear_1;
+        let expected_overflowed = expected_result >= modulus;
+        assert_eq!(dec_res, expected_result % modulus);
+        assert_eq!(dec_overflowed, u64::from(expected_overflowed));
+    }
+}
+
+fn integer_default_overflowing_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.overflowing_sub_parallelized(&ctxt_0, &ctxt_1);
+        let (tmp, tmp_overflowed) = sks.overflowing_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+        assert_eq!(overflowed, tmp_overflowed);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        let expected_result = clear_0.wrapping_sub(clear_1).wrapping_add(modulus);
+        let expected_overflowed = clear_0 < clear_1;
+        assert_eq!(dec_res, expected_result % modulus);
+        assert_eq!(dec_overflowed, u64::from(expected_overflowed));
+    }
+}
+
+fn integer_default_overflowing_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let (ct_res, overflowed) = sks.overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        let (tmp, tmp_overflowed) = sks.overflowing_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+        assert_eq!(overflowed, tmp_overflowed);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let dec_overflowed = cks.decrypt_one_block(&overflowed);
+        let expected_result = clear_0 * clear_1;
+        let expected_overflowed = expected_result >= modulus;
+        assert_eq!(dec_res, expected_result % modulus);
+        assert_eq!(dec_overflowed, u64::from(expected_overflowed));
+    }
+}
+
+fn integer_default_saturating_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        let tmp = sks.saturating_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 + clear_1).min(modulus - 1));
+    }
+}
+
+fn integer_default_saturating_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        let tmp = sks.saturating_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear_0.saturating_sub(clear_1));
+    }
+}
+
+fn integer_default_saturating_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.saturating_mul_parallelized(&ctxt_0, &ctxt_1);
+        let tmp = sks.saturating_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (clear_0 * clear_1).min(modulus - 1));
+    }
+}
+
+fn integer_default_bit_counting<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    let num_bits = cks.parameters().message_modulus().0.ilog2() * NB_CTXT as u32;
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        // Shift the value so that it has a random number of trailing zeros
+        let clear = (rng.gen::<u64>() % modulus) >> rng.gen_range(0..num_bits);
+        let clear = (clear << rng.gen_range(0..num_bits)) % modulus;
+
+        let ctxt = cks.encrypt(clear);
+
+        // Count the bits of the value as if it was a num_bits integer
+        let leading_zeros = clear.leading_zeros() - (u64::BITS - num_bits);
+        let trailing_zeros = clear.trailing_zeros().min(num_bits);
+
+        let ct_res = sks.leading_zeros_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(leading_zeros));
+
+        let ct_res = sks.trailing_zeros_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(trailing_zeros));
+
+        let ct_res = sks.count_ones_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(clear.count_ones()));
+
+        let ct_res = sks.count_zeros_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, u64::from(num_bits - clear.count_ones()));
+
+        let ct_res = sks.ilog2_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = if clear == 0 {
+            modulus - 1
+        } else {
+            u64::from(clear.ilog2())
+        };
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_default_sum_ciphertexts<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for len in [1, 2, 15, 16, 17] {
+        for _ in 0..NB_TEST_SMALLER {
+            let clears = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+
+            let mut ctxts = clears
+                .iter()
+                .copied()
+                .map(|clear| cks.encrypt(clear))
+                .collect::<Vec<_>>();
+            // Inputs with non empty carries are accepted
+            let first = ctxts[0].clone();
+            sks.unchecked_add_assign(&mut ctxts[0], &first);
+            let clear = (clears.iter().sum::<u64>() + clears[0]) % modulus;
+
+            let ct_res = sks.sum_ciphertexts_parallelized(&ctxts).unwrap();
+            let tmp_ct = sks.sum_ciphertexts_parallelized(&ctxts).unwrap();
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res, tmp_ct);
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec_res, clear);
+        }
+    }
+
+    assert!(sks.sum_ciphertexts_parallelized(&[]).is_none());
+}
+
+fn integer_default_dot_product_with_clear<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for len in [1, 2, 7] {
+        for _ in 0..NB_TEST_SMALLER {
+            let clears = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+            let weights = (0..len)
+                .map(|_| rng.gen::<u64>() % modulus)
+                .collect::<Vec<_>>();
+
+            let ctxts = clears
+                .iter()
+                .copied()
+                .map(|clear| cks.encrypt(clear))
+                .collect::<Vec<_>>();
+
+            let ct_res = sks.dot_product_with_clear_parallelized(&ctxts, &weights);
+            let tmp_ct = sks.dot_product_with_clear_parallelized(&ctxts, &weights);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res, tmp_ct);
+
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            let expected = clears
+                .iter()
+                .zip(weights.iter())
+                .fold(0u64, |acc, (clear, weight)| {
+                    (acc + clear * weight) % modulus
+                });
+            assert_eq!(dec_res, expected);
+        }
+    }
+}
+
+fn integer_default_isqrt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    // Also check the edge values
+    let clears = [0, 1, modulus - 1]
+        .into_iter()
+        .chain((0..NB_TEST_SMALLER).map(|_| rng.gen::<u64>() % modulus));
+    for clear in clears {
+        let ctxt = cks.encrypt(clear);
+
+        let ct_res = sks.isqrt_parallelized(&ctxt);
+        let tmp_ct = sks.isqrt_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (0..=clear).take_while(|x| x * x <= clear).last().unwrap();
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_default_scalar_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen_range(0..20u64);
+
+        let mut ctxt = cks.encrypt(clear);
+        // Make sure the carries are dirty
+        sks.unchecked_scalar_add_assign(&mut ctxt, 1);
+        let clear = (clear + 1) % modulus;
+
+        let ct_res = sks.scalar_pow_parallelized(&ctxt, exponent);
+        let tmp_ct = sks.scalar_pow_parallelized(&ctxt, exponent);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear.wrapping_pow(exponent as u32) % modulus);
+    }
+}
+
+fn integer_default_pow<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    // The exponent does not need to have as many blocks as the base
+    let exponent_num_blocks = 2;
+    let exponent_modulus = cks
+        .parameters()
+        .message_modulus()
+        .0
+        .pow(exponent_num_blocks as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen::<u64>() % exponent_modulus;
+
+        let ctxt = cks.encrypt(clear);
+        let ctxt_exponent = cks.as_ref().encrypt_radix(exponent, exponent_num_blocks);
+
+        let ct_res = sks.pow_parallelized(&ctxt, &ctxt_exponent);
+        let tmp_ct = sks.pow_parallelized(&ctxt, &ctxt_exponent);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear.wrapping_pow(exponent as u32) % modulus);
+    }
+}
+
+fn integer_default_modular_arithmetic<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        // The inputs are not necessarily reduced modulo the clear modulus
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+        let clear_modulus = rng.gen_range(1..=modulus);
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+
+        let reduced_0 = clear_0 % clear_modulus;
+        let reduced_1 = clear_1 % clear_modulus;
+
+        let ct_res = sks.add_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        let tmp_ct = sks.add_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (reduced_0 + reduced_1) % clear_modulus);
+
+        let ct_res = sks.sub_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        let tmp_ct = sks.sub_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(
+            dec_res,
+            (reduced_0 + clear_modulus - reduced_1) % clear_modulus
+        );
+
+        let ct_res = sks.mul_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        let tmp_ct = sks.mul_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, (reduced_0 * reduced_1) % clear_modulus);
+    }
+}
+
+fn integer_default_pow_mod<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let exponent_modulus = cks.parameters().message_modulus().0 as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = rng.gen::<u64>() % modulus;
+        let exponent = rng.gen::<u64>() % exponent_modulus;
+        let clear_modulus = rng.gen_range(1..=modulus);
+
+        let ctxt = cks.encrypt(clear);
+        // A one block exponent
+        let ctxt_exponent = cks.as_ref().encrypt_radix(exponent, 1);
+
+        let ct_res = sks.pow_mod_parallelized(&ctxt, &ctxt_exponent, clear_modulus);
+        let tmp_ct = sks.pow_mod_parallelized(&ctxt, &ctxt_exponent, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let expected = (0..exponent).fold(1 % clear_modulus, |acc, _| {
+            (acc * (clear % clear_modulus)) % clear_modulus
+        });
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_default_modular_arithmetic_wide_modulus<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    let message_modulus = cks.parameters().message_modulus().0;
+    let bits_per_block = message_modulus.ilog2();
+
+    // With 48 bits, the moduli are too big for the product to be reduced with a scalar
+    // division, so the multiplication is interleaved with the reduction.
+    // With 72 bits, the moduli do not fit in a u64, so the inputs are reduced with an encrypted
+    // division.
+    for num_bits in [48, 72] {
+        let num_blocks = ((num_bits + bits_per_block - 1) / bits_per_block) as usize;
+        let max_value = 1u128 << num_bits;
+
+        // Odd, so that the modulus is not a power of two
+        let clear_modulus = U256::from(rng.gen_range(max_value / 256..max_value) | 1);
+        let clear_0 = U256::from(rng.gen::<u128>() % max_value);
+        let clear_1 = U256::from(rng.gen::<u128>() % max_value);
+        let exponent = rng.gen::<u64>() % message_modulus as u64;
+
+        let ctxt_0 = cks.encrypt_radix(clear_0, num_blocks);
+        let ctxt_1 = cks.encrypt_radix(clear_1, num_blocks);
+        let ctxt_exponent = cks.encrypt_radix(exponent, 1);
+
+        let reduced_0 = clear_0 % clear_modulus;
+        let reduced_1 = clear_1 % clear_modulus;
+
+        let ct_res = sks.add_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: U256 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, (reduced_0 + reduced_1) % clear_modulus);
+
+        let ct_res = sks.sub_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: U256 = cks.decrypt_radix(&ct_res);
+        assert_eq!(
+            dec_res,
+            (reduced_0 + clear_modulus - reduced_1) % clear_modulus
+        );
+
+        let ct_res = sks.mul_mod_parallelized(&ctxt_0, &ctxt_1, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: U256 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec_res, (reduced_0 * reduced_1) % clear_modulus);
+
+        let ct_res = sks.pow_mod_parallelized(&ctxt_0, &ctxt_exponent, clear_modulus);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: U256 = cks.decrypt_radix(&ct_res);
+        let expected = (0..exponent).fold(U256::ONE, |acc, _| (acc * reduced_0) % clear_modulus);
+        assert_eq!(dec_res, expected);
+    }
+}
+
+//=============================================================================
+// Signed integers
+//=============================================================================
+
+/// Sign-extends the `nb_bits` low bits of `value`,
+/// i.e. wraps `value` the way a `nb_bits` two's complement integer would
+fn signed_wrap(value: i64, nb_bits: u32) -> i64 {
+    let shift = i64::BITS - nb_bits;
+    (value << shift) >> shift
+}
+
+/// Returns the number of bits of a signed radix ciphertext of `NB_CTXT` blocks
+/// and the random generator of clear values in its range
+fn signed_nb_bits_and_rng(cks: &RadixClientKey) -> (u32, impl FnMut() -> i64) {
+    let nb_bits = cks.parameters().message_modulus().0.ilog2() * NB_CTXT as u32;
+    let half_modulus = 1i64 << (nb_bits - 1);
+    let mut rng = rand::thread_rng();
+    (nb_bits, move || rng.gen_range(-half_modulus..half_modulus))
+}
+
+fn integer_signed_default_add<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = gen_clear();
+        let clear_1 = gen_clear();
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let mut ct_res = sks.signed_add_parallelized(&ctxt_0, &ctxt_1);
+        let tmp_ct = sks.signed_add_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+
+        let mut clear = signed_wrap(clear_0 + clear_1, nb_bits);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(clear, dec_res);
+
+        // add multiple times to raise the degree
+        for _ in 0..NB_TEST_SMALLER {
+            sks.signed_add_assign_parallelized(&mut ct_res, &ctxt_0);
+            assert!(ct_res.block_carries_are_empty());
+            clear = signed_wrap(clear + clear_0, nb_bits);
+
+            let dec_res: i64 = cks.decrypt_signed(&ct_res);
+            assert_eq!(clear, dec_res);
+        }
+    }
+}
+
+fn integer_signed_default_sub<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = gen_clear();
+        let clear_1 = gen_clear();
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let mut ct_res = sks.signed_sub_parallelized(&ctxt_0, &ctxt_1);
+        let tmp_ct = sks.signed_sub_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+
+        let mut clear = signed_wrap(clear_0 - clear_1, nb_bits);
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(clear, dec_res);
+
+        // sub multiple times to raise the degree
+        for _ in 0..NB_TEST_SMALLER {
+            sks.signed_sub_assign_parallelized(&mut ct_res, &ctxt_1);
+            assert!(ct_res.block_carries_are_empty());
+            clear = signed_wrap(clear - clear_1, nb_bits);
+
+            let dec_res: i64 = cks.decrypt_signed(&ct_res);
+            assert_eq!(clear, dec_res);
+        }
+    }
+}
+
+fn integer_signed_default_mul<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = gen_clear();
+        let clear_1 = gen_clear();
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let ct_res = sks.signed_mul_parallelized(&ctxt_0, &ctxt_1);
+        let tmp_ct = sks.signed_mul_parallelized(&ctxt_0, &ctxt_1);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(signed_wrap(clear_0.wrapping_mul(clear_1), nb_bits), dec_res);
+    }
+}
+
+fn integer_signed_default_neg<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = gen_clear();
+
+        let ctxt = cks.encrypt_signed(clear);
+
+        let ct_res = sks.signed_neg_parallelized(&ctxt);
+        let tmp = sks.signed_neg_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(signed_wrap(-clear, nb_bits), dec);
+    }
+}
+
+fn integer_signed_default_abs<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    // The minimum value is its own absolute value
+    let min_value = -(1i64 << (nb_bits - 1));
+    let ctxt = cks.encrypt_signed(min_value);
+    let ct_res = sks.signed_abs_parallelized(&ctxt);
+    let dec: i64 = cks.decrypt_signed(&ct_res);
+    assert_eq!(min_value, dec);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = gen_clear();
+
+        let ctxt = cks.encrypt_signed(clear);
+
+        let ct_res = sks.signed_abs_parallelized(&ctxt);
+        let tmp = sks.signed_abs_parallelized(&ctxt);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let dec: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(signed_wrap(clear.abs(), nb_bits), dec);
+    }
+}
+
+fn integer_signed_default_div_rem<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = gen_clear();
+        let mut clear_1 = gen_clear();
+        while clear_1 == 0 {
+            // avoid division by zero
+            clear_1 = gen_clear();
+        }
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let (q_res, r_res) = sks.signed_div_rem_parallelized(&ctxt_0, &ctxt_1);
+        let q: i64 = cks.decrypt_signed(&q_res);
+        let r: i64 = cks.decrypt_signed(&r_res);
+
+        assert!(q_res.block_carries_are_empty());
+        assert!(r_res.block_carries_are_empty());
+        // Only the minimum value divided by -1 overflows, and wraps
+        assert_eq!(signed_wrap(clear_0 / clear_1, nb_bits), q);
+        assert_eq!(clear_0 % clear_1, r);
+
+        // Test individual div/rem to check they are correctly bound
+        let q2_res = sks.signed_div_parallelized(&ctxt_0, &ctxt_1);
+        let q2: i64 = cks.decrypt_signed(&q2_res);
+        assert!(q2_res.block_carries_are_empty());
+
+        let r2_res = sks.signed_rem_parallelized(&ctxt_0, &ctxt_1);
+        let r2: i64 = cks.decrypt_signed(&r2_res);
+        assert!(r2_res.block_carries_are_empty());
+
+        // Determinism checks
+        assert_eq!(q2, q, "Operation was not deterministic");
+        assert_eq!(r2, r, "Operation was not deterministic");
+    }
+}
+
+fn integer_signed_default_scalar_right_shift<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = gen_clear();
+        let scalar = rng.gen::<u32>();
+
+        let ct = cks.encrypt_signed(clear);
+
+        // case when 0<= scalar < nb_bits
+        {
+            let scalar = scalar % nb_bits;
+            let ct_res = sks.signed_scalar_right_shift_parallelized(&ct, scalar as u64);
+            let tmp = sks.signed_scalar_right_shift_parallelized(&ct, scalar as u64);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res, tmp);
+            let dec_res: i64 = cks.decrypt_signed(&ct_res);
+            assert_eq!(clear >> scalar, dec_res);
+        }
+
+        // case when scalar >= nb_bits
+        {
+            let scalar = scalar.saturating_add(nb_bits);
+            let ct_res = sks.signed_scalar_right_shift_parallelized(&ct, scalar as u64);
+            assert!(ct_res.block_carries_are_empty());
+            let dec_res: i64 = cks.decrypt_signed(&ct_res);
+            assert_eq!(clear >> (scalar % nb_bits), dec_res);
+        }
+    }
+
+    let clear = gen_clear();
+
+    let ct = cks.encrypt_signed(clear);
+    let nb_bits_in_block = cks.parameters().message_modulus().0.ilog2();
+    for scalar in 0..nb_bits_in_block {
+        let ct_res = sks.signed_scalar_right_shift_parallelized(&ct, scalar as u64);
+        assert!(ct_res.block_carries_are_empty());
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(clear >> scalar, dec_res);
+    }
+}
+
+fn integer_signed_default_right_shift<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (nb_bits, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    let mut rng = rand::thread_rng();
+    for _ in 0..NB_TEST_SMALLER {
+        let clear = gen_clear();
+        let clear_shift = rng.gen_range(0..nb_bits as u64);
+
+        let ct = cks.encrypt_signed(clear);
+        let shift = cks.encrypt(clear_shift);
+
+        let ct_res = sks.signed_right_shift_parallelized(&ct, &shift);
+        let tmp = sks.signed_right_shift_parallelized(&ct, &shift);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp);
+
+        let dec_res: i64 = cks.decrypt_signed(&ct_res);
+        assert_eq!(clear >> clear_shift, dec_res);
+    }
+}
+
+fn integer_signed_default_comparisons<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let (_, mut gen_clear) = signed_nb_bits_and_rng(&cks);
+
+    for i in 0..NB_TEST_SMALLER {
+        let clear_0 = gen_clear();
+        // Also test equal values
+        let clear_1 = if i % 3 == 0 { clear_0 } else { gen_clear() };
+
+        let ctxt_0 = cks.encrypt_signed(clear_0);
+        let ctxt_1 = cks.encrypt_signed(clear_1);
+
+        let decrypt = |ct| -> i64 { cks.decrypt_signed(ct) };
+
+        let ct_res = sks.signed_eq_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), i64::from(clear_0 == clear_1));
+        let ct_res = sks.signed_ne_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), i64::from(clear_0 != clear_1));
+        let ct_res = sks.signed_gt_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), i64::from(clear_0 > clear_1));
+        let ct_res = sks.signed_ge_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), i64::from(clear_0 >= clear_1));
+        let ct_res = sks.signed_lt_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), i64::from(clear_0 < clear_1));
+        let ct_res = sks.signed_le_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), i64::from(clear_0 <= clear_1));
+
+        let ct_res = sks.signed_max_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), clear_0.max(clear_1));
+        let ct_res = sks.signed_min_parallelized(&ctxt_0, &ctxt_1);
+        assert_eq!(decrypt(&ct_res), clear_0.min(clear_1));
+    }
+}
+
+fn integer_default_select_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    // Odd lengths leave the last candidate unpaired in the tree selection
+    for len in [1, 3, 5] {
+        let clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        let mut ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+        // Inputs with non empty carries are accepted
+        let first = ctxts[0].clone();
+        sks.unchecked_add_assign(&mut ctxts[0], &first);
+        let mut clears = clears;
+        clears[0] = (clears[0] * 2) % modulus;
+
+        // The last two indices are out of bounds, `len` leads to the unpaired candidates
+        for index in [0, len as u64 - 1, len as u64, len as u64 + 2] {
+            let ctxt_index = cks.encrypt(index);
+            let expected = clears.get(index as usize).copied().unwrap_or(0);
+
+            let ct_res = sks.select_at_index_parallelized(&ctxts, &ctxt_index);
+            let tmp_ct = sks.select_at_index_parallelized(&ctxts, &ctxt_index);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res, tmp_ct);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec_res, expected);
+
+            let ct_res = sks.select_at_index_tree_parallelized(&ctxts, &ctxt_index);
+            let tmp_ct = sks.select_at_index_tree_parallelized(&ctxts, &ctxt_index);
+            assert!(ct_res.block_carries_are_empty());
+            assert_eq!(ct_res, tmp_ct);
+            let dec_res: u64 = cks.decrypt(&ct_res);
+            assert_eq!(dec_res, expected);
+        }
+    }
+}
+
+fn integer_default_write_at_index<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    let len = 4;
+    let clears = (0..len)
+        .map(|_| rng.gen::<u64>() % modulus)
+        .collect::<Vec<_>>();
+    let ctxts = clears
+        .iter()
+        .map(|&clear| cks.encrypt(clear))
+        .collect::<Vec<_>>();
+
+    // The last index is out of bounds
+    for index in [0, 2, len as u64 + 1] {
+        let value = rng.gen::<u64>() % modulus;
+        let ctxt_index = cks.encrypt(index);
+        let ctxt_value = cks.encrypt(value);
+
+        let mut expected = clears.clone();
+        if let Some(slot) = expected.get_mut(index as usize) {
+            *slot = value;
+        }
+
+        let mut ct_res = ctxts.clone();
+        sks.write_at_index_parallelized(&mut ct_res, &ctxt_index, &ctxt_value);
+        let mut tmp_ct = ctxts.clone();
+        sks.write_at_index_parallelized(&mut tmp_ct, &ctxt_index, &ctxt_value);
+        assert_eq!(ct_res, tmp_ct);
+
+        let dec_res = ct_res
+            .iter()
+            .map(|ct| {
+                assert!(ct.block_carries_are_empty());
+                cks.decrypt(ct)
+            })
+            .collect::<Vec<u64>>();
+        assert_eq!(dec_res, expected);
+    }
+}
+
+fn integer_default_sort<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for len in [1, 5] {
+        let mut clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        // Make sure duplicated values are handled
+        clears[len - 1] = clears[0];
+        let ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        let ct_res = sks.sort_parallelized(&ctxts);
+        let tmp_ct = sks.sort_parallelized(&ctxts);
+        assert_eq!(ct_res, tmp_ct);
+
+        let dec_res = ct_res
+            .iter()
+            .map(|ct| {
+                assert!(ct.block_carries_are_empty());
+                cks.decrypt(ct)
+            })
+            .collect::<Vec<u64>>();
+        let mut expected = clears.clone();
+        expected.sort_unstable();
+        assert_eq!(dec_res, expected);
+
+        // Only a part of the sorting network is computed for small k
+        let ctxt_refs = ctxts.iter().collect::<Vec<_>>();
+        for k in [1, 2, len] {
+            let ct_res = sks.top_k_parallelized(&ctxt_refs, k);
+            let dec_res = ct_res
+                .iter()
+                .map(|ct| cks.decrypt(ct))
+                .collect::<Vec<u64>>();
+            let expected = expected.iter().rev().take(k).copied().collect::<Vec<_>>();
+            assert_eq!(dec_res, expected);
+        }
+    }
+}
+
+fn integer_default_argmax_argmin<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+
+    for len in [1, 5] {
+        let mut clears = (0..len)
+            .map(|_| rng.gen::<u64>() % modulus)
+            .collect::<Vec<_>>();
+        // Make sure the first occurrence is returned
+        clears.push(*clears.iter().max().unwrap());
+        clears.push(*clears.iter().min().unwrap());
+        let ctxts = clears
+            .iter()
+            .map(|&clear| cks.encrypt(clear))
+            .collect::<Vec<_>>();
+
+        let expected_max = clears
+            .iter()
+            .position(|x| x == clears.iter().max().unwrap())
+            .unwrap() as u64;
+        let expected_min = clears
+            .iter()
+            .position(|x| x == clears.iter().min().unwrap())
+            .unwrap() as u64;
+
+        let ct_res = sks.argmax_parallelized(&ctxts);
+        let tmp_ct = sks.argmax_parallelized(&ctxts);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, expected_max);
+
+        let ct_res = sks.argmin_parallelized(&ctxts);
+        let tmp_ct = sks.argmin_parallelized(&ctxts);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, expected_min);
+    }
+}
+
+fn integer_default_extract_and_pack_bits<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let num_bits = modulus.ilog2();
+
+    for _ in 0..NB_TEST_SMALLER {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = cks.encrypt(clear_0);
+        let ctxt_1 = cks.encrypt(clear_1);
+        // Inputs with non empty carries are accepted
+        let ctxt = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let clear = (clear_0 + clear_1) % modulus;
+
+        let bits = sks.extract_bits_parallelized(&ctxt);
+        let tmp_bits = sks.extract_bits_parallelized(&ctxt);
+        assert_eq!(bits, tmp_bits);
+        assert_eq!(bits.len(), num_bits as usize);
+
+        let dec_bits = bits
+            .iter()
+            .map(|bit| cks.decrypt_one_block(bit))
+            .collect::<Vec<_>>();
+        let expected_bits = (0..num_bits).map(|i| (clear >> i) & 1).collect::<Vec<_>>();
+        assert_eq!(dec_bits, expected_bits);
+
+        let ct_res = sks.pack_bits_parallelized(&bits);
+        let tmp_ct = sks.pack_bits_parallelized(&bits);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res, tmp_ct);
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear);
+
+        // Packing fewer bits gives fewer blocks
+        let ct_res = sks.pack_bits_parallelized(&bits[..3]);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert_eq!(dec_res, clear % 8);
+    }
+}
+
+fn integer_default_oblivious_pseudo_random<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let (cks, mut sks) = KEY_CACHE.get_from_params(param);
+    let cks = RadixClientKey::from((cks, NB_CTXT));
+
+    sks.set_deterministic_pbs_execution(true);
+
+    let mut rng = rand::thread_rng();
+
+    // message_modulus^vec_length
+    let modulus = cks.parameters().message_modulus().0.pow(NB_CTXT as u32) as u64;
+    let num_bits = modulus.ilog2() as u64;
+
+    for _ in 0..NB_TEST_SMALLER {
+        let seed = Seed(rng.gen::<u128>());
+
+        let ct_res = sks.generate_oblivious_pseudo_random_parallelized(seed, NB_CTXT);
+        let tmp_ct = sks.generate_oblivious_pseudo_random_parallelized(seed, NB_CTXT);
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        let dec_tmp: u64 = cks.decrypt(&tmp_ct);
+        assert!(dec_res < modulus);
+        assert_eq!(dec_res, dec_tmp);
+
+        let random_bits_count = rng.gen_range(0..=num_bits);
+        let ct_res = sks.generate_oblivious_pseudo_random_bits_parallelized(
+            seed,
+            random_bits_count,
+            NB_CTXT,
+        );
+        assert!(ct_res.block_carries_are_empty());
+        assert_eq!(ct_res.blocks.len(), NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert!(dec_res < (1 << random_bits_count));
+    }
+
+    // Check that both values of a single random bit are reached
+    let mut seen = [false; 2];
+    for _ in 0..NB_TEST {
+        let seed = Seed(rng.gen::<u128>());
+
+        let ct_res = sks.generate_oblivious_pseudo_random_bits_parallelized(seed, 1, NB_CTXT);
+        let dec_res: u64 = cks.decrypt(&ct_res);
+        assert!(dec_res < 2);
+        seen[dec_res as usize] = true;
+    }
+    assert!(seen.iter().all(|seen| *seen));
+
+    // A bound that is not a power of two goes through the multiplication,
+    // which is too costly to be sampled many times
+    let seed = Seed(rng.gen::<u128>());
+    let ct_res = sks.generate_oblivious_pseudo_random_bounded_parallelized(seed, 3u8, NB_CTXT);
+    assert!(ct_res.block_carries_are_empty());
+    assert_eq!(ct_res.blocks.len(), NB_CTXT);
+    let dec_res: u64 = cks.decrypt(&ct_res);
+    assert!(dec_res < 3);
+}
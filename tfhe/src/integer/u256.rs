@@ -86,23 +86,45 @@ where
 }
 
 // Little endian order
-#[derive(Default, Copy, Clone, Debug, PartialEq, Eq)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(transparent)]
-pub struct U256(pub(crate) [u64; 4]);
+pub struct StaticUnsignedBigInt<const N: usize>(pub(crate) [u64; N]);
 
-impl U256 {
-    pub const BITS: u32 = 256;
-    pub const MAX: Self = Self([u64::MAX; 4]);
-    pub const MIN: Self = Self([0; 4]);
-    pub const ZERO: Self = Self([0; 4]);
-    pub const ONE: Self = Self([1, 0, 0, 0]);
-    pub const TWO: Self = Self([2, 0, 0, 0]);
+pub type U256 = StaticUnsignedBigInt<4>;
+pub type U512 = StaticUnsignedBigInt<8>;
+pub type U1024 = StaticUnsignedBigInt<16>;
+pub type U2048 = StaticUnsignedBigInt<32>;
+
+impl<const N: usize> Default for StaticUnsignedBigInt<N> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> StaticUnsignedBigInt<N> {
+    pub const BITS: u32 = N as u32 * u64::BITS;
+    pub const MAX: Self = Self([u64::MAX; N]);
+    pub const MIN: Self = Self([0; N]);
+    pub const ZERO: Self = Self([0; N]);
+    pub const ONE: Self = Self::from_low_word(1);
+    pub const TWO: Self = Self::from_low_word(2);
+
+    /// Number of bytes of the little and big endian representations
+    pub const NUM_BYTES: usize = N * std::mem::size_of::<u64>();
+
+    const fn from_low_word(word: u64) -> Self {
+        let mut words = [0; N];
+        words[0] = word;
+        Self(words)
+    }
 
     /// Replaces the current value by interpreting the bytes in big endian order
     pub fn copy_from_be_byte_slice(&mut self, bytes: &[u8]) {
-        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes.len(), Self::NUM_BYTES);
         // We internally have
         //
+        // (here for N = 4)
+        //
         // [ index 0    |  index 1   |  index 2 | index 3     ]
         // [WB0,.., WB7 | WB0,..,WB7 | WB0,..,WB7 | WB0,..,WB7]
         // [B0,.., B7   | B8 ,..,B15 | B16,..,B23 | B24,..,B31]
@@ -117,9 +139,11 @@ impl U256 {
         inner_slice.fill(0);
         unsafe {
             // SAFETY SLICES are contiguous
-            // (4 * 64) bits / 8 bits = 32
-            let inner_byte_slice =
-                std::slice::from_raw_parts_mut(inner_slice.as_mut_ptr() as *mut u8, 32);
+            // (N * 64) bits / 8 bits = NUM_BYTES
+            let inner_byte_slice = std::slice::from_raw_parts_mut(
+                inner_slice.as_mut_ptr() as *mut u8,
+                Self::NUM_BYTES,
+            );
             let shortest_len = inner_byte_slice.len().min(bytes.len());
             inner_byte_slice[..shortest_len].copy_from_slice(&bytes[..shortest_len]);
             inner_byte_slice.reverse();
@@ -132,16 +156,18 @@ impl U256 {
 
     /// Replaces the current value by interpreting the bytes in little endian order
     pub fn copy_from_le_byte_slice(&mut self, bytes: &[u8]) {
-        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes.len(), Self::NUM_BYTES);
         // Same principle as in copy_from_be_byte_slice applies here
 
         let inner_slice = self.0.as_mut_slice();
         inner_slice.fill(0);
         unsafe {
             // SAFETY SLICES are contiguous
-            // (4 * 64) bits / 8 bits = 32
-            let inner_byte_slice =
-                std::slice::from_raw_parts_mut(inner_slice.as_mut_ptr() as *mut u8, 32);
+            // (N * 64) bits / 8 bits = NUM_BYTES
+            let inner_byte_slice = std::slice::from_raw_parts_mut(
+                inner_slice.as_mut_ptr() as *mut u8,
+                Self::NUM_BYTES,
+            );
             let shortest_len = inner_byte_slice.len().min(bytes.len());
             inner_byte_slice[..shortest_len].copy_from_slice(&bytes[..shortest_len]);
         }
@@ -152,13 +178,13 @@ impl U256 {
     }
 
     pub fn copy_to_le_byte_slice(&self, bytes: &mut [u8]) {
-        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes.len(), Self::NUM_BYTES);
         let inner_slice = self.0.as_slice();
         unsafe {
             // SAFETY SLICES are contiguous
-            // (4 * 64) bits / 8 bits = 32
+            // (N * 64) bits / 8 bits = NUM_BYTES
             let inner_byte_slice =
-                std::slice::from_raw_parts(inner_slice.as_ptr() as *const u8, 32);
+                std::slice::from_raw_parts(inner_slice.as_ptr() as *const u8, Self::NUM_BYTES);
             let shortest_len = inner_byte_slice.len().min(bytes.len());
             bytes[..shortest_len].copy_from_slice(&inner_byte_slice[..shortest_len]);
         }
@@ -169,14 +195,14 @@ impl U256 {
     }
 
     pub fn copy_to_be_byte_slice(&self, bytes: &mut [u8]) {
-        assert_eq!(bytes.len(), 32);
+        assert_eq!(bytes.len(), Self::NUM_BYTES);
         let inner_slice = self.0.as_slice();
 
         unsafe {
             // SAFETY SLICES are contiguous
-            // (4 * 64) bits / 8 bits = 32
+            // (N * 64) bits / 8 bits = NUM_BYTES
             let inner_byte_slice =
-                std::slice::from_raw_parts(inner_slice.as_ptr() as *const u8, 32);
+                std::slice::from_raw_parts(inner_slice.as_ptr() as *const u8, Self::NUM_BYTES);
             let shortest_len = inner_byte_slice.len().min(bytes.len());
             bytes[..shortest_len].copy_from_slice(&inner_byte_slice[..shortest_len]);
         }
@@ -186,12 +212,6 @@ impl U256 {
         }
     }
 
-    pub fn to_low_high_u128(self) -> (u128, u128) {
-        let low = self.0[0] as u128 | ((self.0[1] as u128) << 64);
-        let high = self.0[2] as u128 | ((self.0[3] as u128) << 64);
-        (low, high)
-    }
-
     pub fn is_power_of_two(self) -> bool {
         if self == Self::ZERO {
             return false;
@@ -222,16 +242,26 @@ impl U256 {
     }
 }
 
+impl U256 {
+    pub fn to_low_high_u128(self) -> (u128, u128) {
+        let low = self.0[0] as u128 | ((self.0[1] as u128) << 64);
+        let high = self.0[2] as u128 | ((self.0[3] as u128) << 64);
+        (low, high)
+    }
+}
+
 #[cfg(test)]
-impl rand::distributions::Distribution<U256> for rand::distributions::Standard {
-    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> U256 {
-        U256([rng.gen(), rng.gen(), rng.gen(), rng.gen()])
+impl<const N: usize> rand::distributions::Distribution<StaticUnsignedBigInt<N>>
+    for rand::distributions::Standard
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> StaticUnsignedBigInt<N> {
+        StaticUnsignedBigInt([(); N].map(|_| rng.gen()))
     }
 }
 
 // Since we store as [low, high], deriving ord
 // would produces bad ordering
-impl std::cmp::Ord for U256 {
+impl<const N: usize> std::cmp::Ord for StaticUnsignedBigInt<N> {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
         let mut current_ord = std::cmp::Ordering::Equal;
         for (w_self, w_other) in self.0.iter().rev().zip(other.0.iter().rev()) {
@@ -245,13 +275,13 @@ impl std::cmp::Ord for U256 {
     }
 }
 
-impl std::cmp::PartialOrd for U256 {
+impl<const N: usize> std::cmp::PartialOrd for StaticUnsignedBigInt<N> {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl std::ops::Add<Self> for U256 {
+impl<const N: usize> std::ops::Add<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn add(mut self, rhs: Self) -> Self::Output {
@@ -260,13 +290,13 @@ impl std::ops::Add<Self> for U256 {
     }
 }
 
-impl std::ops::AddAssign<Self> for U256 {
+impl<const N: usize> std::ops::AddAssign<Self> for StaticUnsignedBigInt<N> {
     fn add_assign(&mut self, rhs: Self) {
         add_assign_words(self.0.as_mut_slice(), rhs.0.as_slice())
     }
 }
 
-impl std::ops::Sub<Self> for U256 {
+impl<const N: usize> std::ops::Sub<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn sub(self, rhs: Self) -> Self::Output {
@@ -275,13 +305,13 @@ impl std::ops::Sub<Self> for U256 {
     }
 }
 
-impl std::ops::SubAssign<Self> for U256 {
+impl<const N: usize> std::ops::SubAssign<Self> for StaticUnsignedBigInt<N> {
     fn sub_assign(&mut self, rhs: Self) {
         *self = *self - rhs;
     }
 }
 
-impl std::ops::Shr<u32> for U256 {
+impl<const N: usize> std::ops::Shr<u32> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn shr(mut self, rhs: u32) -> Self::Output {
@@ -290,7 +320,7 @@ impl std::ops::Shr<u32> for U256 {
     }
 }
 
-impl std::ops::MulAssign<Self> for U256 {
+impl<const N: usize> std::ops::MulAssign<Self> for StaticUnsignedBigInt<N> {
     fn mul_assign(&mut self, rhs: Self) {
         if rhs.is_power_of_two() {
             self.shl_assign(rhs.ilog2());
@@ -300,7 +330,7 @@ impl std::ops::MulAssign<Self> for U256 {
     }
 }
 
-impl std::ops::Mul<Self> for U256 {
+impl<const N: usize> std::ops::Mul<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn mul(mut self, rhs: Self) -> Self::Output {
@@ -309,13 +339,13 @@ impl std::ops::Mul<Self> for U256 {
     }
 }
 
-impl std::ops::DivAssign<Self> for U256 {
+impl<const N: usize> std::ops::DivAssign<Self> for StaticUnsignedBigInt<N> {
     fn div_assign(&mut self, rhs: Self) {
         *self = *self / rhs;
     }
 }
 
-impl std::ops::Div<Self> for U256 {
+impl<const N: usize> std::ops::Div<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn div(self, rhs: Self) -> Self::Output {
@@ -324,13 +354,13 @@ impl std::ops::Div<Self> for U256 {
     }
 }
 
-impl std::ops::RemAssign<Self> for U256 {
+impl<const N: usize> std::ops::RemAssign<Self> for StaticUnsignedBigInt<N> {
     fn rem_assign(&mut self, rhs: Self) {
         *self = *self % rhs;
     }
 }
 
-impl std::ops::Rem<Self> for U256 {
+impl<const N: usize> std::ops::Rem<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn rem(self, rhs: Self) -> Self::Output {
@@ -339,7 +369,7 @@ impl std::ops::Rem<Self> for U256 {
     }
 }
 
-impl std::ops::ShrAssign<u32> for U256 {
+impl<const N: usize> std::ops::ShrAssign<u32> for StaticUnsignedBigInt<N> {
     // move bits from MSB to LSB
     fn shr_assign(&mut self, shift: u32) {
         let shift = shift % Self::BITS;
@@ -368,7 +398,7 @@ impl std::ops::ShrAssign<u32> for U256 {
     }
 }
 
-impl std::ops::Shl<u32> for U256 {
+impl<const N: usize> std::ops::Shl<u32> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn shl(mut self, rhs: u32) -> Self::Output {
@@ -377,7 +407,7 @@ impl std::ops::Shl<u32> for U256 {
     }
 }
 
-impl std::ops::ShlAssign<u32> for U256 {
+impl<const N: usize> std::ops::ShlAssign<u32> for StaticUnsignedBigInt<N> {
     // move bits from LSB to MSB
     fn shl_assign(&mut self, shift: u32) {
         let shift = shift % Self::BITS;
@@ -405,7 +435,7 @@ impl std::ops::ShlAssign<u32> for U256 {
     }
 }
 
-impl std::ops::Not for U256 {
+impl<const N: usize> std::ops::Not for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn not(mut self) -> Self::Output {
@@ -416,7 +446,7 @@ impl std::ops::Not for U256 {
     }
 }
 
-impl std::ops::BitAnd<Self> for U256 {
+impl<const N: usize> std::ops::BitAnd<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn bitand(mut self, rhs: Self) -> Self::Output {
@@ -425,7 +455,7 @@ impl std::ops::BitAnd<Self> for U256 {
     }
 }
 
-impl std::ops::BitAndAssign<Self> for U256 {
+impl<const N: usize> std::ops::BitAndAssign<Self> for StaticUnsignedBigInt<N> {
     fn bitand_assign(&mut self, rhs: Self) {
         for (self_word, rhs_word) in self.0.iter_mut().zip(rhs.0) {
             *self_word &= rhs_word;
@@ -433,7 +463,7 @@ impl std::ops::BitAndAssign<Self> for U256 {
     }
 }
 
-impl std::ops::BitOrAssign<Self> for U256 {
+impl<const N: usize> std::ops::BitOrAssign<Self> for StaticUnsignedBigInt<N> {
     fn bitor_assign(&mut self, rhs: Self) {
         for (self_word, rhs_word) in self.0.iter_mut().zip(rhs.0) {
             *self_word |= rhs_word;
@@ -441,7 +471,7 @@ impl std::ops::BitOrAssign<Self> for U256 {
     }
 }
 
-impl std::ops::BitOr<Self> for U256 {
+impl<const N: usize> std::ops::BitOr<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn bitor(mut self, rhs: Self) -> Self::Output {
@@ -456,7 +486,7 @@ impl From<(u64, u64, u64, u64)> for U256 {
     }
 }
 
-impl std::ops::BitXorAssign<Self> for U256 {
+impl<const N: usize> std::ops::BitXorAssign<Self> for StaticUnsignedBigInt<N> {
     fn bitxor_assign(&mut self, rhs: Self) {
         for (self_word, rhs_word) in self.0.iter_mut().zip(rhs.0) {
             *self_word ^= rhs_word;
@@ -464,7 +494,7 @@ impl std::ops::BitXorAssign<Self> for U256 {
     }
 }
 
-impl std::ops::BitXor<Self> for U256 {
+impl<const N: usize> std::ops::BitXor<Self> for StaticUnsignedBigInt<N> {
     type Output = Self;
 
     fn bitxor(mut self, rhs: Self) -> Self::Output {
@@ -483,72 +513,70 @@ impl From<(u128, u128)> for U256 {
     }
 }
 
-impl From<u8> for U256 {
+impl<const N: usize> From<u8> for StaticUnsignedBigInt<N> {
     fn from(value: u8) -> Self {
         Self::from(value as u128)
     }
 }
 
-impl From<u16> for U256 {
+impl<const N: usize> From<u16> for StaticUnsignedBigInt<N> {
     fn from(value: u16) -> Self {
         Self::from(value as u128)
     }
 }
 
-impl From<u32> for U256 {
+impl<const N: usize> From<u32> for StaticUnsignedBigInt<N> {
     fn from(value: u32) -> Self {
         Self::from(value as u128)
     }
 }
 
-impl From<u64> for U256 {
+impl<const N: usize> From<u64> for StaticUnsignedBigInt<N> {
     fn from(value: u64) -> Self {
         Self::from(value as u128)
     }
 }
 
-impl From<u128> for U256 {
+impl<const N: usize> From<u128> for StaticUnsignedBigInt<N> {
     fn from(value: u128) -> Self {
-        Self([
-            (value & u128::from(u64::MAX)) as u64,
-            (value >> 64) as u64,
-            0,
-            0,
-        ])
+        let mut converted = Self::ZERO;
+        converted.0[0] = (value & u128::from(u64::MAX)) as u64;
+        converted.0[1] = (value >> 64) as u64;
+        converted
     }
 }
 
-impl CastFrom<U256> for u64 {
-    fn cast_from(input: U256) -> Self {
+impl<const N: usize> CastFrom<StaticUnsignedBigInt<N>> for u64 {
+    fn cast_from(input: StaticUnsignedBigInt<N>) -> Self {
         input.0[0]
     }
 }
 
-impl CastFrom<U256> for u8 {
-    fn cast_from(input: U256) -> Self {
+impl<const N: usize> CastFrom<StaticUnsignedBigInt<N>> for u8 {
+    fn cast_from(input: StaticUnsignedBigInt<N>) -> Self {
         input.0[0] as u8
     }
 }
 
-impl CastFrom<u32> for U256 {
+impl<const N: usize> CastFrom<u32> for StaticUnsignedBigInt<N> {
     fn cast_from(input: u32) -> Self {
         Self::from(input)
     }
 }
 
-impl CastFrom<u64> for U256 {
+impl<const N: usize> CastFrom<u64> for StaticUnsignedBigInt<N> {
     fn cast_from(input: u64) -> Self {
         Self::from(input)
     }
 }
 
-impl CastFrom<u8> for U256 {
+impl<const N: usize> CastFrom<u8> for StaticUnsignedBigInt<N> {
     fn cast_from(input: u8) -> Self {
         Self::from(input as u64)
     }
 }
 
-impl From<bool> for U256 {
+impl<const N: usize> From<bool> for StaticUnsignedBigInt<N> {
     fn from(input: bool) -> Self {
         Self::from(if input { 1u64 } else { 0u64 })
     }
@@ -556,8 +584,8 @@ impl From<bool> for U256 {
 
 // SAFETY
 //
-// U256 is allowed to be all zeros
-unsafe impl bytemuck::Zeroable for U256 {}
+// StaticUnsignedBigInt is allowed to be all zeros
+unsafe impl<const N: usize> bytemuck::Zeroable for StaticUnsignedBigInt<N> {}
 
 // SAFETY
 //
@@ -566,10 +594,10 @@ unsafe impl bytemuck::Zeroable for U256 {}
 //
 // https://docs.rs/bytemuck/latest/bytemuck/trait.Pod.html#foreign-impls
 //
-// Thus U256 can safely be considered Pod
-unsafe impl bytemuck::Pod for U256 {}
+// Thus StaticUnsignedBigInt can safely be considered Pod
+unsafe impl<const N: usize> bytemuck::Pod for StaticUnsignedBigInt<N> {}
 
-impl Numeric for U256 {
+impl<const N: usize> Numeric for StaticUnsignedBigInt<N> {
     const BITS: usize = Self::BITS as usize;
 
     const ZERO: Self = Self::ZERO;
@@ -623,8 +651,8 @@ mod tests {
 
     #[test]
     fn test_bitand() {
-        let all_even_bits_set = U256([u64_with_even_bits_set(); 4]);
-        let all_odd_bits_set = U256([u64_with_odd_bits_set(); 4]);
+        let all_even_bits_set = StaticUnsignedBigInt([u64_with_even_bits_set(); 4]);
+        let all_odd_bits_set = StaticUnsignedBigInt([u64_with_odd_bits_set(); 4]);
 
         assert_ne!(all_odd_bits_set, all_even_bits_set);
         assert_eq!(all_odd_bits_set & all_odd_bits_set, all_odd_bits_set);
@@ -634,8 +662,8 @@ mod tests {
 
     #[test]
     fn test_bitor() {
-        let all_even_bits_set = U256([u64_with_even_bits_set(); 4]);
-        let all_odd_bits_set = U256([u64_with_odd_bits_set(); 4]);
+        let all_even_bits_set = StaticUnsignedBigInt([u64_with_even_bits_set(); 4]);
+        let all_odd_bits_set = StaticUnsignedBigInt([u64_with_odd_bits_set(); 4]);
 
         assert_ne!(all_odd_bits_set, all_even_bits_set);
         assert_eq!(all_odd_bits_set | all_odd_bits_set, all_odd_bits_set);
@@ -645,8 +673,8 @@ mod tests {
 
     #[test]
     fn test_bitxor() {
-        let all_even_bits_set = U256([u64_with_even_bits_set(); 4]);
-        let all_odd_bits_set = U256([u64_with_odd_bits_set(); 4]);
+        let all_even_bits_set = StaticUnsignedBigInt([u64_with_even_bits_set(); 4]);
+        let all_odd_bits_set = StaticUnsignedBigInt([u64_with_odd_bits_set(); 4]);
 
         assert_ne!(all_odd_bits_set, all_even_bits_set);
         assert_eq!(all_odd_bits_set ^ all_odd_bits_set, U256::ZERO);
@@ -889,4 +917,35 @@ mod tests {
 
         assert_eq!(be_bytes_2, be_bytes);
     }
+
+    #[test]
+    fn test_u512_mul_div_rem() {
+        let u128_max = U512::from(u128::MAX);
+        let square = u128_max * u128_max;
+        let fourth_power = square * square;
+
+        // (2^128 - 1)^4 uses all 512 bits
+        assert_eq!(fourth_power.leading_zeros(), 0);
+        assert_eq!(fourth_power / square, square);
+        assert_eq!(fourth_power % u128_max, U512::ZERO);
+        assert_eq!((fourth_power + U512::ONE) % square, U512::ONE);
+
+        // The low 256 bits agree with the U256 computation
+        let mut bytes = [0u8; U512::NUM_BYTES];
+        fourth_power.copy_to_le_byte_slice(&mut bytes);
+        let u256_square = U256::from(u128::MAX) * U256::from(u128::MAX);
+        let mut expected_low = [0u8; U256::NUM_BYTES];
+        (u256_square * u256_square).copy_to_le_byte_slice(&mut expected_low);
+        assert_eq!(&bytes[..U256::NUM_BYTES], expected_low.as_slice());
+    }
+
+    #[test]
+    fn test_wide_shifts() {
+        assert_eq!(U1024::BITS, 1024);
+        assert_eq!((U1024::ONE << 1023).ilog2(), 1023);
+        assert_eq!((U1024::ONE << 1023) >> 1023, U1024::ONE);
+        assert_eq!(U2048::MAX >> 2047, U2048::ONE);
+        assert_eq!((U2048::MAX << 2000).leading_zeros(), 0);
+        assert_eq!(U2048::MAX.ilog2(), 2047);
+    }
 }
@@ -2,42 +2,50 @@ use wasm_bindgen::prelude::*;
 use wasm_bindgen::JsError;
 
 use crate::high_level_api::prelude::*;
-use crate::integer::U256;
+use crate::integer::{StaticUnsignedBigInt, U1024, U2048, U256, U512};
 use crate::js_on_wasm_api::high_level_api::{catch_panic, catch_panic_result, into_js_error};
 
-const U128_MAX_AS_STR: &str = "340282366920938463463374607431768211455";
-
-impl From<U256> for JsValue {
-    fn from(value: U256) -> Self {
-        let (low_rs, high_rs) = value.to_low_high_u128();
-
-        let low_js = JsValue::from(low_rs);
-        let high_js = JsValue::from(high_rs);
-        (high_js << JsValue::bigint_from_str("128")) + low_js
+const U64_MAX_AS_STR: &str = "18446744073709551615";
+
+impl<const N: usize> From<StaticUnsignedBigInt<N>> for JsValue {
+    fn from(value: StaticUnsignedBigInt<N>) -> Self {
+        // Starting from the most significant word
+        value
+            .0
+            .iter()
+            .rev()
+            .fold(JsValue::bigint_from_str("0"), |acc, &word| {
+                (acc << JsValue::bigint_from_str("64")) + JsValue::from(word)
+            })
     }
 }
 
-impl TryFrom<JsValue> for U256 {
+impl<const N: usize> TryFrom<JsValue> for StaticUnsignedBigInt<N> {
     type Error = JsError;
 
-    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
-        let low_js = value.clone() & JsValue::bigint_from_str(U128_MAX_AS_STR);
-        let high_js = value >> JsValue::bigint_from_str("128");
-
-        // Since we masked the low value it will fit in u128
-        let low_rs = u128::try_from(low_js).unwrap();
-        // If high does not fit in u128, that means the value is > 256::MAX
-        let high_rs =
-            u128::try_from(high_js).map_err(|_| JsError::new("value is out of range for u256"))?;
+    fn try_from(mut value: JsValue) -> Result<Self, Self::Error> {
+        let mut result = Self::ZERO;
+        for word in result.0.iter_mut() {
+            let word_js = value.clone() & JsValue::bigint_from_str(U64_MAX_AS_STR);
+            // Since we masked the value it will fit in u64
+            *word = u64::try_from(word_js).unwrap();
+            value = value >> JsValue::bigint_from_str("64");
+        }
 
-        let value = U256::from((low_rs, high_rs));
-        Ok(value)
+        // If something remains, that means the value is > Self::MAX (or negative)
+        if value != JsValue::bigint_from_str("0") {
+            return Err(JsError::new(&format!(
+                "value is out of range for u{}",
+                Self::BITS
+            )));
+        }
+        Ok(result)
     }
 }
 
 // We use this macro to define wasm wrapper for
 // FheUint types which maps to a type that is not native
-// to wasm-bindgen such as u128 (rust native) and our U256
+// to wasm-bindgen such as u128 (rust native) and our U256 (and wider)
 // and requires conversions using TryFrom
 macro_rules! create_wrapper_type_non_native_type(
     (
@@ -319,6 +327,27 @@ create_wrapper_type_non_native_type!(
         compact_type_name: CompactFheUint256,
         compact_list_type_name: CompactFheUint256List,
         rust_type: U256,
+    },
+    {
+        type_name: FheUint512,
+        compressed_type_name: CompressedFheUint512,
+        compact_type_name: CompactFheUint512,
+        compact_list_type_name: CompactFheUint512List,
+        rust_type: U512,
+    },
+    {
+        type_name: FheUint1024,
+        compressed_type_name: CompressedFheUint1024,
+        compact_type_name: CompactFheUint1024,
+        compact_list_type_name: CompactFheUint1024List,
+        rust_type: U1024,
+    },
+    {
+        type_name: FheUint2048,
+        compressed_type_name: CompressedFheUint2048,
+        compact_type_name: CompactFheUint2048,
+        compact_list_type_name: CompactFheUint2048List,
+        rust_type: U2048,
     }
 );
 
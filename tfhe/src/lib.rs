@@ -38,6 +38,13 @@ pub mod integer;
 /// cbindgen:ignore
 pub mod shortint;
 
+#[cfg(feature = "regex")]
+/// Welcome to the TFHE-rs [`regex`](`crate::regex`) module documentation!
+///
+/// # Special module attributes
+/// cbindgen:ignore
+pub mod regex;
+
 #[cfg(feature = "__wasm_api")]
 /// cbindgen:ignore
 pub mod js_on_wasm_api;
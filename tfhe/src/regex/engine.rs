@@ -0,0 +1,435 @@
+use std::rc::Rc;
+
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::regex::execution::{Executed, ExecutedResult, Execution, LazyExecution};
+use crate::regex::parser::{parse, RegExpr, RegexParseError};
+
+/// Applies regular expressions on an encrypted ASCII content
+///
+/// The content is a slice of radix ciphertexts, each one encrypting a character.
+///
+/// The engine remembers the results of the homomorphic operations it computes, so that
+/// applying several patterns on the same content reuses the comparisons shared by the
+/// patterns (e.g. the comparisons of the characters to the same constant).
+///
+/// The results are radix ciphertexts with the same number of blocks as the characters,
+/// except for positions and counts which have enough blocks to hold the length
+/// of the content.
+///
+/// # Example
+///
+/// ```rust
+/// use tfhe::integer::gen_keys_radix;
+/// use tfhe::regex::RegexEngine;
+/// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+///
+/// // 4 blocks of 2 bits are needed to encrypt an ASCII character
+/// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, 4);
+///
+/// let content = "a1b22"
+///     .bytes()
+///     .map(|byte| cks.encrypt(byte as u64))
+///     .collect::<Vec<_>>();
+///
+/// let mut engine = RegexEngine::new(&sks, &content);
+///
+/// let ct_res = engine.has_match("/^a[0-9]/").unwrap();
+/// let res: u64 = cks.decrypt(&ct_res);
+/// assert_eq!(res, 1);
+///
+/// // The comparisons of the characters to the digits are reused
+/// let (_, ct_position) = engine.find("/[0-9]{2}/").unwrap();
+/// let position: u64 = cks.decrypt(&ct_position);
+/// assert_eq!(position, 3);
+/// assert!(engine.cache_hits() > 0);
+/// ```
+pub struct RegexEngine<'a> {
+    content: &'a [RadixCiphertext],
+    execution: Execution<'a>,
+}
+
+impl<'a> RegexEngine<'a> {
+    pub fn new(server_key: &'a ServerKey, content: &'a [RadixCiphertext]) -> Self {
+        let num_blocks = content.first().map_or_else(
+            || {
+                let bits_per_block = server_key.key.message_modulus.0.ilog2() as usize;
+                (u8::BITS as usize + bits_per_block - 1) / bits_per_block
+            },
+            |c| c.blocks.len(),
+        );
+        Self {
+            content,
+            execution: Execution::new(server_key, num_blocks),
+        }
+    }
+
+    /// Returns the number of homomorphic operations computed so far
+    pub fn ct_operations_count(&self) -> usize {
+        self.execution.ct_operations_count()
+    }
+
+    /// Returns the number of homomorphic operations that were avoided
+    /// by reusing a previous result
+    pub fn cache_hits(&self) -> usize {
+        self.execution.cache_hits()
+    }
+
+    /// Returns an encryption of 1 if the pattern matches somewhere in the content,
+    /// 0 otherwise
+    pub fn has_match(&mut self, pattern: &str) -> Result<RadixCiphertext, RegexParseError> {
+        let matches = self.matches_at_each_position(pattern)?;
+        let res = matches
+            .into_iter()
+            .reduce(|res, branch_res| self.execution.ct_or(res, branch_res))
+            .unwrap();
+        Ok(res.0)
+    }
+
+    /// Returns whether the pattern matches somewhere in the content, and the position
+    /// of the first character of the first match
+    ///
+    /// If there is no match, the position is an encryption of 0.
+    pub fn find(
+        &mut self,
+        pattern: &str,
+    ) -> Result<(RadixCiphertext, RadixCiphertext), RegexParseError> {
+        let matches = self.matches_at_each_position(pattern)?;
+        let sk = self.execution.server_key();
+
+        let extended_matches = self.extend_to_position_blocks(&matches);
+        let found = matches
+            .into_iter()
+            .reduce(|res, branch_res| self.execution.ct_or(res, branch_res))
+            .unwrap();
+        // The first maximum is the first match, if there is one
+        let position = sk.argmax_parallelized(&extended_matches);
+        Ok((found.0, position))
+    }
+
+    /// Returns the number of positions of the content at which a match starts
+    ///
+    /// Matches may overlap, and empty matches are counted, including the one at the
+    /// end of the content, e.g. `/a*/` has 4 matches in `"aab"`.
+    pub fn count_matches(&mut self, pattern: &str) -> Result<RadixCiphertext, RegexParseError> {
+        let matches = self.matches_at_each_position(pattern)?;
+        let extended_matches = self.extend_to_position_blocks(&matches);
+        Ok(self
+            .execution
+            .server_key()
+            .sum_ciphertexts_vec_parallelized(extended_matches)
+            .unwrap())
+    }
+
+    /// Returns, for each position of the content (including its end), the encryption of 1
+    /// if a match starts at this position
+    fn matches_at_each_position(
+        &mut self,
+        pattern: &str,
+    ) -> Result<Vec<ExecutedResult>, RegexParseError> {
+        let re = parse(pattern)?;
+
+        let mut matches = Vec::with_capacity(self.content.len() + 1);
+        for c_pos in 0..=self.content.len() {
+            let mut res = self.execution.ct_false();
+            for (branch, _) in build_branches(self.content, &re, c_pos) {
+                let branch_res = branch(&mut self.execution);
+                res = self.execution.ct_or(res, branch_res);
+            }
+            matches.push(res);
+        }
+        Ok(matches)
+    }
+
+    /// Extends the results, so that they have enough blocks to hold
+    /// the length of the content
+    fn extend_to_position_blocks(&self, matches: &[ExecutedResult]) -> Vec<RadixCiphertext> {
+        let sk = self.execution.server_key();
+        let bits_per_block = sk.key.message_modulus.0.ilog2();
+        let position_bits = usize::BITS - (self.content.len() + 1).leading_zeros();
+        let position_blocks = ((position_bits + bits_per_block - 1) / bits_per_block) as usize;
+        matches
+            .iter()
+            .map(|(ct, _)| {
+                let num_blocks = ct.blocks.len();
+                if num_blocks < position_blocks {
+                    sk.extend_radix_with_trivial_zero_blocks_msb(ct, position_blocks - num_blocks)
+                } else {
+                    ct.clone()
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns an encryption of 1 if the pattern matches somewhere in the content, 0 otherwise
+///
+/// See [RegexEngine] to apply several patterns on the same content.
+pub fn has_match(
+    sk: &ServerKey,
+    content: &[RadixCiphertext],
+    pattern: &str,
+) -> Result<RadixCiphertext, RegexParseError> {
+    RegexEngine::new(sk, content).has_match(pattern)
+}
+
+fn always_true<'a>() -> LazyExecution<'a> {
+    Rc::new(|exec: &mut Execution<'a>| exec.ct_true())
+}
+
+/// Returns the execution of `first` followed by `second`
+fn and_then<'a>(first: LazyExecution<'a>, second: LazyExecution<'a>) -> LazyExecution<'a> {
+    Rc::new(move |exec: &mut Execution<'a>| {
+        let res_first = first(exec);
+        let res_second = second(exec);
+        exec.ct_and(res_first, res_second)
+    })
+}
+
+/// Returns the branches that continue each of the `continuations` with `re`
+fn continue_branches<'a>(
+    content: &'a [RadixCiphertext],
+    continuations: &[(LazyExecution<'a>, usize)],
+    re: &RegExpr,
+) -> Vec<(LazyExecution<'a>, usize)> {
+    continuations
+        .iter()
+        .flat_map(|(branch_prev, branch_prev_c_pos)| {
+            build_branches(content, re, *branch_prev_c_pos)
+                .into_iter()
+                .map(move |(branch_x, branch_x_c_pos)| {
+                    (and_then(branch_prev.clone(), branch_x), branch_x_c_pos)
+                })
+        })
+        .collect()
+}
+
+/// Returns the possible ways of matching `re` from `c_pos`, each with the position
+/// right after it in the content
+fn build_branches<'a>(
+    content: &'a [RadixCiphertext],
+    re: &RegExpr,
+    c_pos: usize,
+) -> Vec<(LazyExecution<'a>, usize)> {
+    match re {
+        RegExpr::Sof => {
+            if c_pos == 0 {
+                return vec![(always_true(), c_pos)];
+            } else {
+                return vec![];
+            }
+        }
+        RegExpr::Eof => {
+            if c_pos == content.len() {
+                return vec![(always_true(), c_pos)];
+            } else {
+                return vec![];
+            }
+        }
+        // The expressions that consume a character
+        RegExpr::Char { .. }
+        | RegExpr::AnyChar
+        | RegExpr::Between { .. }
+        | RegExpr::Range { .. }
+            if c_pos >= content.len() =>
+        {
+            return vec![];
+        }
+        _ => (),
+    };
+
+    match re.clone() {
+        RegExpr::Char { c } => {
+            let c_char = &content[c_pos];
+            vec![(
+                Rc::new(move |exec: &mut Execution<'a>| {
+                    let ct_c = exec.ct_constant(c);
+                    exec.ct_eq((c_char.clone(), Executed::ct_pos(c_pos)), ct_c)
+                }),
+                c_pos + 1,
+            )]
+        }
+        RegExpr::AnyChar => vec![(always_true(), c_pos + 1)],
+        RegExpr::Not { not_re } => build_branches(content, &not_re, c_pos)
+            .into_iter()
+            .map(|(branch, c_pos)| {
+                (
+                    Rc::new(move |exec: &mut Execution<'a>| {
+                        let branch_res = branch(exec);
+                        exec.ct_not(branch_res)
+                    }) as LazyExecution<'a>,
+                    c_pos,
+                )
+            })
+            .collect(),
+        RegExpr::Either { l_re, r_re } => {
+            let mut res = build_branches(content, &l_re, c_pos);
+            res.append(&mut build_branches(content, &r_re, c_pos));
+            res
+        }
+        RegExpr::Between { from, to } => {
+            let c_char = &content[c_pos];
+            vec![(
+                Rc::new(move |exec: &mut Execution<'a>| {
+                    let ct_from = exec.ct_constant(from);
+                    let ct_to = exec.ct_constant(to);
+                    let ge_from = exec.ct_ge((c_char.clone(), Executed::ct_pos(c_pos)), ct_from);
+                    let le_to = exec.ct_le((c_char.clone(), Executed::ct_pos(c_pos)), ct_to);
+                    exec.ct_and(ge_from, le_to)
+                }),
+                c_pos + 1,
+            )]
+        }
+        RegExpr::Range { cs } => {
+            let c_char = &content[c_pos];
+            vec![(
+                Rc::new(move |exec: &mut Execution<'a>| {
+                    let mut res = exec.ct_false();
+                    for c in cs.iter() {
+                        let ct_c = exec.ct_constant(*c);
+                        let ct_c_char_eq =
+                            exec.ct_eq((c_char.clone(), Executed::ct_pos(c_pos)), ct_c);
+                        res = exec.ct_or(res, ct_c_char_eq);
+                    }
+                    res
+                }),
+                c_pos + 1,
+            )]
+        }
+        RegExpr::Repeated {
+            repeat_re,
+            at_least,
+            at_most,
+        } => {
+            let at_least = at_least.unwrap_or(0);
+            let at_most = at_most.unwrap_or(content.len() - c_pos);
+
+            if at_least > at_most {
+                return vec![];
+            }
+
+            // The branches that repeat `repeat_re` exactly n times, starting with n = at_least
+            let mut res = vec![build_branches(
+                content,
+                &(RegExpr::Seq {
+                    re_xs: std::iter::repeat(*repeat_re.clone())
+                        .take(at_least)
+                        .collect(),
+                }),
+                c_pos,
+            )];
+            for _ in at_least..at_most {
+                let next = continue_branches(content, res.last().unwrap(), &repeat_re);
+                if next.is_empty() {
+                    break;
+                }
+                res.push(next);
+            }
+            res.into_iter().flatten().collect()
+        }
+        RegExpr::Optional { opt_re } => {
+            let mut res = build_branches(content, &opt_re, c_pos);
+            res.push((always_true(), c_pos));
+            res
+        }
+        RegExpr::Seq { re_xs } => re_xs
+            .iter()
+            .fold(vec![(always_true(), c_pos)], |continuations, re_x| {
+                continue_branches(content, &continuations, re_x)
+            }),
+        _ => panic!("unmatched regex variant"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use test_case::test_case;
+
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::{RadixCiphertext, RadixClientKey, ServerKey};
+    use crate::regex::engine::{has_match, RegexEngine};
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    fn keys() -> (RadixClientKey, ServerKey) {
+        let (cks, sks) = KEY_CACHE.get_from_params(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+        (RadixClientKey::from((cks, 4)), sks)
+    }
+
+    fn encrypt_str(cks: &RadixClientKey, s: &str) -> Vec<RadixCiphertext> {
+        s.bytes().map(|byte| cks.encrypt(byte as u64)).collect()
+    }
+
+    #[test_case("ab", "/ab/", 1)]
+    #[test_case("b", "/ab/", 0)]
+    #[test_case("ab", "/a?b/", 1)]
+    #[test_case("b", "/a?b/", 1)]
+    #[test_case("ab", "/^ab|cd$/", 1)]
+    #[test_case(" ab", "/^ab|cd$/", 0)]
+    #[test_case(" cd", "/^ab|cd$/", 0)]
+    #[test_case("cd", "/^ab|cd$/", 1)]
+    #[test_case("abcd", "/^ab|cd$/", 0)]
+    #[test_case("abcd", "/ab|cd$/", 1)]
+    #[test_case("abc", "/abc/", 1)]
+    #[test_case("123abc", "/abc/", 1)]
+    #[test_case("123abc456", "/abc/", 1)]
+    #[test_case("123abdc456", "/abc/", 0)]
+    #[test_case("abc456", "/abc/", 1)]
+    #[test_case("bc", "/a*bc/", 1)]
+    #[test_case("cdaabc", "/a*bc/", 1)]
+    #[test_case("cdbc", "/a+bc/", 0)]
+    #[test_case("bc", "/a+bc/", 0)]
+    #[test_case("Ab", "/ab/i", 1 ; "ab case insensitive")]
+    #[test_case("Ab", "/ab/", 0 ; "ab case sensitive")]
+    #[test_case("cD", "/ab|cd/i", 1)]
+    #[test_case("cD", "/cD/", 1)]
+    #[test_case("test a num 8", "/8/", 1)]
+    #[test_case("test a num 8", "/^8/", 0)]
+    #[test_case("4453", "/^[0-9]*$/", 1)]
+    #[test_case("4453", "/^[09]*$/", 0)]
+    #[test_case("09009", "/^[09]*$/", 1)]
+    #[test_case("de", "/^ab|cd|de$/", 1 ; "multiple or")]
+    #[test_case(" de", "/^ab|cd|de$/", 0 ; "multiple or nests below ^")]
+    #[test_case("", "/^$/", 1 ; "empty content")]
+    #[test_case("a", "/ab?$/", 1 ; "optional at the end")]
+    #[test_case("ab", "/^(a|c)b{0,1}$/", 1 ; "alternation in group then bounded repeat")]
+    #[test_case("abb", "/^ab{0,1}$/", 0 ; "bounded repeat upper limit")]
+    #[test_case("abba", "/^a(b{2}|c)a$/", 1 ; "exact repeat in alternation")]
+    #[test_case("xab", "/(^a|b$)/", 1 ; "anchor inside group")]
+    #[test_case("xay", "/(^a|a$)/", 0 ; "anchors inside group do not match")]
+    #[test_case("a0", "/^a[0-9]$/", 1 ; "lower bound of range is included")]
+    fn test_has_match(content: &str, pattern: &str, exp: u64) {
+        let (cks, sks) = keys();
+        let ct_content = encrypt_str(&cks, content);
+        let ct_res = has_match(&sks, &ct_content, pattern).unwrap();
+
+        let got: u64 = cks.decrypt(&ct_res);
+        assert_eq!(exp, got);
+    }
+
+    #[test]
+    fn test_find_and_count_matches() {
+        let (cks, sks) = keys();
+        let ct_content = encrypt_str(&cks, "xabyab");
+        let mut engine = RegexEngine::new(&sks, &ct_content);
+
+        let (ct_found, ct_position) = engine.find("/ab/").unwrap();
+        let found: u64 = cks.decrypt(&ct_found);
+        let position: u64 = cks.decrypt(&ct_position);
+        assert_eq!((found, position), (1, 1));
+
+        let hits_before = engine.cache_hits();
+        let ct_count = engine.count_matches("/ab/").unwrap();
+        let count: u64 = cks.decrypt(&ct_count);
+        assert_eq!(count, 2);
+        // All the comparisons were already computed by `find`
+        assert!(engine.cache_hits() > hits_before);
+
+        let (ct_found, ct_position) = engine.find("/ba/").unwrap();
+        let found: u64 = cks.decrypt(&ct_found);
+        let position: u64 = cks.decrypt(&ct_position);
+        assert_eq!((found, position), (0, 0));
+
+        let ct_count = engine.count_matches("/a?/").unwrap();
+        let count: u64 = cks.decrypt(&ct_count);
+        assert_eq!(count, 7);
+    }
+}
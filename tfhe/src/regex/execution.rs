@@ -1,8 +1,8 @@
 use std::collections::HashMap;
 use std::rc::Rc;
-use tfhe::integer::{RadixCiphertext, ServerKey};
 
-use crate::parser::u8_to_char;
+use crate::integer::{RadixCiphertext, ServerKey};
+use crate::regex::parser::u8_to_char;
 
 #[derive(Clone, PartialEq, Eq, Hash)]
 pub(crate) enum Executed {
@@ -15,7 +15,7 @@ pub(crate) enum Executed {
     LessOrEqual { a: Box<Executed>, b: Box<Executed> },
     Not { a: Box<Executed> },
 }
-type ExecutedResult = (RadixCiphertext, Executed);
+pub(crate) type ExecutedResult = (RadixCiphertext, Executed);
 
 impl Executed {
     pub(crate) fn ct_pos(at: usize) -> Self {
@@ -33,25 +33,36 @@ impl Executed {
 const CT_FALSE: u8 = 0;
 const CT_TRUE: u8 = 1;
 
-pub(crate) struct Execution {
-    sk: ServerKey,
+/// Evaluates the operations on the encrypted content, remembering their results
+///
+/// As the operations are identified by their inputs (the positions in the content and the
+/// constants), the results stay valid for any pattern applied on the same content.
+pub(crate) struct Execution<'a> {
+    sk: &'a ServerKey,
+    num_blocks: usize,
     cache: HashMap<Executed, RadixCiphertext>,
 
     ct_ops: usize,
     cache_hits: usize,
 }
-pub(crate) type LazyExecution = Rc<dyn Fn(&mut Execution) -> ExecutedResult>;
+pub(crate) type LazyExecution<'a> = Rc<dyn Fn(&mut Execution<'a>) -> ExecutedResult + 'a>;
 
-impl Execution {
-    pub(crate) fn new(sk: ServerKey) -> Self {
+impl<'a> Execution<'a> {
+    /// `num_blocks` is the number of blocks of the encrypted characters
+    pub(crate) fn new(sk: &'a ServerKey, num_blocks: usize) -> Self {
         Self {
             sk,
+            num_blocks,
             cache: HashMap::new(),
             ct_ops: 0,
             cache_hits: 0,
         }
     }
 
+    pub(crate) fn server_key(&self) -> &'a ServerKey {
+        self.sk
+    }
+
     pub(crate) fn ct_operations_count(&self) -> usize {
         self.ct_ops
     }
@@ -62,132 +73,95 @@ impl Execution {
 
     pub(crate) fn ct_eq(&mut self, a: ExecutedResult, b: ExecutedResult) -> ExecutedResult {
         let ctx = Executed::Equal {
-            a: Box::new(a.1.clone()),
-            b: Box::new(b.1.clone()),
+            a: Box::new(a.1),
+            b: Box::new(b.1),
         };
-        self.with_cache(
-            ctx.clone(),
-            Rc::new(move |exec: &mut Execution| {
-                exec.ct_ops += 1;
-
-                let mut ct_a = a.0.clone();
-                let mut ct_b = b.0.clone();
-                (exec.sk.smart_eq(&mut ct_a, &mut ct_b), ctx.clone())
-            }),
-        )
+        self.with_cache(ctx, |sk| {
+            let mut ct_a = a.0;
+            let mut ct_b = b.0;
+            sk.smart_eq_parallelized(&mut ct_a, &mut ct_b)
+        })
     }
 
     pub(crate) fn ct_ge(&mut self, a: ExecutedResult, b: ExecutedResult) -> ExecutedResult {
         let ctx = Executed::GreaterOrEqual {
-            a: Box::new(a.1.clone()),
-            b: Box::new(b.1.clone()),
+            a: Box::new(a.1),
+            b: Box::new(b.1),
         };
-        self.with_cache(
-            ctx.clone(),
-            Rc::new(move |exec| {
-                exec.ct_ops += 1;
-
-                let mut ct_a = a.0.clone();
-                let mut ct_b = b.0.clone();
-                (exec.sk.smart_gt(&mut ct_a, &mut ct_b), ctx.clone())
-            }),
-        )
+        self.with_cache(ctx, |sk| {
+            let mut ct_a = a.0;
+            let mut ct_b = b.0;
+            sk.smart_ge_parallelized(&mut ct_a, &mut ct_b)
+        })
     }
 
     pub(crate) fn ct_le(&mut self, a: ExecutedResult, b: ExecutedResult) -> ExecutedResult {
         let ctx = Executed::LessOrEqual {
-            a: Box::new(a.1.clone()),
-            b: Box::new(b.1.clone()),
+            a: Box::new(a.1),
+            b: Box::new(b.1),
         };
-        self.with_cache(
-            ctx.clone(),
-            Rc::new(move |exec| {
-                exec.ct_ops += 1;
-
-                let mut ct_a = a.0.clone();
-                let mut ct_b = b.0.clone();
-                (exec.sk.smart_le(&mut ct_a, &mut ct_b), ctx.clone())
-            }),
-        )
+        self.with_cache(ctx, |sk| {
+            let mut ct_a = a.0;
+            let mut ct_b = b.0;
+            sk.smart_le_parallelized(&mut ct_a, &mut ct_b)
+        })
     }
 
     pub(crate) fn ct_and(&mut self, a: ExecutedResult, b: ExecutedResult) -> ExecutedResult {
-        let ctx = Executed::And {
-            a: Box::new(a.1.clone()),
-            b: Box::new(b.1.clone()),
-        };
-
         let c_a = a.1.get_trivial_constant();
         let c_b = b.1.get_trivial_constant();
-        if c_a == Some(CT_TRUE) {
-            return (b.0, ctx);
+        if c_a == Some(CT_TRUE) || c_b == Some(CT_FALSE) {
+            return b;
         }
-        if c_a == Some(CT_FALSE) {
-            return (a.0, ctx);
+        if c_a == Some(CT_FALSE) || c_b == Some(CT_TRUE) {
+            return a;
         }
-        if c_b == Some(CT_TRUE) {
-            return (a.0, ctx);
-        }
-        if c_b == Some(CT_FALSE) {
-            return (b.0, ctx);
-        }
-
-        self.with_cache(
-            ctx.clone(),
-            Rc::new(move |exec| {
-                exec.ct_ops += 1;
 
-                let mut ct_a = a.0.clone();
-                let mut ct_b = b.0.clone();
-                (exec.sk.smart_bitand(&mut ct_a, &mut ct_b), ctx.clone())
-            }),
-        )
+        let ctx = Executed::And {
+            a: Box::new(a.1),
+            b: Box::new(b.1),
+        };
+        self.with_cache(ctx, |sk| {
+            let mut ct_a = a.0;
+            let mut ct_b = b.0;
+            sk.smart_bitand_parallelized(&mut ct_a, &mut ct_b)
+        })
     }
 
     pub(crate) fn ct_or(&mut self, a: ExecutedResult, b: ExecutedResult) -> ExecutedResult {
-        let ctx = Executed::Or {
-            a: Box::new(a.1.clone()),
-            b: Box::new(b.1.clone()),
-        };
-
         let c_a = a.1.get_trivial_constant();
         let c_b = b.1.get_trivial_constant();
-        if c_a == Some(CT_TRUE) {
-            return (a.0, ctx);
-        }
-        if c_b == Some(CT_TRUE) {
-            return (b.0, ctx);
+        if c_a == Some(CT_TRUE) || c_b == Some(CT_FALSE) {
+            return a;
         }
-        if c_a == Some(CT_FALSE) && c_b == Some(CT_FALSE) {
-            return (a.0, ctx);
+        if c_b == Some(CT_TRUE) || c_a == Some(CT_FALSE) {
+            return b;
         }
 
-        self.with_cache(
-            ctx.clone(),
-            Rc::new(move |exec| {
-                exec.ct_ops += 1;
-
-                let mut ct_a = a.0.clone();
-                let mut ct_b = b.0.clone();
-                (exec.sk.smart_bitor(&mut ct_a, &mut ct_b), ctx.clone())
-            }),
-        )
+        let ctx = Executed::Or {
+            a: Box::new(a.1),
+            b: Box::new(b.1),
+        };
+        self.with_cache(ctx, |sk| {
+            let mut ct_a = a.0;
+            let mut ct_b = b.0;
+            sk.smart_bitor_parallelized(&mut ct_a, &mut ct_b)
+        })
     }
 
     pub(crate) fn ct_not(&mut self, a: ExecutedResult) -> ExecutedResult {
-        let ctx = Executed::Not {
-            a: Box::new(a.1.clone()),
-        };
-        self.with_cache(
-            ctx.clone(),
-            Rc::new(move |exec| {
-                exec.ct_ops += 1;
+        match a.1.get_trivial_constant() {
+            Some(CT_TRUE) => return self.ct_false(),
+            Some(CT_FALSE) => return self.ct_true(),
+            _ => {}
+        }
 
-                let mut ct_a = a.0.clone();
-                let mut ct_b = exec.ct_constant(1).0;
-                (exec.sk.smart_bitxor(&mut ct_a, &mut ct_b), ctx.clone())
-            }),
-        )
+        let ctx = Executed::Not { a: Box::new(a.1) };
+        let mut ct_b = self.ct_true().0;
+        self.with_cache(ctx, |sk| {
+            let mut ct_a = a.0;
+            sk.smart_bitxor_parallelized(&mut ct_a, &mut ct_b)
+        })
     }
 
     pub(crate) fn ct_false(&self) -> ExecutedResult {
@@ -200,21 +174,24 @@ impl Execution {
 
     pub(crate) fn ct_constant(&self, c: u8) -> ExecutedResult {
         (
-            self.sk.create_trivial_radix(c as u64, 4),
+            self.sk.create_trivial_radix(c as u64, self.num_blocks),
             Executed::Constant { c },
         )
     }
 
-    fn with_cache(&mut self, ctx: Executed, f: LazyExecution) -> ExecutedResult {
+    fn with_cache(
+        &mut self,
+        ctx: Executed,
+        f: impl FnOnce(&ServerKey) -> RadixCiphertext,
+    ) -> ExecutedResult {
         if let Some(res) = self.cache.get(&ctx) {
-            trace!("cache hit: {:?}", &ctx);
             self.cache_hits += 1;
             return (res.clone(), ctx);
         }
-        debug!("evaluation for: {:?}", &ctx);
-        let res = f(self);
-        self.cache.insert(ctx, res.0.clone());
-        res
+        self.ct_ops += 1;
+        let res = f(self.sk);
+        self.cache.insert(ctx.clone(), res.clone());
+        (res, ctx)
     }
 }
 
@@ -0,0 +1,16 @@
+//! Regular expressions over encrypted ASCII content
+//!
+//! The content is a slice of [RadixCiphertext](crate::integer::RadixCiphertext), each one
+//! encrypting an ASCII character, and the patterns are clear regular expressions
+//! written between slashes, e.g. `/^ab?c$/` or `/(a|b){2,4}/i`.
+//!
+//! The supported syntax is described in the
+//! [regex tutorial](https://docs.zama.ai/tfhe-rs/tutorial/regex).
+//!
+//! See [RegexEngine] to evaluate patterns on an encrypted content.
+mod engine;
+mod execution;
+mod parser;
+
+pub use engine::{has_match, RegexEngine};
+pub use parser::RegexParseError;
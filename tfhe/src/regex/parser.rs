@@ -146,7 +146,21 @@ impl fmt::Debug for RegExpr {
     }
 }
 
-pub(crate) fn parse(pattern: &str) -> Result<RegExpr, Box<dyn std::error::Error>> {
+/// Error returned when a pattern is not a valid regular expression
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexParseError {
+    message: String,
+}
+
+impl fmt::Display for RegexParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for RegexParseError {}
+
+pub(crate) fn parse(pattern: &str) -> Result<RegExpr, RegexParseError> {
     let (parsed, unparsed) = (
         between(
             byte(b'/'),
@@ -176,13 +190,17 @@ pub(crate) fn parse(pattern: &str) -> Result<RegExpr, Box<dyn std::error::Error>
                 re
             }
         })
-        .parse(pattern.as_bytes())?;
+        .parse(pattern.as_bytes())
+        .map_err(|err| RegexParseError {
+            message: format!("failed to parse regular expression: {err}"),
+        })?;
     if !unparsed.is_empty() {
-        return Err(format!(
-            "failed to parse regular expression, unexpected token at start of: {}",
-            std::str::from_utf8(unparsed).unwrap()
-        )
-        .into());
+        return Err(RegexParseError {
+            message: format!(
+                "failed to parse regular expression, unexpected token at start of: {}",
+                std::str::from_utf8(unparsed).unwrap()
+            ),
+        });
     }
 
     Ok(parsed)
@@ -200,6 +218,10 @@ pub(crate) fn parse(pattern: &str) -> Result<RegExpr, Box<dyn std::error::Error>
 //  <base> ::= <char>
 //          |  '\' <char>
 //          |  '(' <regex> ')'
+//          |  '^' | '$'
+//
+// The anchors at the very start and end of the pattern apply to the whole
+// regex, the ones inside the pattern only apply to their own alternative.
 
 parser! {
     fn regex[Input]()(Input) -> RegExpr
@@ -272,6 +294,9 @@ where
         .map(|c| RegExpr::Char { c }),
         between(byte(b'['), byte(b']'), range()),
         between(byte(b'('), byte(b')'), regex()),
+        byte(b'^').map(|_| RegExpr::Sof),
+        // A '$' right before the closing '/' is the anchor of the whole regex
+        attempt(byte(b'$').skip(look_ahead(none_of([b'/'])))).map(|_| RegExpr::Eof),
     ))
 }
 
@@ -359,7 +384,7 @@ fn parse_digits(digits: &[u8]) -> usize {
 
 #[cfg(test)]
 mod tests {
-    use crate::parser::{parse, RegExpr};
+    use crate::regex::parser::{parse, RegExpr};
     use test_case::test_case;
 
     #[test_case("/h/", RegExpr::Char { c: b'h' }; "char")]
@@ -692,10 +717,64 @@ mod tests {
     #[test_case("/[79]/",
         RegExpr::Range { cs: vec![b'7', b'9'] };
         "able to match a number range (part 2)")]
+    #[test_case("/(^a|b$)c/",
+        RegExpr::Seq {re_xs: vec![
+            RegExpr::Either {
+                l_re: Box::new(RegExpr::Seq {re_xs: vec![
+                    RegExpr::Sof,
+                    RegExpr::Char { c: b'a' },
+                ]}),
+                r_re: Box::new(RegExpr::Seq {re_xs: vec![
+                    RegExpr::Char { c: b'b' },
+                    RegExpr::Eof,
+                ]}),
+            },
+            RegExpr::Char { c: b'c' },
+        ]};
+        "anchors inside a group")]
+    #[test_case("/a(bc|d|)e/",
+        RegExpr::Seq {re_xs: vec![
+            RegExpr::Char { c: b'a' },
+            RegExpr::Either {
+                l_re: Box::new(RegExpr::Seq {re_xs: vec![
+                    RegExpr::Char { c: b'b' },
+                    RegExpr::Char { c: b'c' },
+                ]}),
+                r_re: Box::new(RegExpr::Either {
+                    l_re: Box::new(RegExpr::Char { c: b'd' }),
+                    r_re: Box::new(RegExpr::Seq { re_xs: vec![] }),
+                }),
+            },
+            RegExpr::Char { c: b'e' },
+        ]};
+        "alternation inside a group")]
+    #[test_case("/(a|bc){1,3}$/",
+        RegExpr::Seq {re_xs: vec![
+            RegExpr::Repeated {
+                repeat_re: Box::new(RegExpr::Either {
+                    l_re: Box::new(RegExpr::Char { c: b'a' }),
+                    r_re: Box::new(RegExpr::Seq {re_xs: vec![
+                        RegExpr::Char { c: b'b' },
+                        RegExpr::Char { c: b'c' },
+                    ]}),
+                }),
+                at_least: Some(1),
+                at_most: Some(3),
+            },
+            RegExpr::Eof,
+        ]};
+        "bounded repetition of an alternation")]
     fn test_parser(pattern: &str, exp: RegExpr) {
         match parse(pattern) {
             Ok(got) => assert_eq!(exp, got),
             Err(e) => panic!("got err: {}", e),
         }
     }
+
+    #[test_case("/a" ; "missing closing slash")]
+    #[test_case("/a)/" ; "unbalanced parenthesis")]
+    #[test_case("/a/x" ; "unknown modifier")]
+    fn test_parser_error(pattern: &str) {
+        assert!(parse(pattern).is_err());
+    }
 }
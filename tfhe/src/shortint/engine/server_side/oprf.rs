@@ -0,0 +1,153 @@
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::math::random::{CompressionSeed, Seed};
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::engine::{EngineResult, ShortintEngine};
+use crate::shortint::server_key::ShortintBootstrappingKey;
+use crate::shortint::{Ciphertext, PBSOrder, ServerKey};
+
+impl ShortintEngine {
+    pub(crate) fn generate_oblivious_pseudo_random(
+        &mut self,
+        server_key: &ServerKey,
+        seed: Seed,
+        random_bits_count: u64,
+    ) -> EngineResult<Ciphertext> {
+        assert!(
+            random_bits_count <= server_key.message_modulus.0.ilog2() as u64,
+            "Cannot generate more than {} random bits in a block, {random_bits_count} were asked",
+            server_key.message_modulus.0.ilog2()
+        );
+
+        if random_bits_count == 0 {
+            return self.create_trivial(server_key, 0, server_key.ciphertext_modulus);
+        }
+
+        let delta =
+            (1_u64 << 63) / (server_key.message_modulus.0 * server_key.carry_modulus.0) as u64;
+        let polynomial_size = server_key.bootstrapping_key.polynomial_size().0;
+
+        // A ciphertext with a uniformly random mask and a zero body: its phase is uniform and
+        // unknown to anyone as it depends on the secret key. The mask is derived from the seed.
+        let random_ct = SeededLweCiphertext::new(
+            0u64,
+            server_key
+                .bootstrapping_key
+                .input_lwe_dimension()
+                .to_lwe_size(),
+            CompressionSeed { seed },
+            server_key.ciphertext_modulus,
+        )
+        .decompress_into_lwe_ciphertext();
+
+        // After the modulus switch, the phase is uniform in [0, 2N[.
+        // We split [0, N[ in `half_count` boxes, the box `i` is mapped to (2i + 1) * delta / 2.
+        // Because of the negacyclicity, the box `i` of [N, 2N[ is mapped to -(2i + 1) * delta / 2.
+        // This gives 2 * half_count equally likely values, that an offset maps to
+        // [0, 2 * half_count[ (times delta).
+        let half_count = 1usize << (random_bits_count - 1);
+        let box_size = polynomial_size / half_count;
+
+        let mut acc = GlweCiphertext::new(
+            0u64,
+            server_key.bootstrapping_key.glwe_size(),
+            server_key.bootstrapping_key.polynomial_size(),
+            server_key.ciphertext_modulus,
+        );
+        for (i, coefficient) in acc.get_mut_body().as_mut().iter_mut().enumerate() {
+            let box_index = (i / box_size) as u64;
+            *coefficient = (2 * box_index + 1) * (delta / 2);
+        }
+
+        let mut buffer_after_pbs = LweCiphertext::new(
+            0u64,
+            server_key
+                .bootstrapping_key
+                .output_lwe_dimension()
+                .to_lwe_size(),
+            server_key.ciphertext_modulus,
+        );
+
+        match &server_key.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                let fft = Fft::new(fourier_bsk.polynomial_size());
+                let fft = fft.as_view();
+                self.computation_buffers.resize(
+                    programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+                        fourier_bsk.glwe_size(),
+                        fourier_bsk.polynomial_size(),
+                        fft,
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = self.computation_buffers.stack();
+
+                programmable_bootstrap_lwe_ciphertext_mem_optimized(
+                    &random_ct,
+                    &mut buffer_after_pbs,
+                    &acc,
+                    fourier_bsk,
+                    fft,
+                    stack,
+                );
+            }
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk,
+                thread_count,
+                deterministic_execution,
+            } => {
+                if *deterministic_execution {
+                    multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext(
+                        &random_ct,
+                        &mut buffer_after_pbs,
+                        &acc,
+                        fourier_bsk,
+                        *thread_count,
+                    );
+                } else {
+                    multi_bit_programmable_bootstrap_lwe_ciphertext(
+                        &random_ct,
+                        &mut buffer_after_pbs,
+                        &acc,
+                        fourier_bsk,
+                        *thread_count,
+                    );
+                }
+            }
+        };
+
+        let mut ct = match server_key.pbs_order {
+            PBSOrder::KeyswitchBootstrap => buffer_after_pbs,
+            PBSOrder::BootstrapKeyswitch => {
+                let mut output = LweCiphertext::new(
+                    0u64,
+                    server_key
+                        .key_switching_key
+                        .output_key_lwe_dimension()
+                        .to_lwe_size(),
+                    server_key.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(
+                    &server_key.key_switching_key,
+                    &buffer_after_pbs,
+                    &mut output,
+                );
+                output
+            }
+        };
+
+        // Maps ±(2i + 1) * delta / 2 to [0, 2 * half_count[ (times delta)
+        let offset = delta / 2 + (half_count as u64 - 1) * delta;
+        lwe_ciphertext_plaintext_add_assign(&mut ct, Plaintext(offset));
+
+        Ok(Ciphertext {
+            ct,
+            degree: Degree((1 << random_bits_count) - 1),
+            message_modulus: server_key.message_modulus,
+            carry_modulus: server_key.carry_modulus,
+            pbs_order: server_key.pbs_order,
+        })
+    }
+}
@@ -894,3 +894,41 @@ pub const PARAM_MESSAGE_7_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     ciphertext_modulus: CiphertextModulus::new_native(),
     encryption_key_choice: EncryptionKeyChoice::Small,
 };
+
+/// Parameter sets to encrypt CRT ciphertexts with a compact public key.
+///
+/// The message space of the blocks (message modulus times carry modulus) can hold the product
+/// of two residues, so that CRT ciphertexts can be multiplied.
+///
+/// With `PARAM_CRT_MODULI_8_*` the moduli of the basis must be at most 8 (e.g. `[2, 3, 5, 7]`),
+/// with `PARAM_CRT_MODULI_16_*` at most 16 (e.g. `[2, 3, 5, 7, 11, 13]`).
+pub const ALL_PARAMETER_VEC_COMPACT_PK_CRT: [ClassicPBSParameters; 4] = [
+    PARAM_CRT_MODULI_8_COMPACT_PK_KS_PBS,
+    PARAM_CRT_MODULI_16_COMPACT_PK_KS_PBS,
+    PARAM_CRT_MODULI_8_COMPACT_PK_PBS_KS,
+    PARAM_CRT_MODULI_16_COMPACT_PK_PBS_KS,
+];
+
+/// CRT parameters for moduli up to 8, this is [PARAM_MESSAGE_3_CARRY_3_COMPACT_PK_KS_PBS].
+///
+/// A CRT block keeps the 64 values message space of this set (its carry modulus is chosen so
+/// that message modulus times carry modulus stays 64), so the encoding, and thus the noise and
+/// security analysis of the set, are unchanged. The product of two residues (at most `7 * 7`)
+/// fits in this message space.
+pub const PARAM_CRT_MODULI_8_COMPACT_PK_KS_PBS: ClassicPBSParameters =
+    PARAM_MESSAGE_3_CARRY_3_COMPACT_PK_KS_PBS;
+/// CRT parameters for moduli up to 16, this is [PARAM_MESSAGE_4_CARRY_4_COMPACT_PK_KS_PBS].
+///
+/// A CRT block keeps the 256 values message space of this set, so its noise and security
+/// analysis still holds. The product of two residues (at most `15 * 15`) fits in this message
+/// space.
+pub const PARAM_CRT_MODULI_16_COMPACT_PK_KS_PBS: ClassicPBSParameters =
+    PARAM_MESSAGE_4_CARRY_4_COMPACT_PK_KS_PBS;
+/// Same as [PARAM_CRT_MODULI_8_COMPACT_PK_KS_PBS], for the `PBS_KS` order,
+/// this is [PARAM_MESSAGE_3_CARRY_3_COMPACT_PK_PBS_KS].
+pub const PARAM_CRT_MODULI_8_COMPACT_PK_PBS_KS: ClassicPBSParameters =
+    PARAM_MESSAGE_3_CARRY_3_COMPACT_PK_PBS_KS;
+/// Same as [PARAM_CRT_MODULI_16_COMPACT_PK_KS_PBS], for the `PBS_KS` order,
+/// this is [PARAM_MESSAGE_4_CARRY_4_COMPACT_PK_PBS_KS].
+pub const PARAM_CRT_MODULI_16_COMPACT_PK_PBS_KS: ClassicPBSParameters =
+    PARAM_MESSAGE_4_CARRY_4_COMPACT_PK_PBS_KS;
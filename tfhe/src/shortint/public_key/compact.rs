@@ -9,6 +9,7 @@ use crate::core_crypto::prelude::{
 use crate::core_crypto::prelude::encrypt_lwe_ciphertext_with_compact_public_key;
 
 use crate::shortint::ciphertext::{CompactCiphertextList, Degree};
+use crate::shortint::parameters::MessageModulus;
 use crate::shortint::{Ciphertext, ClientKey, PBSOrder, ShortintParameterSet};
 
 use crate::shortint::engine::ShortintEngine;
@@ -39,6 +40,36 @@ fn to_plaintext_iterator(
     })
 }
 
+fn to_plaintext_iterator_with_message_modulus(
+    message_iter: impl Iterator<Item = (u64, MessageModulus)>,
+    parameters: &ShortintParameterSet,
+) -> impl Iterator<Item = Plaintext<u64>> {
+    let message_modulus = parameters.message_modulus().0 as u64;
+    let carry_modulus = parameters.carry_modulus().0 as u64;
+    message_iter.map(move |(message, block_message_modulus)| {
+        //The delta is the one defined by the parameters
+        let delta = (1_u64 << 63) / (message_modulus * carry_modulus);
+
+        //The input is reduced modulus its own message_modulus
+        let m = message % block_message_modulus.0 as u64;
+
+        let shifted_message = m * delta;
+        // encode the message
+        Plaintext(shifted_message)
+    })
+}
+
+fn to_native_crt_plaintext_iterator(
+    message_iter: impl Iterator<Item = (u64, u8)>,
+) -> impl Iterator<Item = Plaintext<u64>> {
+    message_iter.map(|(message, message_modulus)| {
+        let m = (message % message_modulus as u64) as u128;
+        let shifted_message = m * (1 << 64) / message_modulus as u128;
+        // encode the message
+        Plaintext(shifted_message as u64)
+    })
+}
+
 impl CompactPublicKey {
     pub fn new(client_key: &ClientKey) -> CompactPublicKey {
         Self::try_new(client_key).expect(
@@ -129,9 +160,43 @@ impl CompactPublicKey {
     }
 
     pub fn encrypt_iter(&self, messages: impl Iterator<Item = u64>) -> CompactCiphertextList {
-        let plaintext_container = to_plaintext_iterator(messages, &self.parameters)
-            .map(|plaintext| plaintext.0)
-            .collect::<Vec<_>>();
+        self.encrypt_plaintext_iter(to_plaintext_iterator(messages, &self.parameters))
+    }
+
+    /// Encrypts each message with its own message modulus, the carry modulus
+    /// being chosen so that the message space of the parameters is kept.
+    ///
+    /// The moduli are not stored in the returned list, its metadata is the one
+    /// of the parameters, it is up to the caller to set the moduli of the blocks
+    /// after expansion.
+    pub(crate) fn encrypt_iter_with_message_modulus(
+        &self,
+        messages: impl Iterator<Item = (u64, MessageModulus)>,
+    ) -> CompactCiphertextList {
+        self.encrypt_plaintext_iter(to_plaintext_iterator_with_message_modulus(
+            messages,
+            &self.parameters,
+        ))
+    }
+
+    /// Encrypts each message with its own message modulus using the native CRT encoding,
+    /// i.e. without padding nor carry space.
+    ///
+    /// The moduli are not stored in the returned list, its metadata is the one
+    /// of the parameters, it is up to the caller to set the moduli of the blocks
+    /// after expansion.
+    pub(crate) fn encrypt_iter_native_crt(
+        &self,
+        messages: impl Iterator<Item = (u64, u8)>,
+    ) -> CompactCiphertextList {
+        self.encrypt_plaintext_iter(to_native_crt_plaintext_iterator(messages))
+    }
+
+    fn encrypt_plaintext_iter(
+        &self,
+        plaintexts: impl Iterator<Item = Plaintext<u64>>,
+    ) -> CompactCiphertextList {
+        let plaintext_container = plaintexts.map(|plaintext| plaintext.0).collect::<Vec<_>>();
 
         let plaintext_list = PlaintextList::from_container(plaintext_container);
         let mut ct_list = LweCompactCiphertextListOwned::new(
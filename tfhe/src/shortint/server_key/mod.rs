@@ -8,6 +8,7 @@ mod comp_op;
 mod div_mod;
 mod mul;
 mod neg;
+mod oprf;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_mul;
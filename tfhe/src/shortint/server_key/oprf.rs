@@ -0,0 +1,52 @@
+use super::ServerKey;
+use crate::core_crypto::commons::math::random::Seed;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::Ciphertext;
+
+impl ServerKey {
+    /// Generates an encrypted pseudo-random value, uniform in `[0, 2^random_bits_count[`,
+    /// without any knowledge of the client key.
+    ///
+    /// The value is fully determined by the `seed` and the secret key, but as the secret key is
+    /// unknown to the server, nobody knows the value without decrypting it.
+    /// Calling this function twice with the same seed gives encryptions of the same value,
+    /// so a new seed must be used to get a new value.
+    ///
+    /// The returned ciphertext has an empty carry.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `random_bits_count` is greater than the number of bits of the message modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::commons::math::random::Seed;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct = sks.generate_oblivious_pseudo_random(Seed(42), 2);
+    ///
+    /// // Decrypt
+    /// let clear = cks.decrypt(&ct);
+    /// assert!(clear < 4);
+    ///
+    /// // The same seed gives the same value
+    /// let ct = sks.generate_oblivious_pseudo_random(Seed(42), 2);
+    /// assert_eq!(cks.decrypt(&ct), clear);
+    /// ```
+    pub fn generate_oblivious_pseudo_random(
+        &self,
+        seed: Seed,
+        random_bits_count: u64,
+    ) -> Ciphertext {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine
+                .generate_oblivious_pseudo_random(self, seed, random_bits_count)
+                .unwrap()
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::core_crypto::commons::math::random::Seed;
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::*;
 use paste::paste;
@@ -109,6 +110,7 @@ create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
 create_parametrized_test!(shortint_generate_lookup_table);
+create_parametrized_test!(shortint_generate_oblivious_pseudo_random);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
 create_parametrized_test!(shortint_default_add);
@@ -3201,3 +3203,38 @@ where
     println!("(msg_true - msg_false) * control_bit  + msg_false = {clear_mux}, res = {dec_res}");
     assert_eq!(clear_mux, dec_res);
 }
+
+fn shortint_generate_oblivious_pseudo_random<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let mut rng = rand::thread_rng();
+    let message_bits = cks.parameters.message_modulus().0.ilog2() as u64;
+
+    for random_bits_count in 0..=message_bits {
+        let modulus = 1u64 << random_bits_count;
+        let mut seen = vec![false; modulus as usize];
+
+        for _ in 0..NB_TEST {
+            let seed = Seed(rng.gen::<u128>());
+            let ct = sks.generate_oblivious_pseudo_random(seed, random_bits_count);
+            assert!(ct.carry_is_empty());
+
+            let dec = cks.decrypt_message_and_carry(&ct);
+            assert!(dec < modulus);
+            seen[dec as usize] = true;
+
+            // The value only depends on the seed
+            let ct = sks.generate_oblivious_pseudo_random(seed, random_bits_count);
+            assert_eq!(cks.decrypt_message_and_carry(&ct), dec);
+        }
+
+        // With a single bit, both values should have been generated
+        if random_bits_count == 1 {
+            assert!(seen.iter().all(|&seen| seen), "{seen:?}");
+        }
+    }
+}